@@ -0,0 +1,148 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Invariants of the native token across the Relay Chain and Asset Hub.
+//!
+//! The migration moves balances between the chains, but the balances of both chains together
+//! must stay the same. The checking accounts stand in for the balance on other chains and are
+//! left out, like in the circulating supply.
+
+use paseo_runtime_constants::ahm::{translate_para_sovereign_account, MigrationProgress};
+use polkadot_primitives::{AccountId, Balance};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The native token on one chain, or on both chains together.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BalanceSummary {
+	/// The total issuance without the balance of the checking account.
+	pub circulating: Balance,
+	/// The total amount on hold.
+	pub holds: Balance,
+	/// The total amount frozen.
+	pub freezes: Balance,
+	/// The total amount locked per lock identifier, like the staking, vesting and voting locks.
+	pub locks: BTreeMap<[u8; 8], Balance>,
+	/// The total balance of every account with a balance, except for the checking account.
+	pub accounts: BTreeMap<AccountId, Balance>,
+}
+
+impl BalanceSummary {
+	/// The summary of the chain of the runtime `T`, whose checking account is `checking_account`.
+	pub fn of<T>(checking_account: &AccountId) -> Self
+	where
+		T: frame_system::Config<
+				AccountId = AccountId,
+				AccountData = pallet_balances::AccountData<Balance>,
+			> + pallet_balances::Config<Balance = Balance>,
+	{
+		let accounts: BTreeMap<_, _> = frame_system::Account::<T>::iter()
+			.map(|(who, info)| (who, info.data.free.saturating_add(info.data.reserved)))
+			.filter(|(who, total)| *total > 0 && who != checking_account)
+			.collect();
+		let checking = frame_system::Account::<T>::get(checking_account).data;
+		let mut locks = BTreeMap::new();
+		for lock in pallet_balances::Locks::<T>::iter_values().flatten() {
+			add_lock(&mut locks, lock.id, lock.amount);
+		}
+
+		BalanceSummary {
+			circulating: pallet_balances::TotalIssuance::<T>::get()
+				.saturating_sub(checking.free.saturating_add(checking.reserved)),
+			holds: pallet_balances::Holds::<T>::iter_values()
+				.flatten()
+				.map(|hold| hold.amount)
+				.sum(),
+			freezes: pallet_balances::Freezes::<T>::iter_values()
+				.flatten()
+				.map(|freeze| freeze.amount)
+				.sum(),
+			locks,
+			accounts,
+		}
+	}
+
+	/// The summary of both chains together.
+	///
+	/// The accounts of the Relay Chain are translated to their accounts on Asset Hub.
+	pub fn combine(rc: BalanceSummary, ah: BalanceSummary) -> Self {
+		let mut accounts = ah.accounts;
+		for (who, total) in rc.accounts {
+			let who = translate_para_sovereign_account(&who).map_or(who, |(_, ah_who)| ah_who);
+			let balance = accounts.entry(who).or_default();
+			*balance = balance.saturating_add(total);
+		}
+		let mut locks = ah.locks;
+		for (id, amount) in rc.locks {
+			add_lock(&mut locks, id, amount);
+		}
+
+		BalanceSummary {
+			circulating: rc.circulating.saturating_add(ah.circulating),
+			holds: rc.holds.saturating_add(ah.holds),
+			freezes: rc.freezes.saturating_add(ah.freezes),
+			locks,
+			accounts,
+		}
+	}
+}
+
+/// Add `amount` to the total of the lock `id`.
+fn add_lock(locks: &mut BTreeMap<[u8; 8], Balance>, id: [u8; 8], amount: Balance) {
+	let total = locks.entry(id).or_default();
+	*total = total.saturating_add(amount);
+}
+
+/// Assert that both chains together hold the same balances `before` and `after` the migration.
+///
+/// Every account whose balance changed is printed before the assertions.
+pub fn assert_balances_preserved(before: &BalanceSummary, after: &BalanceSummary) {
+	let mut mismatches = 0;
+	let all: BTreeSet<_> = before.accounts.keys().chain(after.accounts.keys()).collect();
+	for who in all {
+		let old = before.accounts.get(who).copied().unwrap_or_default();
+		let new = after.accounts.get(who).copied().unwrap_or_default();
+		if old != new {
+			println!("{who}: {old} before, {new} after ({:+})", new as i128 - old as i128);
+			mismatches += 1;
+		}
+	}
+
+	assert_eq!(before.circulating, after.circulating, "the total issuance changed");
+	assert_eq!(before.holds, after.holds, "the total amount on hold changed");
+	assert_eq!(before.freezes, after.freezes, "the total amount frozen changed");
+	assert_eq!(before.locks, after.locks, "the total amount locked changed");
+	assert_eq!(before.accounts.len(), after.accounts.len(), "the number of accounts changed");
+	assert_eq!(mismatches, 0, "the balance of {mismatches} accounts changed");
+}
+
+/// Assert that Asset Hub processed every item that the Relay Chain sent.
+///
+/// The items that Asset Hub failed to integrate are printed, they are reported by the migration
+/// and do not fail the check.
+pub fn assert_all_processed(rc: &MigrationProgress, ah: &MigrationProgress) {
+	assert_eq!(rc.stage, ah.stage, "Asset Hub is not at the stage of the Relay Chain");
+
+	for ((pallet, sent), (_, processed)) in rc.items.iter().zip(ah.items.iter()) {
+		if processed.failed > 0 {
+			println!("{pallet:?}: {} of {} items failed", processed.failed, sent.sent);
+		}
+		assert_eq!(
+			sent.sent,
+			processed.acknowledged + processed.failed,
+			"Asset Hub did not process all items of {pallet:?}"
+		);
+	}
+}
@@ -0,0 +1,35 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset Hub Migration (AHM) tests on snapshots of the Relay Chain and Asset Hub.
+//!
+//! Both snapshots are loaded together and the migration is run to completion, passing the
+//! downward and upward messages between the two chains after every block. The tests only run if
+//! the paths of the snapshots are given in the `SNAP_RC` and `SNAP_AH` environment variables, for
+//! example:
+//!
+//! ```sh
+//! SNAP_RC=paseo.snap SNAP_AH=asset-hub-paseo.snap \
+//! 	cargo test -p paseo-integration-tests-ahm --features try-runtime --release -- --nocapture
+//! ```
+//!
+//! The snapshots can be created with `try-runtime create-snapshot`.
+
+pub mod checks;
+pub mod mock;
+
+#[cfg(test)]
+mod tests;
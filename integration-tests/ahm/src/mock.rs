@@ -0,0 +1,142 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Loading of the snapshots and the production of blocks on both chains.
+//!
+//! Only the hooks that drive the migration are executed: the `RcMigrator` on the Relay Chain and
+//! the message queues on both chains. The messages are passed between the chains in place of the
+//! collators and validators.
+
+use asset_hub_paseo_runtime::{Block as AhBlock, Runtime as AhRuntime};
+use cumulus_primitives_core::{AggregateMessageOrigin as AhMessageOrigin, ParaId};
+use frame_support::traits::{EnqueueMessage, OnInitialize, ServiceQueues};
+use paseo_runtime::{ah_migration::RcMigrationStage, Block as RcBlock, Runtime as RcRuntime};
+use paseo_runtime_constants::ahm::MigrationStage;
+use remote_externalities::{Builder, Mode, OfflineConfig, RemoteExternalities, SnapshotConfig};
+use runtime_parachains::inclusion::{AggregateMessageOrigin as RcMessageOrigin, UmpQueueId};
+use sp_runtime::{BoundedSlice, Weight};
+use std::env::var;
+
+/// The parachain id of Asset Hub.
+pub const AH_PARA_ID: ParaId = ParaId::new(1000);
+
+/// The number of Relay Chain blocks after which a migration is considered stuck.
+pub const MAX_BLOCKS: u32 = 100_000;
+
+type RcSystem = frame_system::Pallet<RcRuntime>;
+type AhSystem = frame_system::Pallet<AhRuntime>;
+type RcMessageQueue = pallet_message_queue::Pallet<RcRuntime>;
+type AhMessageQueue = pallet_message_queue::Pallet<AhRuntime>;
+
+/// Load the snapshot of the Relay Chain from the path in `SNAP_RC`.
+///
+/// Panics if `SNAP_RC` is not set.
+pub async fn load_rc() -> RemoteExternalities<RcBlock> {
+	let path =
+		var("SNAP_RC").expect("`SNAP_RC` is set to the path of a snapshot of the Relay Chain");
+	Builder::<RcBlock>::default()
+		.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+		.build()
+		.await
+		.expect("the snapshot of the Relay Chain can be loaded")
+}
+
+/// Load the snapshot of Asset Hub from the path in `SNAP_AH`.
+///
+/// Panics if `SNAP_AH` is not set.
+pub async fn load_ah() -> RemoteExternalities<AhBlock> {
+	let path = var("SNAP_AH").expect("`SNAP_AH` is set to the path of a snapshot of Asset Hub");
+	Builder::<AhBlock>::default()
+		.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+		.build()
+		.await
+		.expect("the snapshot of Asset Hub can be loaded")
+}
+
+/// Start the migration and produce blocks on both chains until it is done.
+///
+/// Panics if the migration does not finish within [`MAX_BLOCKS`] Relay Chain blocks.
+pub fn migrate(rc: &mut RemoteExternalities<RcBlock>, ah: &mut RemoteExternalities<AhBlock>) {
+	rc.execute_with(|| RcMigrationStage::<RcRuntime>::put(MigrationStage::Scheduled { start: 0 }));
+
+	for _ in 0..MAX_BLOCKS {
+		let (dmp, done) = rc.execute_with(|| {
+			next_block_rc();
+			(take_dmp(), RcMigrationStage::<RcRuntime>::get() == MigrationStage::Done)
+		});
+		let ump = ah.execute_with(|| {
+			next_block_ah(dmp);
+			take_ump()
+		});
+		rc.execute_with(|| enqueue_ump(ump));
+
+		if done {
+			// The Relay Chain processes the last reports of Asset Hub in its next block.
+			rc.execute_with(next_block_rc);
+			return
+		}
+	}
+	panic!("the migration did not finish within {MAX_BLOCKS} blocks");
+}
+
+/// Produce the next block of the Relay Chain.
+pub fn next_block_rc() {
+	let now = RcSystem::block_number() + 1;
+	RcSystem::reset_events();
+	RcSystem::set_block_number(now);
+
+	paseo_runtime::RcMigrator::on_initialize(now);
+	RcMessageQueue::service_queues(Weight::MAX);
+}
+
+/// Produce the next block of Asset Hub, which receives the downward messages `dmp`.
+pub fn next_block_ah(dmp: Vec<Vec<u8>>) {
+	let now = AhSystem::block_number() + 1;
+	AhSystem::reset_events();
+	AhSystem::set_block_number(now);
+
+	for message in dmp {
+		let message = BoundedSlice::try_from(&message[..]).expect("the message fits the queue");
+		AhMessageQueue::enqueue_message(message, AhMessageOrigin::Parent);
+	}
+	AhMessageQueue::service_queues(Weight::MAX);
+}
+
+/// Take the messages that the Relay Chain sent to Asset Hub.
+pub fn take_dmp() -> Vec<Vec<u8>> {
+	runtime_parachains::dmp::DownwardMessageQueues::<RcRuntime>::take(AH_PARA_ID)
+		.into_iter()
+		.map(|message| message.msg)
+		.collect()
+}
+
+/// Take the messages that Asset Hub sent to the Relay Chain.
+pub fn take_ump() -> Vec<Vec<u8>> {
+	cumulus_pallet_parachain_system::PendingUpwardMessages::<AhRuntime>::take()
+}
+
+/// Enqueue the messages that Asset Hub sent to the Relay Chain.
+///
+/// They are processed in the next block of the Relay Chain.
+pub fn enqueue_ump(ump: Vec<Vec<u8>>) {
+	for message in ump {
+		let message = BoundedSlice::try_from(&message[..]).expect("the message fits the queue");
+		RcMessageQueue::enqueue_message(
+			message,
+			RcMessageOrigin::Ump(UmpQueueId::Para(AH_PARA_ID)),
+		);
+	}
+}
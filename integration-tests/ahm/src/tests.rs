@@ -0,0 +1,87 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{checks::*, mock::*};
+use asset_hub_paseo_runtime::{AhMigrator, Block as AhBlock, Runtime as AhRuntime};
+use frame_support::traits::Get;
+use paseo_runtime::{Block as RcBlock, RcMigrator, Runtime as RcRuntime};
+use remote_externalities::RemoteExternalities;
+
+/// Needs snapshots of both chains taken at the same block, run it with:
+///
+/// ```sh
+/// SNAP_RC=paseo.snap SNAP_AH=asset-hub-paseo.snap \
+/// 	cargo test -p paseo-integration-tests-ahm --release -- --ignored
+/// ```
+#[tokio::test]
+#[ignore = "needs snapshots of both chains in `SNAP_RC` and `SNAP_AH`"]
+async fn migration_preserves_balances() {
+	let (mut rc, mut ah) = (load_rc().await, load_ah().await);
+	sp_tracing::try_init_simple();
+
+	let rc_checking = paseo_runtime::xcm_config::CheckAccount::get();
+	let ah_checking = asset_hub_paseo_runtime::xcm_config::CheckingAccount::get();
+	let summary = |rc: &mut RemoteExternalities<RcBlock>, ah: &mut RemoteExternalities<AhBlock>| {
+		BalanceSummary::combine(
+			rc.execute_with(|| BalanceSummary::of::<RcRuntime>(&rc_checking)),
+			ah.execute_with(|| BalanceSummary::of::<AhRuntime>(&ah_checking)),
+		)
+	};
+
+	let before = summary(&mut rc, &mut ah);
+	#[cfg(feature = "try-runtime")]
+	let nom_pools_before = rc.execute_with(RcMigrator::nom_pools_summary);
+	#[cfg(feature = "try-runtime")]
+	let vesting_before = rc.execute_with(RcMigrator::vesting_summary);
+	migrate(&mut rc, &mut ah);
+	let after = summary(&mut rc, &mut ah);
+
+	assert_all_processed(
+		&rc.execute_with(RcMigrator::progress),
+		&ah.execute_with(AhMigrator::progress),
+	);
+	assert_balances_preserved(&before, &after);
+
+	#[cfg(feature = "try-runtime")]
+	{
+		use frame_support::traits::TryState;
+		use frame_system::pallet_prelude::BlockNumberFor;
+
+		rc.execute_with(|| {
+			let now: BlockNumberFor<RcRuntime> = frame_system::Pallet::<RcRuntime>::block_number();
+			paseo_runtime::AllPalletsWithSystem::try_state(now, Default::default())
+				.expect("the Relay Chain is consistent");
+		});
+		ah.execute_with(|| {
+			let now: BlockNumberFor<AhRuntime> = frame_system::Pallet::<AhRuntime>::block_number();
+			asset_hub_paseo_runtime::AllPalletsWithSystem::try_state(now, Default::default())
+				.expect("Asset Hub is consistent");
+			AhMigrator::check_nom_pools(nom_pools_before).expect("nomination pools are migrated");
+			AhMigrator::check_vesting(vesting_before).expect("vesting schedules are migrated");
+		});
+		assert_eq!(
+			rc.execute_with(RcMigrator::nom_pools_summary),
+			Default::default(),
+			"no nomination pools are left on the Relay Chain"
+		);
+		let vesting_after = rc.execute_with(RcMigrator::vesting_summary);
+		assert_eq!(
+			(vesting_after.accounts, vesting_after.schedules),
+			(0, 0),
+			"no vesting schedules are left on the Relay Chain"
+		);
+	}
+}
@@ -48,6 +48,7 @@ decl_test_parachains! {
 			ForeignAssets: asset_hub_paseo_runtime::ForeignAssets,
 			PoolAssets: asset_hub_paseo_runtime::PoolAssets,
 			AssetConversion: asset_hub_paseo_runtime::AssetConversion,
+			AhMigrator: asset_hub_paseo_runtime::AhMigrator,
 		}
 	},
 }
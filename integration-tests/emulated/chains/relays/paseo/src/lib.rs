@@ -38,6 +38,11 @@ decl_test_relay_chains! {
 			Treasury: paseo_runtime::Treasury,
 			AssetRate: paseo_runtime::AssetRate,
 			Hrmp: paseo_runtime::Hrmp,
+			RcMigrator: paseo_runtime::RcMigrator,
+			Preimage: paseo_runtime::Preimage,
+			Scheduler: paseo_runtime::Scheduler,
+			ConvictionVoting: paseo_runtime::ConvictionVoting,
+			Referenda: paseo_runtime::Referenda,
 		}
 	},
 }
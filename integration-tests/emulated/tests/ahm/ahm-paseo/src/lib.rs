@@ -0,0 +1,43 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use codec::Encode;
+
+// Substrate
+pub use frame_support::{
+	assert_ok,
+	sp_runtime::{AccountId32, DispatchError},
+	traits::fungible::{Inspect, Mutate},
+};
+
+// Polkadot
+pub use xcm::prelude::*;
+
+// Cumulus
+pub use emulated_integration_tests_common::xcm_emulator::{
+	assert_expected_events, Chain, Parachain as Para, RelayChain as Relay, TestExt,
+};
+pub use parachains_common::{AccountId, Balance};
+pub use paseo_system_emulated_network::{
+	asset_hub_paseo_emulated_chain::{
+		genesis::ED as ASSET_HUB_PASEO_ED, AssetHubPaseoParaPallet as AssetHubPaseoPallet,
+	},
+	paseo_emulated_chain::{genesis::ED as PASEO_ED, PaseoRelayPallet as PaseoPallet},
+	AssetHubPaseoPara as AssetHubPaseo, PaseoRelay as Paseo,
+};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,109 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use asset_hub_paseo_runtime::ah_migration::pallet_ah_migrator;
+use paseo_runtime_constants::ahm::{PortableHoldReason, RcAccount};
+
+#[test]
+fn relay_accounts_are_migrated_to_asset_hub() {
+	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
+	// <https://github.com/rust-lang/rust/issues/86935>
+	type AssetHubEvent = <AssetHubPaseo as Chain>::RuntimeEvent;
+
+	let who = AccountId32::new([7u8; 32]);
+	let amount = PASEO_ED * 1_000;
+
+	Paseo::execute_with(|| {
+		type Balances = <Paseo as PaseoPallet>::Balances;
+		Balances::set_balance(&who, amount);
+		let issuance = Balances::total_issuance();
+
+		// Migrate all accounts of the genesis in one go.
+		let (result, _) = <Paseo as PaseoPallet>::RcMigrator::migrate_accounts(None);
+		assert_ok!(result);
+
+		assert_eq!(Balances::total_balance(&who), 0);
+		assert_eq!(Balances::total_issuance(), issuance);
+	});
+
+	AssetHubPaseo::execute_with(|| {
+		assert_expected_events!(
+			AssetHubPaseo,
+			vec![
+				AssetHubEvent::AhMigrator(
+					pallet_ah_migrator::Event::AccountBatchProcessed { .. }
+				) => {},
+			]
+		);
+		assert_eq!(<AssetHubPaseo as AssetHubPaseoPallet>::Balances::total_balance(&who), amount);
+	});
+}
+
+#[test]
+fn accounts_that_cannot_be_integrated_are_reported() {
+	type AssetHubEvent = <AssetHubPaseo as Chain>::RuntimeEvent;
+
+	let good = AccountId32::new([8u8; 32]);
+	let bad = AccountId32::new([9u8; 32]);
+	let account = |who: AccountId32, hold: Balance| RcAccount {
+		who,
+		free: ASSET_HUB_PASEO_ED * 100,
+		reserved: hold,
+		frozen: 0,
+		holds: vec![(PortableHoldReason::Preimage, hold)],
+		freezes: vec![],
+		locks: vec![],
+		unnamed_reserve: 0,
+	};
+
+	AssetHubPaseo::execute_with(|| {
+		let relay_origin: <AssetHubPaseo as Chain>::RuntimeOrigin =
+			pallet_xcm::Origin::Xcm(Location::parent()).into();
+
+		// Only the Relay Chain may send accounts.
+		assert!(<AssetHubPaseo as AssetHubPaseoPallet>::AhMigrator::receive_accounts(
+			<AssetHubPaseo as Chain>::RuntimeOrigin::signed(good.clone()),
+			vec![account(good.clone(), ASSET_HUB_PASEO_ED)],
+		)
+		.is_err());
+
+		// The hold of the bad account exceeds its balance.
+		assert_ok!(<AssetHubPaseo as AssetHubPaseoPallet>::AhMigrator::receive_accounts(
+			relay_origin,
+			vec![
+				account(good.clone(), ASSET_HUB_PASEO_ED),
+				account(bad.clone(), ASSET_HUB_PASEO_ED * 1_000)
+			],
+		));
+
+		assert_expected_events!(
+			AssetHubPaseo,
+			vec![
+				AssetHubEvent::AhMigrator(
+					pallet_ah_migrator::Event::AccountIntegrationFailed { who, .. }
+				) => { who: *who == bad, },
+				AssetHubEvent::AhMigrator(
+					pallet_ah_migrator::Event::AccountBatchProcessed { count_good, count_bad }
+				) => { count_good: *count_good == 1, count_bad: *count_bad == 1, },
+			]
+		);
+
+		type Balances = <AssetHubPaseo as AssetHubPaseoPallet>::Balances;
+		assert_eq!(Balances::total_balance(&good), ASSET_HUB_PASEO_ED * 100);
+		assert_eq!(Balances::total_balance(&bad), 0);
+	});
+}
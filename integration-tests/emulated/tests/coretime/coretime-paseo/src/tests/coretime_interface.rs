@@ -27,7 +27,7 @@ fn transact_hardcoded_weights_are_sane() {
 	//   extrinsics.
 	// - Request revenue info - triggered when each timeslice is committed.
 	// - Assign core - triggered when an entry is encountered in the workplan for the next
-	//   timeslice.
+	//   timeslice. The worst case assignment is chunked across several messages.
 
 	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
 	// <https://github.com/rust-lang/rust/issues/86935>
@@ -155,6 +155,12 @@ fn transact_hardcoded_weights_are_sane() {
 				CoretimeEvent::Broker(
 					pallet_broker::Event::CoreAssigned { .. }
 				) => {},
+				CoretimeEvent::AssignmentChunks(
+					coretime_paseo_runtime::assignment_chunks::Event::AssignmentChunked {
+						chunks: 3,
+						..
+					}
+				) => {},
 				CoretimeEvent::ParachainSystem(
 					cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. }
 				) => {},
@@ -179,8 +185,9 @@ fn transact_hardcoded_weights_are_sane() {
 		);
 	});
 
-	// Check that the assign_core and request_revenue_info_at messages were processed successfully.
-	// This will fail if the weights are misconfigured.
+	// Check that the assign_core_chunk and request_revenue_info_at messages were processed
+	// successfully and that the chunks were reassembled. This will fail if the weights are
+	// misconfigured.
 	Paseo::execute_with(|| {
 		Paseo::assert_ump_queue_processed(true, Some(CoretimePolkadot::para_id()), None);
 
@@ -193,8 +200,14 @@ fn transact_hardcoded_weights_are_sane() {
 				RelayEvent::MessageQueue(
 					pallet_message_queue::Event::Processed { success: true, .. }
 				) => {},
-				RelayEvent::Coretime(
-					runtime_parachains::coretime::Event::CoreAssigned { .. }
+				RelayEvent::CoretimeAssignments(
+					paseo_runtime::coretime_assignments::Event::AssignmentChunkReceived {
+						assignments: 28,
+						..
+					}
+				) => {},
+				RelayEvent::CoretimeAssignments(
+					paseo_runtime::coretime_assignments::Event::CoreAssigned { chunks: 3, .. }
 				) => {},
 			]
 		);
@@ -0,0 +1,151 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::traits::{
+	fungible::Mutate, schedule::DispatchTime, OnInitialize, StorePreimage,
+};
+use pallet_broker::{ConfigRecord, Configuration};
+use pallet_conviction_voting::{AccountVote, Conviction, Vote};
+use pallet_referenda::TracksInfo;
+use paseo_runtime::governance::pallet_custom_origins;
+use sp_runtime::Perbill;
+
+#[test]
+fn general_admin_referendum_reconfigures_the_broker() {
+	// RuntimeEvent aliases to avoid warning from usage of qualified paths in assertions due to
+	// <https://github.com/rust-lang/rust/issues/86935>
+	type CoretimeEvent = <CoretimePolkadot as Chain>::RuntimeEvent;
+	type RelayEvent = <Paseo as Chain>::RuntimeEvent;
+
+	let config = ConfigRecord {
+		advance_notice: 5,
+		interlude_length: 1,
+		leadin_length: 2,
+		region_length: 1,
+		ideal_bulk_proportion: Perbill::from_percent(40),
+		limit_cores_offered: None,
+		renewal_bump: Perbill::from_percent(2),
+		contribution_timeout: 1,
+	};
+
+	// The broker cannot be configured by anyone but its admin.
+	CoretimePolkadot::execute_with(|| {
+		assert_err!(
+			<CoretimePolkadot as CoretimePolkadotPallet>::Broker::configure(
+				<CoretimePolkadot as Chain>::RuntimeOrigin::signed(CoretimePolkadotSender::get()),
+				config.clone()
+			),
+			DispatchError::BadOrigin
+		);
+	});
+
+	// The referendum sends the configuration to the Coretime Chain with the `GeneralAdmin` origin,
+	// which is its plurality there.
+	let configure = <CoretimePolkadot as Chain>::RuntimeCall::Broker(pallet_broker::Call::<
+		<CoretimePolkadot as Chain>::Runtime,
+	>::configure {
+		config: config.clone(),
+	});
+	let send = <Paseo as Chain>::RuntimeCall::XcmPallet(pallet_xcm::Call::<
+		<Paseo as Chain>::Runtime,
+	>::send {
+		dest: bx!(VersionedLocation::from(Location::new(
+			0,
+			[Parachain(CoretimePolkadot::para_id().into())]
+		))),
+		message: bx!(VersionedXcm::from(Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				require_weight_at_most: Weight::from_parts(1_000_000_000, 200_000),
+				call: configure.encode().into(),
+			},
+		]))),
+	});
+
+	Paseo::execute_with(|| {
+		type Runtime = <Paseo as Chain>::Runtime;
+		type RuntimeOrigin = <Paseo as Chain>::RuntimeOrigin;
+		type Referenda = <Paseo as PaseoPallet>::Referenda;
+
+		let proposer = PaseoSender::get();
+		let signed = || RuntimeOrigin::signed(proposer.clone());
+		let advance = |blocks: u32| {
+			let now = <Paseo as Chain>::System::block_number() + blocks;
+			<Paseo as Chain>::System::set_block_number(now);
+			now
+		};
+
+		// Give the proposer enough stake to carry the referendum on its own.
+		let votes = 2 * pallet_balances::TotalIssuance::<Runtime>::get();
+		assert_ok!(<<Paseo as PaseoPallet>::Balances as Mutate<AccountId>>::mint_into(
+			&proposer, votes
+		));
+
+		let origin =
+			paseo_runtime::OriginCaller::Origins(pallet_custom_origins::Origin::GeneralAdmin);
+		let track_id = <Runtime as pallet_referenda::Config>::Tracks::track_for(&origin)
+			.expect("`GeneralAdmin` has a track");
+		let track = <Runtime as pallet_referenda::Config>::Tracks::info(track_id)
+			.expect("the track exists");
+		let proposal = <<Paseo as PaseoPallet>::Preimage as StorePreimage>::bound(send)
+			.expect("the proposal can be noted");
+
+		assert_ok!(Referenda::submit(signed(), bx!(origin), proposal, DispatchTime::After(0)));
+		let index = pallet_referenda::ReferendumCount::<Runtime>::get() - 1;
+		assert_ok!(Referenda::place_decision_deposit(signed(), index));
+		assert_ok!(<Paseo as PaseoPallet>::ConvictionVoting::vote(
+			signed(),
+			index,
+			AccountVote::Standard {
+				vote: Vote { aye: true, conviction: Conviction::None },
+				balance: votes,
+			}
+		));
+
+		// Hooks don't run in emulated tests, so the referendum is nudged along its track.
+		advance(track.prepare_period);
+		assert_ok!(Referenda::nudge_referendum(RuntimeOrigin::root(), index));
+		advance(track.confirm_period);
+		assert_ok!(Referenda::nudge_referendum(RuntimeOrigin::root(), index));
+		let enactment = advance(track.min_enactment_period);
+		<Paseo as PaseoPallet>::Scheduler::on_initialize(enactment);
+
+		assert_expected_events!(
+			Paseo,
+			vec![
+				RelayEvent::Referenda(pallet_referenda::Event::Confirmed { .. }) => {},
+				RelayEvent::Scheduler(
+					pallet_scheduler::Event::Dispatched { result: Ok(()), .. }
+				) => {},
+				RelayEvent::XcmPallet(pallet_xcm::Event::Sent { .. }) => {},
+			]
+		);
+	});
+
+	CoretimePolkadot::execute_with(|| {
+		assert_expected_events!(
+			CoretimePolkadot,
+			vec![
+				CoretimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+				CoretimeEvent::Broker(pallet_broker::Event::ConfigurationSet { .. }) => {},
+			]
+		);
+		assert_eq!(Configuration::<<CoretimePolkadot as Chain>::Runtime>::get(), Some(config));
+	});
+}
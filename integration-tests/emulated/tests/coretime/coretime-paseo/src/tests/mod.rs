@@ -15,4 +15,5 @@
 // limitations under the License.
 
 mod coretime_interface;
+mod governance;
 mod teleport;
@@ -0,0 +1,1366 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Types shared between the Relay Chain and Asset Hub for the Asset Hub Migration (AHM).
+//!
+//! Both runtimes encode and decode these types when the Relay Chain sends its state to Asset Hub,
+//! so their encoding must never change in a backwards incompatible way.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
+use frame_support::traits::{Bounded, ConstU32};
+use pallet_conviction_voting::{Tally, Voting};
+use pallet_referenda::{Deposit, ReferendumInfo};
+use polkadot_parachain_primitives::primitives::{Id as ParaId, Sibling};
+use polkadot_primitives::{AccountId, AccountIndex, Balance, BlockNumber};
+use polkadot_runtime_common::{
+	claims::{EthereumAddress, StatementKind},
+	impls::VersionedLocatableAsset,
+};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256},
+	DispatchError, FixedU128, Perbill, RuntimeDebug, Saturating,
+};
+use xcm::{
+	latest::{Junction, Location},
+	VersionedLocation,
+};
+
+/// A hold reason of the Relay Chain that can be re-created on Asset Hub.
+///
+/// The Relay Chain and Asset Hub have different `RuntimeHoldReason` enums, hence holds are sent
+/// in this chain agnostic format and mapped into the local enum by the receiver.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PortableHoldReason {
+	/// `pallet_preimage::HoldReason::Preimage`.
+	#[codec(index = 0)]
+	Preimage,
+	/// `pallet_state_trie_migration::HoldReason::SlashForMigrate`.
+	#[codec(index = 1)]
+	StateTrieMigration,
+	/// `pallet_delegated_staking::HoldReason::StakingDelegation`.
+	#[codec(index = 2)]
+	DelegatedStaking,
+}
+
+/// A freeze reason of the Relay Chain that can be re-created on Asset Hub.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PortableFreezeReason {
+	/// `pallet_nomination_pools::FreezeReason::PoolMinBalance`.
+	#[codec(index = 0)]
+	NominationPools,
+}
+
+/// Mirror of `pallet_balances::Reasons`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PortableLockReasons {
+	/// Paying system transaction fees.
+	#[codec(index = 0)]
+	Fee,
+	/// Any reason other than paying system transaction fees.
+	#[codec(index = 1)]
+	Misc,
+	/// Any reason at all.
+	#[codec(index = 2)]
+	All,
+}
+
+/// Mirror of `pallet_balances::BalanceLock`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PortableLock {
+	/// The identifier of the lock.
+	pub id: [u8; 8],
+	/// The amount which the free balance may not drop below while the lock is in effect.
+	pub amount: Balance,
+	/// The reasons for which the lock applies.
+	pub reasons: PortableLockReasons,
+}
+
+/// An account with its balance, as it is sent from the Relay Chain to Asset Hub.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RcAccount {
+	/// The account address.
+	pub who: AccountId,
+	/// The total amount that was withdrawn from the Relay Chain and has to be minted on Asset Hub.
+	///
+	/// This includes the funds that have to be put on hold or reserved again.
+	pub free: Balance,
+	/// The amount that was reserved on the Relay Chain, including the holds.
+	///
+	/// Asset Hub checks that the integrated holds and reserves add up to it.
+	pub reserved: Balance,
+	/// The amount frozen by the migrated locks and freezes.
+	///
+	/// Asset Hub checks that the integrated locks and freezes raise the frozen amount of the
+	/// account to at least this amount.
+	pub frozen: Balance,
+	/// The holds of the account.
+	pub holds: Vec<(PortableHoldReason, Balance)>,
+	/// The freezes of the account.
+	pub freezes: Vec<(PortableFreezeReason, Balance)>,
+	/// The locks of the account.
+	pub locks: Vec<PortableLock>,
+	/// The reserved amount that is not accounted for by any hold.
+	pub unnamed_reserve: Balance,
+}
+
+impl RcAccount {
+	/// The total amount held or reserved by the account.
+	pub fn total_held(&self) -> Balance {
+		self.holds
+			.iter()
+			.fold(self.unnamed_reserve, |acc, (_, amount)| acc.saturating_add(*amount))
+	}
+}
+
+/// The pallets whose state is moved by the migration, in the order they are migrated.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum MigratedPallet {
+	/// `frame_system::Account` and the balances of every account.
+	#[codec(index = 0)]
+	Accounts,
+	/// `pallet_referenda`.
+	#[codec(index = 1)]
+	Referenda,
+	/// `pallet_conviction_voting`.
+	#[codec(index = 2)]
+	ConvictionVoting,
+	/// `pallet_treasury`, `pallet_bounties` and `pallet_child_bounties`.
+	#[codec(index = 3)]
+	Treasury,
+	/// `pallet_nomination_pools`.
+	#[codec(index = 4)]
+	NomPools,
+	/// `pallet_delegated_staking`.
+	#[codec(index = 5)]
+	DelegatedStaking,
+	/// `pallet_multisig`.
+	#[codec(index = 6)]
+	Multisig,
+	/// `pallet_proxy`.
+	#[codec(index = 7)]
+	Proxy,
+	/// `pallet_vesting`.
+	#[codec(index = 8)]
+	Vesting,
+	/// `polkadot_runtime_common::claims`.
+	#[codec(index = 9)]
+	Claims,
+	/// `pallet_preimage`.
+	#[codec(index = 10)]
+	Preimage,
+	/// `pallet_scheduler`.
+	#[codec(index = 11)]
+	Scheduler,
+	/// `pallet_indices`.
+	#[codec(index = 12)]
+	Indices,
+	/// The reserves of `polkadot_runtime_common::slots` and `polkadot_runtime_common::crowdloan`.
+	#[codec(index = 13)]
+	Crowdloan,
+}
+
+impl MigratedPallet {
+	/// All pallets, in the order they are migrated.
+	pub const ALL: [MigratedPallet; 14] = [
+		MigratedPallet::Accounts,
+		MigratedPallet::Referenda,
+		MigratedPallet::ConvictionVoting,
+		MigratedPallet::Treasury,
+		MigratedPallet::NomPools,
+		MigratedPallet::DelegatedStaking,
+		MigratedPallet::Multisig,
+		MigratedPallet::Proxy,
+		MigratedPallet::Vesting,
+		MigratedPallet::Claims,
+		MigratedPallet::Preimage,
+		MigratedPallet::Scheduler,
+		MigratedPallet::Indices,
+		MigratedPallet::Crowdloan,
+	];
+
+	/// The pallet migrated after this one, `None` for the last one.
+	pub fn next(&self) -> Option<MigratedPallet> {
+		let position = Self::ALL.iter().position(|pallet| pallet == self)?;
+		Self::ALL.get(position + 1).copied()
+	}
+}
+
+/// The stage of the Asset Hub Migration, shared by the Relay Chain and Asset Hub.
+///
+/// The Relay Chain drives the migration and informs Asset Hub about every change of the stage.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub enum MigrationStage {
+	/// The migration has not been scheduled yet.
+	#[default]
+	#[codec(index = 0)]
+	Pending,
+	/// The migration starts at the given Relay Chain block.
+	#[codec(index = 1)]
+	Scheduled { start: BlockNumber },
+	/// The state of the given pallet is being migrated.
+	#[codec(index = 2)]
+	Ongoing(MigratedPallet),
+	/// The migration is finished.
+	#[codec(index = 3)]
+	Done,
+}
+
+impl MigrationStage {
+	/// Whether state is being moved, during which user activity has to be paused.
+	pub fn is_ongoing(&self) -> bool {
+		matches!(self, MigrationStage::Ongoing(_))
+	}
+
+	/// Whether the state of `pallet` is fully migrated.
+	pub fn has_migrated(&self, pallet: MigratedPallet) -> bool {
+		let position =
+			|pallet: MigratedPallet| MigratedPallet::ALL.iter().position(|p| *p == pallet);
+		match self {
+			MigrationStage::Ongoing(current) => position(*current) > position(pallet),
+			MigrationStage::Done => true,
+			MigrationStage::Pending | MigrationStage::Scheduled { .. } => false,
+		}
+	}
+}
+
+/// The number of items of a pallet that were handled by the migration.
+///
+/// The Relay Chain counts the items it sent, Asset Hub the items it processed.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct PalletProgress {
+	/// The number of items sent by the Relay Chain.
+	pub sent: u32,
+	/// The number of items integrated by Asset Hub.
+	pub acknowledged: u32,
+	/// The number of items that Asset Hub failed to integrate.
+	pub failed: u32,
+}
+
+/// The progress of the migration as seen by one of the two chains.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MigrationProgress {
+	/// The current stage of the migration.
+	pub stage: MigrationStage,
+	/// The last account that was migrated, only known to the Relay Chain.
+	pub accounts_cursor: Option<AccountId>,
+	/// The number of items handled per pallet.
+	pub items: Vec<(MigratedPallet, PalletProgress)>,
+	/// The free balance of the accounts that was sent, respectively minted.
+	pub moved: Balance,
+	/// The last error that occurred while migrating or integrating items.
+	pub last_error: Option<DispatchError>,
+}
+
+/// An origin of the Relay Chain that a referendum can be submitted with.
+///
+/// Asset Hub has the same tracks and custom origins as the Relay Chain, but the origins are
+/// located in different pallets of the two runtimes.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PortableGovernanceOrigin {
+	/// `frame_system::RawOrigin::Root`.
+	#[codec(index = 0)]
+	Root,
+	#[codec(index = 1)]
+	StakingAdmin,
+	#[codec(index = 2)]
+	Treasurer,
+	#[codec(index = 3)]
+	FellowshipAdmin,
+	#[codec(index = 4)]
+	GeneralAdmin,
+	#[codec(index = 5)]
+	AuctionAdmin,
+	#[codec(index = 6)]
+	LeaseAdmin,
+	#[codec(index = 7)]
+	ReferendumCanceller,
+	#[codec(index = 8)]
+	ReferendumKiller,
+	#[codec(index = 9)]
+	SmallTipper,
+	#[codec(index = 10)]
+	BigTipper,
+	#[codec(index = 11)]
+	SmallSpender,
+	#[codec(index = 12)]
+	MediumSpender,
+	#[codec(index = 13)]
+	BigSpender,
+	#[codec(index = 14)]
+	WhitelistedCaller,
+	#[codec(index = 15)]
+	WishForChange,
+}
+
+/// A referendum of the Relay Chain.
+///
+/// All block numbers are Relay Chain block numbers. The proposal is only referenced, its call is
+/// not decoded.
+pub type PortableReferendumInfo = ReferendumInfo<
+	u16,
+	PortableGovernanceOrigin,
+	BlockNumber,
+	Bounded<(), BlakeTwo256>,
+	Balance,
+	Tally<Balance, ()>,
+	AccountId,
+	(BlockNumber, u32),
+>;
+
+/// A referendum of the Relay Chain together with its metadata.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RcReferendum {
+	/// The index of the referendum, which is kept on Asset Hub.
+	pub index: u32,
+	/// The status of the referendum.
+	pub info: PortableReferendumInfo,
+	/// The preimage hash of the metadata of the referendum.
+	pub metadata: Option<H256>,
+}
+
+/// The votes of an account in one class of the Relay Chain.
+///
+/// The prior lock expires at a Relay Chain block number. `MaxVotes` is the same on both chains.
+pub type PortableVoting = Voting<Balance, AccountId, BlockNumber, u32, ConstU32<512>>;
+
+/// The state of `pallet_conviction_voting` of a single account.
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub enum RcConvictionVotingMessage {
+	/// The votes of an account in a class.
+	#[codec(index = 0)]
+	VotingFor(AccountId, u16, PortableVoting),
+	/// The amounts that are locked per class of an account.
+	#[codec(index = 1)]
+	ClassLocksFor(AccountId, Vec<(u16, Balance)>),
+}
+
+/// A legacy treasury proposal, encoded like `pallet_treasury::Proposal`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableTreasuryProposal {
+	pub proposer: AccountId,
+	pub value: Balance,
+	pub beneficiary: AccountId,
+	pub bond: Balance,
+}
+
+/// The state of the payment of a spend, encoded like `pallet_treasury::PaymentState`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortablePaymentState {
+	#[codec(index = 0)]
+	Pending,
+	#[codec(index = 1)]
+	Attempted { id: u64 },
+	#[codec(index = 2)]
+	Failed,
+}
+
+/// A treasury spend, encoded like `pallet_treasury::SpendStatus`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableSpendStatus {
+	pub asset_kind: VersionedLocatableAsset,
+	pub amount: Balance,
+	pub beneficiary: VersionedLocation,
+	pub valid_from: BlockNumber,
+	pub expire_at: BlockNumber,
+	pub status: PortablePaymentState,
+}
+
+/// The status of a bounty, encoded like `pallet_bounties::BountyStatus`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortableBountyStatus {
+	#[codec(index = 0)]
+	Proposed,
+	#[codec(index = 1)]
+	Approved,
+	#[codec(index = 2)]
+	Funded,
+	#[codec(index = 3)]
+	CuratorProposed { curator: AccountId },
+	#[codec(index = 4)]
+	Active { curator: AccountId, update_due: BlockNumber },
+	#[codec(index = 5)]
+	PendingPayout { curator: AccountId, beneficiary: AccountId, unlock_at: BlockNumber },
+	#[codec(index = 6)]
+	ApprovedWithCurator { curator: AccountId },
+}
+
+/// A bounty, encoded like `pallet_bounties::Bounty`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableBounty {
+	pub proposer: AccountId,
+	pub value: Balance,
+	pub fee: Balance,
+	pub curator_deposit: Balance,
+	pub bond: Balance,
+	pub status: PortableBountyStatus,
+}
+
+/// The status of a child bounty, encoded like `pallet_child_bounties::ChildBountyStatus`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortableChildBountyStatus {
+	#[codec(index = 0)]
+	Added,
+	#[codec(index = 1)]
+	CuratorProposed { curator: AccountId },
+	#[codec(index = 2)]
+	Active { curator: AccountId },
+	#[codec(index = 3)]
+	PendingPayout { curator: AccountId, beneficiary: AccountId, unlock_at: BlockNumber },
+}
+
+/// A child bounty, encoded like `pallet_child_bounties::ChildBounty`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableChildBounty {
+	pub parent_bounty: u32,
+	pub value: Balance,
+	pub fee: Balance,
+	pub curator_deposit: Balance,
+	pub status: PortableChildBountyStatus,
+}
+
+/// An item of the state of `pallet_treasury`, `pallet_bounties` or `pallet_child_bounties`.
+///
+/// The funds of the treasury, the bounties and the child bounties are held by accounts derived from
+/// the same pallet ids on both chains, so they are migrated together with all other accounts.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcTreasuryMessage {
+	#[codec(index = 0)]
+	ProposalCount(u32),
+	#[codec(index = 1)]
+	Proposals(u32, PortableTreasuryProposal),
+	#[codec(index = 2)]
+	Approvals(Vec<u32>),
+	#[codec(index = 3)]
+	SpendCount(u32),
+	/// A spend, with its asset kind and beneficiary as seen from the Relay Chain.
+	#[codec(index = 4)]
+	Spends(u32, PortableSpendStatus),
+	#[codec(index = 5)]
+	BountyCount(u32),
+	#[codec(index = 6)]
+	Bounties(u32, PortableBounty),
+	#[codec(index = 7)]
+	BountyDescriptions(u32, Vec<u8>),
+	#[codec(index = 8)]
+	BountyApprovals(Vec<u32>),
+	#[codec(index = 9)]
+	ChildBountyCount(u32),
+	#[codec(index = 10)]
+	ParentChildBounties(u32, u32),
+	/// A child bounty of a parent bounty.
+	#[codec(index = 11)]
+	ChildBounties(u32, u32, PortableChildBounty),
+	#[codec(index = 12)]
+	ChildBountyDescriptions(u32, Vec<u8>),
+	#[codec(index = 13)]
+	ChildrenCuratorFees(u32, Balance),
+}
+
+/// The storage values of `pallet_nomination_pools`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct PortableNomPoolsStorageValues {
+	pub total_value_locked: Balance,
+	pub min_join_bond: Balance,
+	pub min_create_bond: Balance,
+	pub max_pools: Option<u32>,
+	pub max_pool_members: Option<u32>,
+	pub max_pool_members_per_pool: Option<u32>,
+	pub global_max_commission: Option<Perbill>,
+	pub last_pool_id: u32,
+}
+
+/// A pool member, encoded like `pallet_nomination_pools::PoolMember`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortablePoolMember {
+	pub pool_id: u32,
+	pub points: Balance,
+	pub last_recorded_reward_counter: FixedU128,
+	/// The unbonding balance per era, encoded like the `BoundedBTreeMap` of the pallet.
+	pub unbonding_eras: Vec<(u32, Balance)>,
+}
+
+/// The rate at which the commission of a pool may change, encoded like
+/// `pallet_nomination_pools::CommissionChangeRate`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableCommissionChangeRate {
+	pub max_increase: Perbill,
+	pub min_delay: BlockNumber,
+}
+
+/// Who may claim the commission of a pool, encoded like
+/// `pallet_nomination_pools::CommissionClaimPermission`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortableCommissionClaimPermission {
+	#[codec(index = 0)]
+	Permissionless,
+	#[codec(index = 1)]
+	Account(AccountId),
+}
+
+/// The commission of a pool, encoded like `pallet_nomination_pools::Commission`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableCommission {
+	pub current: Option<(Perbill, AccountId)>,
+	pub max: Option<Perbill>,
+	pub change_rate: Option<PortableCommissionChangeRate>,
+	pub throttle_from: Option<BlockNumber>,
+	pub claim_permission: Option<PortableCommissionClaimPermission>,
+}
+
+/// The roles of a pool, encoded like `pallet_nomination_pools::PoolRoles`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortablePoolRoles {
+	pub depositor: AccountId,
+	pub root: Option<AccountId>,
+	pub nominator: Option<AccountId>,
+	pub bouncer: Option<AccountId>,
+}
+
+/// The state of a pool, encoded like `pallet_nomination_pools::PoolState`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortablePoolState {
+	#[codec(index = 0)]
+	Open,
+	#[codec(index = 1)]
+	Blocked,
+	#[codec(index = 2)]
+	Destroying,
+}
+
+/// A bonded pool, encoded like `pallet_nomination_pools::BondedPoolInner`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableBondedPoolInner {
+	pub commission: PortableCommission,
+	pub member_counter: u32,
+	pub points: Balance,
+	pub roles: PortablePoolRoles,
+	pub state: PortablePoolState,
+}
+
+/// The reward pool of a pool, encoded like `pallet_nomination_pools::RewardPool`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableRewardPool {
+	pub last_recorded_reward_counter: FixedU128,
+	pub last_recorded_total_payouts: Balance,
+	pub total_rewards_claimed: Balance,
+	pub total_commission_pending: Balance,
+	pub total_commission_claimed: Balance,
+}
+
+/// An unbonding pool, encoded like `pallet_nomination_pools::UnbondPool`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableUnbondPool {
+	pub points: Balance,
+	pub balance: Balance,
+}
+
+/// The unbonding pools of a pool, encoded like `pallet_nomination_pools::SubPools`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableSubPools {
+	pub no_era: PortableUnbondPool,
+	/// The unbonding pools per era, encoded like the `BoundedBTreeMap` of the pallet.
+	pub with_era: Vec<(u32, PortableUnbondPool)>,
+}
+
+/// Who may claim the rewards of a pool member, encoded like
+/// `pallet_nomination_pools::ClaimPermission`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortableClaimPermission {
+	#[codec(index = 0)]
+	Permissioned,
+	#[codec(index = 1)]
+	PermissionlessCompound,
+	#[codec(index = 2)]
+	PermissionlessWithdraw,
+	#[codec(index = 3)]
+	PermissionlessAll,
+}
+
+/// A chunk of a staking ledger that is unlocking, encoded like `pallet_staking::UnlockChunk`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableUnlockChunk {
+	#[codec(compact)]
+	pub value: Balance,
+	#[codec(compact)]
+	pub era: u32,
+}
+
+/// A staking ledger, encoded like `pallet_staking::StakingLedger`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableStakingLedger {
+	pub stash: AccountId,
+	#[codec(compact)]
+	pub total: Balance,
+	#[codec(compact)]
+	pub active: Balance,
+	pub unlocking: Vec<PortableUnlockChunk>,
+	pub legacy_claimed_rewards: Vec<u32>,
+}
+
+/// Where staking rewards are paid to, encoded like `pallet_staking::RewardDestination`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortableRewardDestination {
+	#[codec(index = 0)]
+	Staked,
+	#[codec(index = 1)]
+	Stash,
+	#[codec(index = 2)]
+	Controller,
+	#[codec(index = 3)]
+	Account(AccountId),
+	#[codec(index = 4)]
+	None,
+}
+
+/// The nominations of a staker, encoded like `pallet_staking::Nominations`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableNominations {
+	pub targets: Vec<AccountId>,
+	pub submitted_in: u32,
+	pub suppressed: bool,
+}
+
+/// The state of `pallet_staking` of the bonded account of a pool.
+///
+/// The bonded account is its own controller and a virtual staker, whose funds are held by the
+/// delegators.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortablePoolStake {
+	pub ledger: PortableStakingLedger,
+	pub payee: Option<PortableRewardDestination>,
+	pub nominations: Option<PortableNominations>,
+}
+
+/// An item of the state of `pallet_nomination_pools`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcNomPoolsMessage {
+	/// All storage values, sent once.
+	#[codec(index = 0)]
+	StorageValues(PortableNomPoolsStorageValues),
+	#[codec(index = 1)]
+	PoolMembers(AccountId, PortablePoolMember),
+	#[codec(index = 2)]
+	BondedPools(u32, PortableBondedPoolInner),
+	#[codec(index = 3)]
+	RewardPools(u32, PortableRewardPool),
+	#[codec(index = 4)]
+	SubPoolsStorage(u32, PortableSubPools),
+	#[codec(index = 5)]
+	Metadata(u32, Vec<u8>),
+	#[codec(index = 6)]
+	ReversePoolIdLookup(AccountId, u32),
+	#[codec(index = 7)]
+	ClaimPermissions(AccountId, PortableClaimPermission),
+	/// The staking state of the bonded account of a pool, sent together with the pool.
+	#[codec(index = 8)]
+	PoolStake(PortablePoolStake),
+}
+
+/// The ledger of an agent, encoded like `pallet_delegated_staking::AgentLedger`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PortableAgentLedger {
+	pub payee: AccountId,
+	#[codec(compact)]
+	pub total_delegated: Balance,
+	#[codec(compact)]
+	pub unclaimed_withdrawals: Balance,
+	#[codec(compact)]
+	pub pending_slash: Balance,
+}
+
+/// A delegation to an agent, encoded like `pallet_delegated_staking::Delegation`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PortableDelegation {
+	pub agent: AccountId,
+	#[codec(compact)]
+	pub amount: Balance,
+}
+
+/// An item of the state of `pallet_delegated_staking`.
+///
+/// The delegated funds are held from the delegators, these holds were already migrated together
+/// with the accounts.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcDelegatedStakingMessage {
+	#[codec(index = 0)]
+	Agents(AccountId, PortableAgentLedger),
+	#[codec(index = 1)]
+	Delegators(AccountId, PortableDelegation),
+}
+
+/// A pending multisig operation, encoded like `pallet_multisig::Multisig`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableMultisig {
+	/// The block number and extrinsic index at which the operation was opened.
+	pub when: (BlockNumber, u32),
+	pub deposit: Balance,
+	pub depositor: AccountId,
+	pub approvals: Vec<AccountId>,
+}
+
+/// The deposit of a multisig operation of the Relay Chain that is refunded on Asset Hub.
+///
+/// Multisig operations are not migrated, their calls can not be executed on Asset Hub.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RcMultisig {
+	/// The account that paid the deposit.
+	pub creator: AccountId,
+	/// The part of the deposit that was migrated together with the account of the creator.
+	///
+	/// The rest was already refunded on the Relay Chain.
+	pub deposit: Balance,
+}
+
+/// A proxy definition, encoded like `pallet_proxy::ProxyDefinition` of the Relay Chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableProxyDefinition {
+	pub delegate: AccountId,
+	pub proxy_type: crate::proxy::ProxyType,
+	pub delay: BlockNumber,
+}
+
+/// An announcement of a proxy, encoded like `pallet_proxy::Announcement`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableAnnouncement {
+	pub real: AccountId,
+	pub call_hash: H256,
+	pub height: BlockNumber,
+}
+
+/// The proxies or announcements of an account.
+///
+/// The deposits are recomputed on Asset Hub, the sent deposit is the part of the Relay Chain
+/// deposit that was migrated together with the account. The rest was already refunded on the
+/// Relay Chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcProxyMessage {
+	#[codec(index = 0)]
+	Proxies { delegator: AccountId, deposit: Balance, proxies: Vec<PortableProxyDefinition> },
+	#[codec(index = 1)]
+	Announcements {
+		delegate: AccountId,
+		deposit: Balance,
+		announcements: Vec<PortableAnnouncement>,
+	},
+}
+
+/// A vesting schedule, encoded like `pallet_vesting::VestingInfo`.
+///
+/// The starting block is a Relay Chain block number on both chains, Asset Hub evaluates the
+/// schedules with the block number of the Relay Chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableVestingInfo {
+	pub locked: Balance,
+	pub per_block: Balance,
+	pub starting_block: BlockNumber,
+}
+
+impl PortableVestingInfo {
+	/// The amount that is still locked at block `n`, like `VestingInfo::locked_at`.
+	pub fn locked_at(&self, n: BlockNumber) -> Balance {
+		let vested_blocks = n.saturating_sub(self.starting_block) as Balance;
+		self.locked.saturating_sub(vested_blocks.saturating_mul(self.per_block))
+	}
+
+	/// The block at which everything is unlocked, like `VestingInfo::ending_block_as_balance`.
+	pub fn ending_block(&self) -> Balance {
+		let per_block = self.per_block.max(1);
+		let duration = self.locked.div_ceil(per_block);
+		(self.starting_block as Balance).saturating_add(duration)
+	}
+}
+
+/// The vesting schedules of an account.
+///
+/// The vesting lock is migrated together with the account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RcVestingSchedule {
+	pub who: AccountId,
+	pub schedules: Vec<PortableVestingInfo>,
+}
+
+/// An item of the pre-sale claims.
+///
+/// The claimed balance is minted when claiming, so no funds are moved with the claims.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcClaimsMessage {
+	/// The total amount that can still be claimed.
+	#[codec(index = 0)]
+	Total(Balance),
+	#[codec(index = 1)]
+	Claims(EthereumAddress, Balance),
+	/// The vesting schedule of a claim: locked, per block and starting block.
+	///
+	/// The starting block is a Relay Chain block number on both chains.
+	#[codec(index = 2)]
+	Vesting(EthereumAddress, (Balance, Balance, BlockNumber)),
+	#[codec(index = 3)]
+	Signing(EthereumAddress, StatementKind),
+	#[codec(index = 4)]
+	Preclaims(AccountId, EthereumAddress),
+}
+
+/// The maximum number of bytes of preimages that are sent in a single batch.
+///
+/// Larger preimages are split into chunks and reassembled on Asset Hub.
+pub const PREIMAGE_CHUNK_LEN: u32 = 16 * 1024;
+
+/// The request status of a preimage.
+///
+/// Encoded like `pallet_preimage::RequestStatus` with `HoldConsideration` tickets, which only
+/// contain the held amount, and like the legacy `pallet_preimage::OldRequestStatus`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PortableRequestStatus {
+	#[codec(index = 0)]
+	Unrequested { ticket: (AccountId, Balance), len: u32 },
+	#[codec(index = 1)]
+	Requested { maybe_ticket: Option<(AccountId, Balance)>, count: u32, maybe_len: Option<u32> },
+}
+
+impl PortableRequestStatus {
+	/// The account and amount of the deposit of the preimage, if any.
+	pub fn ticket(&self) -> Option<&(AccountId, Balance)> {
+		match self {
+			PortableRequestStatus::Unrequested { ticket, .. } => Some(ticket),
+			PortableRequestStatus::Requested { maybe_ticket, .. } => maybe_ticket.as_ref(),
+		}
+	}
+}
+
+/// An item of `pallet_preimage`.
+///
+/// The preimages are moved unchanged, so that they keep their hash. The deposits were migrated
+/// together with the accounts of the depositors.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcPreimageMessage {
+	/// The request status of a preimage, with its deposit held by `pallet_preimage`.
+	#[codec(index = 0)]
+	RequestStatus { hash: H256, status: PortableRequestStatus },
+	/// The legacy request status of a preimage, with its deposit reserved.
+	#[codec(index = 1)]
+	LegacyStatus { hash: H256, status: PortableRequestStatus },
+	/// The bytes `offset..offset + data.len()` of the preimage `hash` of length `len`.
+	#[codec(index = 2)]
+	Chunk { hash: H256, len: u32, offset: u32, data: Vec<u8> },
+}
+
+/// A scheduled task, encoded like `pallet_scheduler::Scheduled`.
+///
+/// Generic over the call and the origin, whose encodings differ between the two runtimes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PortableScheduled<Call, Origin> {
+	pub maybe_id: Option<[u8; 32]>,
+	pub priority: u8,
+	pub call: Call,
+	/// The period in Relay Chain blocks and the number of repetitions.
+	pub maybe_periodic: Option<(BlockNumber, u32)>,
+	pub origin: Origin,
+}
+
+/// A scheduled task of the Relay Chain.
+///
+/// The call is only referenced, it is translated on Asset Hub. Tasks with an origin that does not
+/// exist on Asset Hub have no origin and are dropped there.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RcScheduledTask {
+	/// The Relay Chain block number the task is scheduled for.
+	pub when: BlockNumber,
+	/// The index of the task in the agenda of the block.
+	pub index: u32,
+	pub task: PortableScheduled<Bounded<(), BlakeTwo256>, Option<PortableGovernanceOrigin>>,
+}
+
+/// A claimed account index of the Relay Chain.
+///
+/// The deposit was migrated as a reserve together with the account of the owner.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RcIndex {
+	pub index: AccountIndex,
+	/// The owner, already translated to its account on Asset Hub.
+	pub who: AccountId,
+	pub deposit: Balance,
+	/// Whether the index is frozen and can not be freed or transferred anymore.
+	pub frozen: bool,
+}
+
+/// A reserve of `polkadot_runtime_common::slots` or `polkadot_runtime_common::crowdloan`.
+///
+/// The reserves were migrated together with the accounts. They are released on Asset Hub once the
+/// Relay Chain reaches the given block, which is the end of the last lease of the parachain.
+/// Accounts are already translated to their account on Asset Hub.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RcCrowdloanMessage {
+	/// The amount reserved by a lease holder, which is either a crowdloan or a single account.
+	#[codec(index = 0)]
+	LeaseReserve { unreserve_block: BlockNumber, para_id: ParaId, who: AccountId, amount: Balance },
+	/// A contribution to a crowdloan, which is paid back from the account of the crowdloan.
+	#[codec(index = 1)]
+	CrowdloanContribution {
+		withdraw_block: BlockNumber,
+		para_id: ParaId,
+		contributor: AccountId,
+		crowdloan_account: AccountId,
+		amount: Balance,
+	},
+	/// The deposit of the creator of a crowdloan.
+	#[codec(index = 2)]
+	CrowdloanDeposit {
+		unreserve_block: BlockNumber,
+		para_id: ParaId,
+		depositor: AccountId,
+		amount: Balance,
+	},
+}
+
+/// Summary of the vesting schedules, compared before and after the migration by try-runtime.
+///
+/// The amounts that are still locked are sampled at the same Relay Chain block numbers on both
+/// chains.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct VestingSummary {
+	/// The number of accounts with vesting schedules.
+	pub accounts: u32,
+	/// The number of vesting schedules.
+	pub schedules: u32,
+	/// The sampled Relay Chain block numbers and the total amount locked at each of them.
+	pub locked_at: Vec<(BlockNumber, Balance)>,
+}
+
+impl VestingSummary {
+	/// A summary that samples the locked amounts at `heights`.
+	pub fn new(heights: impl IntoIterator<Item = BlockNumber>) -> Self {
+		Self { locked_at: heights.into_iter().map(|n| (n, 0)).collect(), ..Default::default() }
+	}
+
+	/// Account for the vesting schedules of a single account.
+	pub fn add_schedules(&mut self, schedules: &[PortableVestingInfo]) {
+		self.accounts.saturating_inc();
+		for schedule in schedules {
+			self.schedules.saturating_inc();
+			for (n, locked) in self.locked_at.iter_mut() {
+				locked.saturating_accrue(schedule.locked_at(*n));
+			}
+		}
+	}
+}
+
+/// Summary of the nomination pools, compared before and after the migration by try-runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+pub struct NomPoolsSummary {
+	/// The number of bonded pools.
+	pub pools: u32,
+	/// The number of pool members.
+	pub members: u32,
+	/// The sum of the points of all bonded pools.
+	pub pool_points: Balance,
+	/// The sum of the points of all pool members.
+	pub member_points: Balance,
+	/// The sum of the balances of all unbonding pools.
+	pub unbonding_balance: Balance,
+	/// The sum of all delegations.
+	pub delegated: Balance,
+	/// The sum of the bonded balances of the bonded accounts of all pools.
+	pub bonded: Balance,
+	/// The number of pools that nominate.
+	pub nominating: u32,
+}
+
+impl NomPoolsSummary {
+	/// Account for a bonded pool.
+	pub fn add_pool(&mut self, pool: &PortableBondedPoolInner) {
+		self.pools.saturating_inc();
+		self.pool_points.saturating_accrue(pool.points);
+	}
+
+	/// Account for a pool member.
+	pub fn add_member(&mut self, member: &PortablePoolMember) {
+		self.members.saturating_inc();
+		self.member_points.saturating_accrue(member.points);
+	}
+
+	/// Account for the unbonding pools of a pool.
+	pub fn add_sub_pools(&mut self, sub_pools: &PortableSubPools) {
+		for pool in
+			core::iter::once(&sub_pools.no_era).chain(sub_pools.with_era.iter().map(|p| &p.1))
+		{
+			self.unbonding_balance.saturating_accrue(pool.balance);
+		}
+	}
+
+	/// Account for a delegation.
+	pub fn add_delegation(&mut self, delegation: &PortableDelegation) {
+		self.delegated.saturating_accrue(delegation.amount);
+	}
+
+	/// Account for the staking ledger of the bonded account of a pool.
+	pub fn add_ledger(&mut self, ledger: &PortableStakingLedger, nominating: bool) {
+		self.bonded.saturating_accrue(ledger.total);
+		if nominating {
+			self.nominating.saturating_inc();
+		}
+	}
+}
+
+/// The account of a parachain on Asset Hub, for its sovereign account on the Relay Chain.
+///
+/// Parachains derive their account on the Relay Chain as a child (`ChildParachainConvertsVia`)
+/// and on Asset Hub as a sibling (`SiblingParachainConvertsVia`). Returns `None` for all accounts
+/// that are not the sovereign account of a parachain.
+pub fn translate_para_sovereign_account(who: &AccountId) -> Option<(ParaId, AccountId)> {
+	let para_id = ParaId::try_from_account(who)?;
+	Some((para_id, sibling_sovereign_account(para_id)))
+}
+
+/// The sovereign account of the parachain `para_id` on Asset Hub.
+pub fn sibling_sovereign_account(para_id: ParaId) -> AccountId {
+	Sibling::from(para_id).into_account_truncating()
+}
+
+/// The account on Asset Hub of the Relay Chain account `who`.
+///
+/// This is `who` itself, unless it is the sovereign account of a parachain.
+pub fn translate_account(who: AccountId) -> AccountId {
+	translate_para_sovereign_account(&who).map_or(who, |(_, ah_who)| ah_who)
+}
+
+/// An item of the Relay Chain that refers to accounts.
+///
+/// All accounts are replaced with their account on Asset Hub before the item is sent, like the
+/// migrated accounts themselves.
+pub trait TranslateAccounts {
+	/// Replace all accounts with their account on Asset Hub, see [`translate_account`].
+	fn translate_accounts(self) -> Self;
+}
+
+impl<I: TranslateAccounts> TranslateAccounts for Vec<I> {
+	fn translate_accounts(self) -> Self {
+		self.into_iter().map(TranslateAccounts::translate_accounts).collect()
+	}
+}
+
+impl TranslateAccounts for RcReferendum {
+	fn translate_accounts(self) -> Self {
+		let translate_deposit = |deposit: Deposit<AccountId, Balance>| Deposit {
+			who: translate_account(deposit.who),
+			..deposit
+		};
+		let info = match self.info {
+			ReferendumInfo::Ongoing(mut status) => {
+				status.submission_deposit = translate_deposit(status.submission_deposit);
+				status.decision_deposit = status.decision_deposit.map(translate_deposit);
+				ReferendumInfo::Ongoing(status)
+			},
+			ReferendumInfo::Approved(moment, submission, decision) => ReferendumInfo::Approved(
+				moment,
+				submission.map(translate_deposit),
+				decision.map(translate_deposit),
+			),
+			ReferendumInfo::Rejected(moment, submission, decision) => ReferendumInfo::Rejected(
+				moment,
+				submission.map(translate_deposit),
+				decision.map(translate_deposit),
+			),
+			ReferendumInfo::Cancelled(moment, submission, decision) => ReferendumInfo::Cancelled(
+				moment,
+				submission.map(translate_deposit),
+				decision.map(translate_deposit),
+			),
+			ReferendumInfo::TimedOut(moment, submission, decision) => ReferendumInfo::TimedOut(
+				moment,
+				submission.map(translate_deposit),
+				decision.map(translate_deposit),
+			),
+			info @ ReferendumInfo::Killed(_) => info,
+		};
+		RcReferendum { info, ..self }
+	}
+}
+
+impl TranslateAccounts for RcConvictionVotingMessage {
+	fn translate_accounts(self) -> Self {
+		match self {
+			RcConvictionVotingMessage::VotingFor(who, class, voting) => {
+				let voting = match voting {
+					Voting::Delegating(mut delegating) => {
+						delegating.target = translate_account(delegating.target);
+						Voting::Delegating(delegating)
+					},
+					voting @ Voting::Casting(_) => voting,
+				};
+				RcConvictionVotingMessage::VotingFor(translate_account(who), class, voting)
+			},
+			RcConvictionVotingMessage::ClassLocksFor(who, locks) =>
+				RcConvictionVotingMessage::ClassLocksFor(translate_account(who), locks),
+		}
+	}
+}
+
+impl TranslateAccounts for RcTreasuryMessage {
+	fn translate_accounts(self) -> Self {
+		use PortableBountyStatus as Status;
+		use PortableChildBountyStatus as ChildStatus;
+		use RcTreasuryMessage::*;
+		match self {
+			Proposals(id, proposal) => Proposals(
+				id,
+				PortableTreasuryProposal {
+					proposer: translate_account(proposal.proposer),
+					beneficiary: translate_account(proposal.beneficiary),
+					..proposal
+				},
+			),
+			Spends(id, mut spend) => {
+				spend.beneficiary = translate_location(spend.beneficiary);
+				Spends(id, spend)
+			},
+			Bounties(id, mut bounty) => {
+				bounty.proposer = translate_account(bounty.proposer);
+				bounty.status = match bounty.status {
+					Status::CuratorProposed { curator } =>
+						Status::CuratorProposed { curator: translate_account(curator) },
+					Status::Active { curator, update_due } =>
+						Status::Active { curator: translate_account(curator), update_due },
+					Status::PendingPayout { curator, beneficiary, unlock_at } =>
+						Status::PendingPayout {
+							curator: translate_account(curator),
+							beneficiary: translate_account(beneficiary),
+							unlock_at,
+						},
+					Status::ApprovedWithCurator { curator } =>
+						Status::ApprovedWithCurator { curator: translate_account(curator) },
+					status @ (Status::Proposed | Status::Approved | Status::Funded) => status,
+				};
+				Bounties(id, bounty)
+			},
+			ChildBounties(parent, id, mut child_bounty) => {
+				child_bounty.status = match child_bounty.status {
+					ChildStatus::CuratorProposed { curator } =>
+						ChildStatus::CuratorProposed { curator: translate_account(curator) },
+					ChildStatus::Active { curator } =>
+						ChildStatus::Active { curator: translate_account(curator) },
+					ChildStatus::PendingPayout { curator, beneficiary, unlock_at } =>
+						ChildStatus::PendingPayout {
+							curator: translate_account(curator),
+							beneficiary: translate_account(beneficiary),
+							unlock_at,
+						},
+					status @ ChildStatus::Added => status,
+				};
+				ChildBounties(parent, id, child_bounty)
+			},
+			message @ (ProposalCount(_) |
+			Approvals(_) |
+			SpendCount(_) |
+			BountyCount(_) |
+			BountyDescriptions(..) |
+			BountyApprovals(_) |
+			ChildBountyCount(_) |
+			ParentChildBounties(..) |
+			ChildBountyDescriptions(..) |
+			ChildrenCuratorFees(..)) => message,
+		}
+	}
+}
+
+/// Translate the account of a location that is a local account of the Relay Chain.
+fn translate_location(location: VersionedLocation) -> VersionedLocation {
+	let Ok(local) = Location::try_from(location.clone()) else { return location };
+	match local.unpack() {
+		(0, [Junction::AccountId32 { network, id }]) => {
+			let translated: [u8; 32] = translate_account(AccountId::from(*id)).into();
+			if translated == *id {
+				return location
+			}
+			Location::new(0, [Junction::AccountId32 { network: *network, id: translated }]).into()
+		},
+		_ => location,
+	}
+}
+
+impl TranslateAccounts for RcScheduledTask {
+	fn translate_accounts(self) -> Self {
+		// Tasks are only migrated with governance origins, which do not refer to accounts, and
+		// their calls are translated on Asset Hub.
+		self
+	}
+}
+
+impl TranslateAccounts for RcNomPoolsMessage {
+	fn translate_accounts(self) -> Self {
+		use RcNomPoolsMessage::*;
+		match self {
+			PoolMembers(who, member) => PoolMembers(translate_account(who), member),
+			BondedPools(id, mut pool) => {
+				let roles = &mut pool.roles;
+				roles.depositor = translate_account(roles.depositor.clone());
+				roles.root = roles.root.take().map(translate_account);
+				roles.nominator = roles.nominator.take().map(translate_account);
+				roles.bouncer = roles.bouncer.take().map(translate_account);
+				let commission = &mut pool.commission;
+				commission.current =
+					commission.current.take().map(|(rate, who)| (rate, translate_account(who)));
+				if let Some(PortableCommissionClaimPermission::Account(who)) =
+					commission.claim_permission.as_mut()
+				{
+					*who = translate_account(who.clone());
+				}
+				BondedPools(id, pool)
+			},
+			ReversePoolIdLookup(who, id) => ReversePoolIdLookup(translate_account(who), id),
+			ClaimPermissions(who, permission) =>
+				ClaimPermissions(translate_account(who), permission),
+			PoolStake(mut stake) => {
+				stake.ledger.stash = translate_account(stake.ledger.stash);
+				if let Some(PortableRewardDestination::Account(who)) = stake.payee.as_mut() {
+					*who = translate_account(who.clone());
+				}
+				if let Some(nominations) = stake.nominations.as_mut() {
+					nominations.targets = core::mem::take(&mut nominations.targets)
+						.into_iter()
+						.map(translate_account)
+						.collect();
+				}
+				PoolStake(stake)
+			},
+			message @ (StorageValues(_) | RewardPools(..) | SubPoolsStorage(..) | Metadata(..)) =>
+				message,
+		}
+	}
+}
+
+impl TranslateAccounts for RcDelegatedStakingMessage {
+	fn translate_accounts(self) -> Self {
+		match self {
+			RcDelegatedStakingMessage::Agents(agent, mut ledger) => {
+				ledger.payee = translate_account(ledger.payee);
+				RcDelegatedStakingMessage::Agents(translate_account(agent), ledger)
+			},
+			RcDelegatedStakingMessage::Delegators(delegator, mut delegation) => {
+				delegation.agent = translate_account(delegation.agent);
+				RcDelegatedStakingMessage::Delegators(translate_account(delegator), delegation)
+			},
+		}
+	}
+}
+
+impl TranslateAccounts for RcMultisig {
+	fn translate_accounts(self) -> Self {
+		RcMultisig { creator: translate_account(self.creator), ..self }
+	}
+}
+
+impl TranslateAccounts for RcProxyMessage {
+	fn translate_accounts(self) -> Self {
+		match self {
+			RcProxyMessage::Proxies { delegator, deposit, proxies } => RcProxyMessage::Proxies {
+				delegator: translate_account(delegator),
+				deposit,
+				proxies: proxies
+					.into_iter()
+					.map(|proxy| PortableProxyDefinition {
+						delegate: translate_account(proxy.delegate),
+						..proxy
+					})
+					.collect(),
+			},
+			RcProxyMessage::Announcements { delegate, deposit, announcements } =>
+				RcProxyMessage::Announcements {
+					delegate: translate_account(delegate),
+					deposit,
+					announcements: announcements
+						.into_iter()
+						.map(|announcement| PortableAnnouncement {
+							real: translate_account(announcement.real),
+							..announcement
+						})
+						.collect(),
+				},
+		}
+	}
+}
+
+impl TranslateAccounts for RcVestingSchedule {
+	fn translate_accounts(self) -> Self {
+		RcVestingSchedule { who: translate_account(self.who), ..self }
+	}
+}
+
+impl TranslateAccounts for RcClaimsMessage {
+	fn translate_accounts(self) -> Self {
+		match self {
+			RcClaimsMessage::Preclaims(who, address) =>
+				RcClaimsMessage::Preclaims(translate_account(who), address),
+			message => message,
+		}
+	}
+}
+
+impl TranslateAccounts for RcPreimageMessage {
+	fn translate_accounts(self) -> Self {
+		let translate_ticket =
+			|(who, amount): (AccountId, Balance)| (translate_account(who), amount);
+		let translate_status = |status: PortableRequestStatus| match status {
+			PortableRequestStatus::Unrequested { ticket, len } =>
+				PortableRequestStatus::Unrequested { ticket: translate_ticket(ticket), len },
+			PortableRequestStatus::Requested { maybe_ticket, count, maybe_len } =>
+				PortableRequestStatus::Requested {
+					maybe_ticket: maybe_ticket.map(translate_ticket),
+					count,
+					maybe_len,
+				},
+		};
+		match self {
+			RcPreimageMessage::RequestStatus { hash, status } =>
+				RcPreimageMessage::RequestStatus { hash, status: translate_status(status) },
+			RcPreimageMessage::LegacyStatus { hash, status } =>
+				RcPreimageMessage::LegacyStatus { hash, status: translate_status(status) },
+			message @ RcPreimageMessage::Chunk { .. } => message,
+		}
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Lookup of the accounts that are moved to a different account by the migration.
+	pub trait AhMigrationApi {
+		/// The account on Asset Hub of the Relay Chain account `who`.
+		///
+		/// This is `who` itself, unless it is the sovereign account of a parachain.
+		fn translated_account(who: AccountId) -> AccountId;
+		/// The account on Asset Hub of the parachain `para_id`.
+		fn para_sovereign_account(para_id: u32) -> AccountId;
+	}
+
+	/// Progress of the migration, for monitoring and try-runtime checks.
+	pub trait AhMigrationProgressApi {
+		/// The progress of the migration on this chain.
+		fn migration_progress() -> MigrationProgress;
+	}
+}
+
+/// Re-interpret `value` as a type with the same encoding.
+///
+/// Used for types that only differ in their generic parameters between the two runtimes, e.g.
+/// `Bounded<RuntimeCall, _>` or `Tally<Balance, Total>`.
+pub fn transcode<A: Encode, B: Decode>(value: &A) -> Result<B, codec::Error> {
+	B::decode_all(&mut &value.encode()[..])
+}
@@ -18,6 +18,8 @@
 
 extern crate alloc;
 
+pub mod ahm;
+pub mod staking;
 pub mod weights;
 
 pub use self::currency::DOLLARS;
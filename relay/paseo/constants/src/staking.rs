@@ -0,0 +1,108 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Types shared between the Relay Chain and Asset Hub for staking on Asset Hub.
+//!
+//! The elections run on Asset Hub, while the sessions, the block authorship and the offences stay
+//! on the Relay Chain. Both chains report to each other with the messages below, so their
+//! encoding must never change in a backwards incompatible way.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use polkadot_primitives::{AccountId, Balance, SessionIndex};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedPointNumber, FixedU128, Perbill, RuntimeDebug};
+
+/// The total issuance the yearly emission is calculated from.
+///
+/// TI at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139),
+/// block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
+pub const FIXED_TOTAL_ISSUANCE: Balance = 1_487_502_468_008_283_162;
+
+/// The yearly inflation rate, as a share of [`FIXED_TOTAL_ISSUANCE`].
+pub const INFLATION_RATE: Perbill = Perbill::from_percent(8);
+
+/// The share of the era emission that goes to the treasury, as per ref 1139.
+pub const TREASURY_SHARE: Perbill = Perbill::from_percent(15);
+
+/// The payout of an era that lasted `era_duration_millis`, split into the payout of the stakers
+/// and the one of the treasury.
+///
+/// Used by the `EraPayout` of the Relay Chain and of Asset Hub, so that both pay the same.
+pub fn era_payout(
+	fixed_total_issuance: Balance,
+	inflation_rate: Perbill,
+	treasury_share: Perbill,
+	era_duration_millis: u64,
+) -> (Balance, Balance) {
+	const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+	// A normal-sized era will have 1 / 365.25 here:
+	let relative_era_len =
+		FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
+
+	let yearly_emission = inflation_rate * fixed_total_issuance;
+	let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
+	let to_treasury = treasury_share * era_emission;
+	let to_stakers = era_emission.saturating_sub(to_treasury);
+
+	(to_stakers, to_treasury)
+}
+
+/// Where the validator set of the Relay Chain comes from.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub enum SessionClientMode {
+	/// The validators are elected by `pallet_staking` on the Relay Chain.
+	#[default]
+	#[codec(index = 0)]
+	Local,
+	/// The validators are elected on Asset Hub.
+	///
+	/// The Relay Chain reports the end of every session, the reward points and the offences to
+	/// Asset Hub, which answers with the new validator sets.
+	#[codec(index = 1)]
+	AssetHub,
+}
+
+/// The end of a session on the Relay Chain, reported to Asset Hub.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SessionReport {
+	/// The index of the session that ended.
+	pub end_index: SessionIndex,
+	/// The reward points that the validators collected since the last report.
+	pub validator_points: Vec<(AccountId, u32)>,
+}
+
+/// The offences of validators of the Relay Chain, reported to Asset Hub.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OffenceReport {
+	/// The session in which the offences were committed.
+	pub session: SessionIndex,
+	/// The offending validators and the fraction of their stake to slash.
+	pub offences: Vec<(AccountId, Perbill)>,
+}
+
+/// A new validator set elected on Asset Hub, reported to the Relay Chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ValidatorSetReport {
+	/// The session of the Relay Chain for which Asset Hub planned the validator set.
+	///
+	/// The Relay Chain activates the set at its next session rotation.
+	pub planned_session: SessionIndex,
+	/// The validators.
+	pub validators: Vec<AccountId>,
+}
@@ -0,0 +1,280 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `frame_system::Account` and the balances of every account.
+//!
+//! Every account is unlocked on the Relay Chain: holds are released, freezes thawed, locks
+//! removed and reserves unreserved. The free balance is then withdrawn and credited to the
+//! checking account, exactly like a teleport. The withdrawn amount is sent together with the
+//! holds, freezes, locks and reserves to Asset Hub, which mints it and re-creates them.
+//!
+//! The pallets that stay active on the Relay Chain keep their funds there: the locks of
+//! [`Config::RcOnlyLocks`], like the one of staking, and the reserves of
+//! [`Config::RcOnlyReserves`], like the deposits of parachains and HRMP channels, are neither
+//! removed nor sent. Accounts that still have consumers keep the existential deposit on the Relay
+//! Chain.
+//!
+//! The sovereign accounts of parachains are derived differently on Asset Hub, they are migrated to
+//! the account that the parachain controls there.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		fungible::{Inspect, Mutate, MutateFreeze, MutateHold},
+		tokens::{Fortitude, Precision, Preservation},
+		Contains, LockableCurrency, NamedReservableCurrency, ReservableCurrency,
+	},
+	weights::Weight,
+};
+use pallet_balances::Reasons;
+use paseo_runtime_constants::ahm::{
+	translate_para_sovereign_account, MigratedPallet, PortableLock, PortableLockReasons,
+};
+use polkadot_primitives::{AccountId, Balance};
+use sp_core::Get;
+use sp_runtime::{traits::Convert, DispatchError, Saturating};
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of accounts, starting after `last_key`.
+	///
+	/// Returns the cursor to continue from, or `None` once all accounts are migrated, together
+	/// with the consumed weight. If the batch cannot be sent, nothing is committed.
+	pub fn migrate_accounts(
+		last_key: Option<AccountId>,
+	) -> (Result<Option<AccountId>, DispatchError>, Weight) {
+		let keys: Vec<AccountId> = match last_key {
+			Some(ref last_key) => frame_system::Account::<T>::iter_keys_from(
+				frame_system::Account::<T>::hashed_key_for(last_key),
+			),
+			None => frame_system::Account::<T>::iter_keys(),
+		}
+		.take(T::MaxAccountsPerBlock::get() as usize)
+		.collect();
+
+		let mut weight = T::DbWeight::get().reads(keys.len() as u64 + 1);
+		let Some(next_key) = keys.last().cloned() else { return (Ok(None), weight) };
+
+		let result = with_transaction(|| {
+			let mut batch = Vec::new();
+			for who in keys.iter() {
+				weight.saturating_accrue(Self::account_weight(Self::account_items(who)));
+				let withdrawn = with_transaction(|| match Self::withdraw_account(who) {
+					Ok(account) => TransactionOutcome::Commit(Ok(account)),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				});
+				match withdrawn {
+					Ok(Some(account)) => batch.push(account),
+					Ok(None) => {},
+					Err(error) => {
+						log::warn!(
+							target: LOG_TARGET,
+							"Failed to withdraw account {:?}: {:?}",
+							who,
+							error
+						);
+						LastError::<T>::put(error);
+						Self::deposit_event(Event::AccountWithdrawFailed {
+							who: who.clone(),
+							error,
+						});
+					},
+				}
+			}
+
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(()))
+			}
+			match Self::send_accounts(batch) {
+				Ok(()) => TransactionOutcome::Commit(Ok(())),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result.map(|()| Some(next_key)), weight)
+	}
+
+	/// Unlock and withdraw all funds of `who` that can leave the Relay Chain.
+	///
+	/// The locks of [`Config::RcOnlyLocks`] and the reserves noted in [`KeptReserves`] stay on
+	/// the Relay Chain. Returns `None` if there is nothing to migrate for this account.
+	pub(crate) fn withdraw_account(who: &AccountId) -> Result<Option<RcAccount>, DispatchError> {
+		if *who == T::CheckingAccount::get() {
+			return Ok(None)
+		}
+		let info = frame_system::Account::<T>::get(who);
+		if info.data.free.saturating_add(info.data.reserved) == 0 {
+			return Ok(None)
+		}
+
+		let mut unnamed_reserve: Balance = 0;
+
+		let mut holds = Vec::new();
+		for hold in pallet_balances::Holds::<T>::get(who) {
+			<Balances<T> as MutateHold<_>>::release(&hold.id, who, hold.amount, Precision::Exact)?;
+			match T::PortableHoldReason::convert(hold.id) {
+				Some(reason) => holds.push((reason, hold.amount)),
+				None => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Hold {:?} of {:?} has no portable reason, migrating it as a reserve",
+						hold.id,
+						who
+					);
+					unnamed_reserve.saturating_accrue(hold.amount);
+				},
+			}
+		}
+
+		let mut freezes = Vec::new();
+		for freeze in pallet_balances::Freezes::<T>::get(who) {
+			<Balances<T> as MutateFreeze<_>>::thaw(&freeze.id, who)?;
+			match T::PortableFreezeReason::convert(freeze.id) {
+				Some(reason) => freezes.push((reason, freeze.amount)),
+				None => log::warn!(
+					target: LOG_TARGET,
+					"Freeze {:?} of {:?} has no portable reason, dropping it",
+					freeze.id,
+					who
+				),
+			}
+		}
+
+		let mut locks = Vec::new();
+		for lock in pallet_balances::Locks::<T>::get(who) {
+			if T::RcOnlyLocks::contains(&lock.id) {
+				continue
+			}
+			<Balances<T> as LockableCurrency<_>>::remove_lock(lock.id, who);
+			let reasons = match lock.reasons {
+				Reasons::Fee => PortableLockReasons::Fee,
+				Reasons::Misc => PortableLockReasons::Misc,
+				Reasons::All => PortableLockReasons::All,
+			};
+			locks.push(PortableLock { id: lock.id, amount: lock.amount, reasons });
+		}
+
+		for reserve in pallet_balances::Reserves::<T>::get(who) {
+			let missing = <Balances<T> as NamedReservableCurrency<_>>::unreserve_named(
+				&reserve.id,
+				who,
+				reserve.amount,
+			);
+			unnamed_reserve.saturating_accrue(reserve.amount.saturating_sub(missing));
+		}
+		let reserved = <Balances<T> as ReservableCurrency<_>>::reserved_balance(who);
+		let kept_reserve = KeptReserves::<T>::get(who).min(reserved);
+		let unreserve = reserved.saturating_sub(kept_reserve);
+		let missing = <Balances<T> as ReservableCurrency<_>>::unreserve(who, unreserve);
+		unnamed_reserve.saturating_accrue(unreserve.saturating_sub(missing));
+
+		let frozen = locks
+			.iter()
+			.map(|lock| lock.amount)
+			.chain(freezes.iter().map(|(_, amount)| *amount))
+			.max()
+			.unwrap_or_default();
+
+		// Accounts with consumers can not be reaped and keep the existential deposit. The kept
+		// locks are respected by withdrawing politely.
+		let teleportable = <Balances<T> as Inspect<_>>::reducible_balance(
+			who,
+			Preservation::Expendable,
+			Fortitude::Polite,
+		);
+		let burned = <Balances<T> as Mutate<_>>::burn_from(
+			who,
+			teleportable,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		<Balances<T> as Mutate<_>>::mint_into(&T::CheckingAccount::get(), burned)?;
+
+		let ah_who = match translate_para_sovereign_account(who) {
+			Some((para_id, ah_who)) => {
+				Self::deposit_event(Event::AccountTranslated {
+					para_id: para_id.into(),
+					from: who.clone(),
+					to: ah_who.clone(),
+				});
+				ah_who
+			},
+			None => who.clone(),
+		};
+
+		Ok(Some(RcAccount {
+			who: ah_who,
+			free: burned,
+			reserved: info.data.reserved.saturating_sub(kept_reserve),
+			frozen,
+			holds,
+			freezes,
+			locks,
+			unnamed_reserve,
+		}))
+	}
+
+	/// Send a batch of withdrawn accounts to Asset Hub.
+	fn send_accounts(accounts: Vec<RcAccount>) -> Result<(), Error<T>> {
+		let count = accounts.len() as u32;
+		let total = accounts.iter().fold(0, |acc: Balance, a| acc.saturating_add(a.free));
+		let weight = T::AhWeightPerAccount::get().saturating_mul(count as u64);
+
+		Self::send_to_asset_hub(AhMigratorCalls::ReceiveAccounts(accounts), weight)?;
+		SentItems::<T>::mutate(MigratedPallet::Accounts, |sent| sent.saturating_accrue(count));
+		MovedBalance::<T>::mutate(|moved| moved.saturating_accrue(total));
+		Self::deposit_event(Event::AccountsBatchSent { count, total });
+		Ok(())
+	}
+
+	/// Note the reserves of [`Config::RcOnlyReserves`] in [`KeptReserves`].
+	///
+	/// Returns the weight of collecting and noting the reserves.
+	pub(crate) fn note_kept_reserves() -> Weight {
+		let (reserves, mut weight) = T::RcOnlyReserves::rc_only_reserves();
+		weight.saturating_accrue(T::DbWeight::get().writes(reserves.len() as u64));
+		for (who, amount) in reserves {
+			KeptReserves::<T>::insert(who, amount);
+		}
+		weight
+	}
+
+	/// The number of holds, freezes, locks and named reserves of `who`.
+	fn account_items(who: &AccountId) -> u32 {
+		[
+			pallet_balances::Holds::<T>::decode_len(who),
+			pallet_balances::Freezes::<T>::decode_len(who),
+			pallet_balances::Locks::<T>::decode_len(who),
+			pallet_balances::Reserves::<T>::decode_len(who),
+		]
+		.into_iter()
+		.map(|len| len.unwrap_or_default() as u32)
+		.sum()
+	}
+
+	/// The weight to migrate a single account with `items` holds, freezes, locks and named
+	/// reserves on the Relay Chain.
+	fn account_weight(items: u32) -> Weight {
+		// The account, its holds, freezes, locks and reserves, its kept reserve and the checking
+		// account are read, every item is released and written.
+		T::DbWeight::get()
+			.reads_writes(7, 6)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(items as u64))
+	}
+}
@@ -0,0 +1,88 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Switch of the issuer of the native token from the Relay Chain to Asset Hub.
+//!
+//! Before the switch the checking account of the Relay Chain holds the balance that was teleported
+//! to other chains (`MintLocation::Local`). After the switch it holds the balance that can be
+//! teleported out of the Relay Chain (`MintLocation::NonLocal`), which is all balance on the Relay
+//! Chain, and Asset Hub tracks the balance on all other chains.
+//!
+//! The checking accounts are excluded from the circulating supply of their chain, so the sum of
+//! the circulating supplies of all chains stays the same.
+
+use super::*;
+use frame_support::{
+	ensure,
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::{Fortitude, Precision, Preservation},
+	},
+};
+use polkadot_primitives::AccountId;
+use sp_core::Get;
+use sp_runtime::DispatchError;
+use xcm_builder::MintLocation;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// The checking account of the native token and where the token is minted.
+	pub fn teleport_tracking() -> Option<(AccountId, MintLocation)> {
+		let location = if TeleportsTrackedOnAssetHub::<T>::get() {
+			MintLocation::NonLocal
+		} else {
+			MintLocation::Local
+		};
+		Some((T::CheckingAccount::get(), location))
+	}
+
+	/// Set the checking account to the balance on the Relay Chain and track the teleports as
+	/// `MintLocation::NonLocal`.
+	///
+	/// Returns the total issuance of the Relay Chain before the switch, from which Asset Hub
+	/// derives the balance of its checking account.
+	pub(crate) fn do_switch_teleport_tracking() -> Result<Balance, DispatchError> {
+		ensure!(!TeleportsTrackedOnAssetHub::<T>::get(), Error::<T>::AlreadySwitched);
+
+		let checking_account = T::CheckingAccount::get();
+		let total_issuance = <Balances<T> as Inspect<_>>::total_issuance();
+		let checking_balance = <Balances<T> as Inspect<_>>::total_balance(&checking_account);
+		let local_balance = total_issuance.saturating_sub(checking_balance);
+
+		if checking_balance > local_balance {
+			<Balances<T> as Mutate<_>>::burn_from(
+				&checking_account,
+				checking_balance - local_balance,
+				Preservation::Expendable,
+				Precision::Exact,
+				Fortitude::Force,
+			)?;
+		} else {
+			<Balances<T> as Mutate<_>>::mint_into(
+				&checking_account,
+				local_balance - checking_balance,
+			)?;
+		}
+		TeleportsTrackedOnAssetHub::<T>::put(true);
+
+		Self::deposit_event(Event::TeleportTrackingSwitched {
+			rc_total_issuance: total_issuance,
+			checking_balance: local_balance,
+		});
+		Ok(total_issuance)
+	}
+}
@@ -0,0 +1,131 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of the pre-sale claims of `polkadot_runtime_common::claims`.
+//!
+//! The claims, their vesting schedules, statements and pre-claims are removed from the Relay Chain
+//! as they are sent. The claimed balances are minted when claiming, so no funds are moved.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{MigratedPallet, RcClaimsMessage};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+/// Storage items of `polkadot_runtime_common::claims` that are not public.
+pub(crate) mod alias {
+	use frame_support::{
+		pallet_prelude::{OptionQuery, ValueQuery},
+		storage_alias, Identity,
+	};
+	use polkadot_primitives::{AccountId, Balance, BlockNumber};
+	use polkadot_runtime_common::claims::{self, EthereumAddress, StatementKind};
+
+	#[storage_alias]
+	pub type Claims<T: claims::Config> =
+		StorageMap<claims::Pallet<T>, Identity, EthereumAddress, Balance, OptionQuery>;
+
+	#[storage_alias]
+	pub type Total<T: claims::Config> = StorageValue<claims::Pallet<T>, Balance, ValueQuery>;
+
+	#[storage_alias]
+	pub type Vesting<T: claims::Config> = StorageMap<
+		claims::Pallet<T>,
+		Identity,
+		EthereumAddress,
+		(Balance, Balance, BlockNumber),
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub type Signing<T: claims::Config> =
+		StorageMap<claims::Pallet<T>, Identity, EthereumAddress, StatementKind, OptionQuery>;
+
+	#[storage_alias]
+	pub type Preclaims<T: claims::Config> =
+		StorageMap<claims::Pallet<T>, Identity, AccountId, EthereumAddress, OptionQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of claims items.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_claims() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::claims_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let batch = Self::take_claims_messages(max);
+			let done = batch.len() < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Claims,
+				AhMigratorCalls::ReceiveClaimsMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Take up to `max` items from storage.
+	fn take_claims_messages(max: usize) -> Vec<RcClaimsMessage> {
+		let mut batch = Vec::new();
+
+		if alias::Total::<T>::exists() {
+			batch.push(RcClaimsMessage::Total(alias::Total::<T>::take()));
+		}
+		batch.extend(
+			alias::Claims::<T>::drain()
+				.take(max - batch.len())
+				.map(|(who, amount)| RcClaimsMessage::Claims(who, amount)),
+		);
+		batch.extend(
+			alias::Vesting::<T>::drain()
+				.take(max - batch.len())
+				.map(|(who, schedule)| RcClaimsMessage::Vesting(who, schedule)),
+		);
+		batch.extend(
+			alias::Signing::<T>::drain()
+				.take(max - batch.len())
+				.map(|(who, statement)| RcClaimsMessage::Signing(who, statement)),
+		);
+		batch.extend(
+			alias::Preclaims::<T>::drain()
+				.take(max - batch.len())
+				.map(|(who, address)| RcClaimsMessage::Preclaims(who, address)),
+		);
+
+		batch
+	}
+
+	/// Upper bound of the weight to migrate a single item on the Relay Chain.
+	fn claims_weight() -> Weight {
+		// The item is read and removed.
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
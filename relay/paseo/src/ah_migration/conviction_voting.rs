@@ -0,0 +1,95 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_conviction_voting`.
+//!
+//! The votes and the class locks of every account are removed from the Relay Chain as they are
+//! sent. The balance locks themselves were already migrated together with the accounts.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{transcode, MigratedPallet};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of votes and class locks.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_conviction_voting() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::conviction_voting_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let (mut batch, mut drained) = (Vec::new(), 0);
+
+			for (who, class, voting) in pallet_conviction_voting::VotingFor::<T>::drain().take(max)
+			{
+				drained += 1;
+				match (transcode(&class), transcode(&voting)) {
+					(Ok(class), Ok(voting)) =>
+						batch.push(RcConvictionVotingMessage::VotingFor(who, class, voting)),
+					_ => log::error!(
+						target: LOG_TARGET,
+						"Votes of {:?} in class {:?} can not be converted, dropping them",
+						who,
+						class
+					),
+				}
+			}
+			let remaining = max.saturating_sub(drained);
+			for (who, locks) in
+				pallet_conviction_voting::ClassLocksFor::<T>::drain().take(remaining)
+			{
+				drained += 1;
+				match transcode(&locks) {
+					Ok(locks) => batch.push(RcConvictionVotingMessage::ClassLocksFor(who, locks)),
+					Err(_) => log::error!(
+						target: LOG_TARGET,
+						"Class locks of {:?} can not be converted, dropping them",
+						who
+					),
+				}
+			}
+
+			let done = drained < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::ConvictionVoting,
+				AhMigratorCalls::ReceiveConvictionVotingMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Upper bound of the weight to migrate a single message on the Relay Chain.
+	fn conviction_voting_weight() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
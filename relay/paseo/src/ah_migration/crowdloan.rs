@@ -0,0 +1,276 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of the reserves of `polkadot_runtime_common::slots` and
+//! `polkadot_runtime_common::crowdloan`.
+//!
+//! The reserves of the lease holders, the crowdloan contributions and the crowdloan deposits were
+//! migrated together with the accounts. Asset Hub releases them once the Relay Chain reaches the
+//! end of the last lease of the parachain, so only that block is sent here. Crowdloans without a
+//! lease are released at their end.
+//!
+//! The leases and crowdloans stay on the Relay Chain, they still decide which parachains are
+//! scheduled. The contributions are removed, so that they can only be withdrawn on Asset Hub.
+//!
+//! The registration deposits of `paras_registrar` are not migrated. They are part of the
+//! reserves of `Config::RcOnlyReserves`, which stay reserved on the Relay Chain and are released
+//! there when the parachain is deregistered.
+
+use super::*;
+use codec::MaxEncodedLen;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{translate_account, MigratedPallet, RcCrowdloanMessage};
+use polkadot_parachain_primitives::primitives::Id as ParaId;
+use polkadot_primitives::{AccountId, Balance, BlockNumber};
+use polkadot_runtime_common::{crowdloan as pallet_crowdloan, slots as pallet_slots};
+use scale_info::TypeInfo;
+use sp_core::Get;
+use sp_runtime::{DispatchError, RuntimeDebug, SaturatedConversion};
+
+/// Storage items of `slots` and `crowdloan` with the concrete types of the Relay Chain.
+pub(crate) mod alias {
+	use frame_support::{pallet_prelude::ValueQuery, storage_alias, Twox64Concat};
+	use polkadot_parachain_primitives::primitives::Id as ParaId;
+	use polkadot_primitives::{AccountId, Balance, BlockNumber};
+	use polkadot_runtime_common::{crowdloan as pallet_crowdloan, slots as pallet_slots};
+	use sp_std::vec::Vec;
+
+	#[storage_alias]
+	pub type Leases<T: pallet_slots::Config> = StorageMap<
+		pallet_slots::Pallet<T>,
+		Twox64Concat,
+		ParaId,
+		Vec<Option<(AccountId, Balance)>>,
+		ValueQuery,
+	>;
+
+	#[storage_alias]
+	pub type Funds<T: pallet_crowdloan::Config> = StorageMap<
+		pallet_crowdloan::Pallet<T>,
+		Twox64Concat,
+		ParaId,
+		pallet_crowdloan::FundInfo<AccountId, Balance, BlockNumber, BlockNumber>,
+	>;
+}
+
+/// The position of the migration of the reserves.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CrowdloanPosition {
+	/// The lease reserves of all parachains up to this one were sent.
+	Leases(ParaId),
+	/// All lease reserves and the deposits of the crowdloans up to this one were sent, the
+	/// remaining contributions to this crowdloan are sent next.
+	Contributions(ParaId),
+}
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of lease reserves and crowdloan contributions.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_crowdloans() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::crowdloan_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let (batch, done) = Self::take_crowdloan_messages(max);
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Crowdloan,
+				AhMigratorCalls::ReceiveCrowdloanMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Take about `max` messages, together with whether all of them are taken.
+	///
+	/// The lease reserves of a parachain are never split across batches.
+	pub(crate) fn take_crowdloan_messages(max: usize) -> (Vec<RcCrowdloanMessage>, bool) {
+		let mut batch = Vec::new();
+
+		while batch.len() < max {
+			let next = match CrowdloanCursor::<T>::get() {
+				None => Self::take_lease_reserves(None, &mut batch),
+				Some(CrowdloanPosition::Leases(last)) =>
+					Self::take_lease_reserves(Some(last), &mut batch),
+				Some(CrowdloanPosition::Contributions(para_id)) =>
+					Self::take_contributions(para_id, max - batch.len(), &mut batch),
+			};
+			match next {
+				Some(position) => CrowdloanCursor::<T>::put(position),
+				None => {
+					CrowdloanCursor::<T>::kill();
+					return (batch, true)
+				},
+			}
+		}
+
+		(batch, false)
+	}
+
+	/// Take the lease reserves of the parachain after `last`.
+	///
+	/// Continues with the crowdloans once all leases are taken.
+	fn take_lease_reserves(
+		last: Option<ParaId>,
+		batch: &mut Vec<RcCrowdloanMessage>,
+	) -> Option<CrowdloanPosition> {
+		let next = match last {
+			Some(last) => alias::Leases::<T>::iter_from(alias::Leases::<T>::hashed_key_for(last)),
+			None => alias::Leases::<T>::iter(),
+		}
+		.next();
+		let Some((para_id, leases)) = next else {
+			return Self::take_crowdloan_deposit(None, batch)
+		};
+
+		// Every lease holder keeps the largest of its leases reserved until its last lease ends.
+		let mut holders: Vec<&AccountId> = Vec::new();
+		for (who, _) in leases.iter().flatten() {
+			if !holders.contains(&who) {
+				holders.push(who);
+			}
+		}
+		for who in holders {
+			let amount = leases
+				.iter()
+				.flatten()
+				.filter(|(holder, _)| holder == who)
+				.map(|(_, amount)| *amount)
+				.max()
+				.unwrap_or_default();
+			let Some(unreserve_block) = Self::lease_end(&leases, who) else { continue };
+			batch.push(RcCrowdloanMessage::LeaseReserve {
+				unreserve_block,
+				para_id,
+				who: translate_account(who.clone()),
+				amount,
+			});
+		}
+
+		Some(CrowdloanPosition::Leases(para_id))
+	}
+
+	/// Take the deposit of the crowdloan after `last`.
+	fn take_crowdloan_deposit(
+		last: Option<ParaId>,
+		batch: &mut Vec<RcCrowdloanMessage>,
+	) -> Option<CrowdloanPosition> {
+		let (para_id, fund) = match last {
+			Some(last) => alias::Funds::<T>::iter_from(alias::Funds::<T>::hashed_key_for(last)),
+			None => alias::Funds::<T>::iter(),
+		}
+		.next()?;
+
+		batch.push(RcCrowdloanMessage::CrowdloanDeposit {
+			unreserve_block: Self::crowdloan_end(para_id, &fund),
+			para_id,
+			depositor: translate_account(fund.depositor),
+			amount: fund.deposit,
+		});
+
+		Some(CrowdloanPosition::Contributions(para_id))
+	}
+
+	/// Take up to `max` contributions to the crowdloan of `para_id` and remove them.
+	///
+	/// Continues with the next crowdloan once all contributions are taken.
+	fn take_contributions(
+		para_id: ParaId,
+		max: usize,
+		batch: &mut Vec<RcCrowdloanMessage>,
+	) -> Option<CrowdloanPosition> {
+		let Some(fund) = alias::Funds::<T>::get(para_id) else {
+			return Self::take_crowdloan_deposit(Some(para_id), batch)
+		};
+		let contributions: Vec<_> =
+			pallet_crowdloan::Pallet::<T>::contribution_iterate(fund.fund_index)
+				.take(max)
+				.collect();
+		if contributions.is_empty() {
+			return Self::take_crowdloan_deposit(Some(para_id), batch)
+		}
+
+		let withdraw_block = Self::crowdloan_end(para_id, &fund);
+		let crowdloan_account = pallet_crowdloan::Pallet::<T>::fund_account_id(fund.fund_index);
+		for (contributor, (amount, _memo)) in contributions {
+			pallet_crowdloan::Pallet::<T>::contribution_kill(fund.fund_index, &contributor);
+			batch.push(RcCrowdloanMessage::CrowdloanContribution {
+				withdraw_block,
+				para_id,
+				contributor: translate_account(contributor),
+				crowdloan_account: crowdloan_account.clone(),
+				amount: amount.saturated_into(),
+			});
+		}
+
+		Some(CrowdloanPosition::Contributions(para_id))
+	}
+
+	/// The block at which the crowdloan of `para_id` can be withdrawn.
+	///
+	/// This is the end of its last lease, or the end of the crowdloan if it did not win a lease.
+	fn crowdloan_end(
+		para_id: ParaId,
+		fund: &pallet_crowdloan::FundInfo<AccountId, Balance, BlockNumber, BlockNumber>,
+	) -> BlockNumber {
+		let crowdloan_account = pallet_crowdloan::Pallet::<T>::fund_account_id(fund.fund_index);
+		Self::lease_end(&alias::Leases::<T>::get(para_id), &crowdloan_account).unwrap_or(fund.end)
+	}
+
+	/// The first block after the last of the `leases` of `who`.
+	///
+	/// The first lease is the one of the current lease period.
+	pub(crate) fn lease_end(
+		leases: &[Option<(AccountId, Balance)>],
+		who: &AccountId,
+	) -> Option<BlockNumber> {
+		let last = leases
+			.iter()
+			.rposition(|lease| lease.as_ref().is_some_and(|(holder, _)| holder == who))?;
+
+		let period: BlockNumber = <T as pallet_slots::Config>::LeasePeriod::get().saturated_into();
+		let offset: BlockNumber = <T as pallet_slots::Config>::LeaseOffset::get().saturated_into();
+		let now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+		let current = now.saturating_sub(offset) / period;
+
+		Some(
+			current
+				.saturating_add(last as u32 + 1)
+				.saturating_mul(period)
+				.saturating_add(offset),
+		)
+	}
+
+	/// Upper bound of the weight to migrate a single item on the Relay Chain.
+	fn crowdloan_weight() -> Weight {
+		// The leases, the crowdloan and the contribution are read, the contribution is removed.
+		T::DbWeight::get().reads_writes(3, 2)
+	}
+}
@@ -0,0 +1,103 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_delegated_staking`.
+//!
+//! The ledgers of the agents and the delegations are removed from the Relay Chain as they are
+//! sent. The delegated funds are held from the delegators, these holds were already migrated
+//! together with the accounts.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{MigratedPallet, RcDelegatedStakingMessage};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+/// Storage items of `pallet_delegated_staking` that are not public.
+///
+/// The values are read with their portable representation, which has the same encoding.
+pub(crate) mod alias {
+	use frame_support::{pallet_prelude::OptionQuery, storage_alias, Twox64Concat};
+	use paseo_runtime_constants::ahm::{PortableAgentLedger, PortableDelegation};
+	use polkadot_primitives::AccountId;
+
+	#[storage_alias]
+	pub type Agents<T: pallet_delegated_staking::Config> = CountedStorageMap<
+		pallet_delegated_staking::Pallet<T>,
+		Twox64Concat,
+		AccountId,
+		PortableAgentLedger,
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub type Delegators<T: pallet_delegated_staking::Config> = CountedStorageMap<
+		pallet_delegated_staking::Pallet<T>,
+		Twox64Concat,
+		AccountId,
+		PortableDelegation,
+		OptionQuery,
+	>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of agents and delegations.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_delegated_staking() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::delegated_staking_weight().saturating_mul(max as u64);
+
+		let result =
+			with_transaction(|| {
+				let mut batch: Vec<_> = alias::Agents::<T>::drain()
+					.take(max)
+					.map(|(who, ledger)| RcDelegatedStakingMessage::Agents(who, ledger))
+					.collect();
+				let remaining = max.saturating_sub(batch.len());
+				batch.extend(alias::Delegators::<T>::drain().take(remaining).map(
+					|(who, delegation)| RcDelegatedStakingMessage::Delegators(who, delegation),
+				));
+
+				let done = batch.len() < max;
+				if batch.is_empty() {
+					return TransactionOutcome::Commit(Ok(done))
+				}
+
+				let count = batch.len() as u32;
+				match Self::send_batch(
+					MigratedPallet::DelegatedStaking,
+					AhMigratorCalls::ReceiveDelegatedStakingMessages(batch),
+					count,
+				) {
+					Ok(()) => TransactionOutcome::Commit(Ok(done)),
+					Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+				}
+			});
+
+		(result, weight)
+	}
+
+	/// Upper bound of the weight to migrate a single message on the Relay Chain.
+	fn delegated_staking_weight() -> Weight {
+		// The value and the counter of the map are updated.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
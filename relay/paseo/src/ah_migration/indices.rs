@@ -0,0 +1,77 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_indices`.
+//!
+//! Every claimed or frozen index is removed from the Relay Chain and claimed again for the same
+//! account on Asset Hub, so that addresses given as an index keep resolving. The deposits were
+//! already migrated as reserves together with the accounts. Owners that are the sovereign account
+//! of a parachain are translated like their accounts.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{translate_account, MigratedPallet, RcIndex};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of account indices.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_indices() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::indices_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let batch: Vec<_> = pallet_indices::Accounts::<T>::drain()
+				.take(max)
+				.map(|(index, (who, deposit, frozen))| RcIndex {
+					index,
+					who: translate_account(who),
+					deposit,
+					frozen,
+				})
+				.collect();
+
+			let done = batch.len() < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Indices,
+				AhMigratorCalls::ReceiveIndices(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Upper bound of the weight to migrate a single index on the Relay Chain.
+	fn indices_weight() -> Weight {
+		// The index is read and removed.
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
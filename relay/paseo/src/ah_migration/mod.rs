@@ -0,0 +1,660 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Relay Chain side of the Asset Hub Migration (AHM).
+//!
+//! The `RcMigrator` pallet walks the state of the Relay Chain across many blocks and sends it in
+//! batches to the `AhMigrator` pallet on Asset Hub through XCM `Transact` messages. Every stage
+//! keeps a cursor in storage, so the migration can be paused and resumed at any block.
+
+pub mod accounts;
+pub mod checking_account;
+pub mod claims;
+pub mod conviction_voting;
+pub mod crowdloan;
+pub mod delegated_staking;
+pub mod indices;
+pub mod multisig;
+pub mod nom_pools;
+pub mod preimage;
+pub mod proxy;
+pub mod referenda;
+pub mod scheduler;
+pub mod treasury;
+pub mod vesting;
+
+pub use pallet_rc_migrator::*;
+
+use codec::{Decode, Encode};
+use frame_support::weights::Weight;
+use paseo_runtime_constants::ahm::{
+	MigrationStage, RcAccount, RcClaimsMessage, RcConvictionVotingMessage, RcCrowdloanMessage,
+	RcDelegatedStakingMessage, RcIndex, RcMultisig, RcNomPoolsMessage, RcPreimageMessage,
+	RcProxyMessage, RcReferendum, RcScheduledTask, RcTreasuryMessage, RcVestingSchedule,
+	TranslateAccounts,
+};
+use polkadot_primitives::{AccountId, Balance};
+use sp_std::{vec, vec::Vec};
+
+/// The reserves of pallets that stay on the Relay Chain.
+pub trait RcOnlyReservesProvider {
+	/// The reserved balance per account, with at most one entry per account, and the weight of
+	/// collecting them.
+	fn rc_only_reserves() -> (Vec<(AccountId, Balance)>, Weight);
+}
+
+/// Weight functions needed for the `RcMigrator` pallet.
+pub trait WeightInfo {
+	fn force_set_stage() -> Weight;
+	fn switch_teleport_tracking() -> Weight;
+	fn receive_processed() -> Weight;
+}
+
+/// The calls of the `AhMigrator` pallet on Asset Hub.
+///
+/// The codec indices must correspond to the call indices of `pallet_ah_migrator`.
+#[derive(Encode, Decode)]
+pub(crate) enum AhMigratorCalls {
+	#[codec(index = 0)]
+	ReceiveAccounts(Vec<RcAccount>),
+	#[codec(index = 1)]
+	UpdateStage(MigrationStage),
+	#[codec(index = 2)]
+	ReceiveReferenda(Vec<RcReferendum>),
+	#[codec(index = 3)]
+	ReceiveConvictionVotingMessages(Vec<RcConvictionVotingMessage>),
+	#[codec(index = 4)]
+	ReceiveTreasuryMessages(Vec<RcTreasuryMessage>),
+	#[codec(index = 5)]
+	ReceiveNomPoolsMessages(Vec<RcNomPoolsMessage>),
+	#[codec(index = 6)]
+	ReceiveDelegatedStakingMessages(Vec<RcDelegatedStakingMessage>),
+	#[codec(index = 7)]
+	ReceiveMultisigs(Vec<RcMultisig>),
+	#[codec(index = 8)]
+	ReceiveProxyMessages(Vec<RcProxyMessage>),
+	#[codec(index = 9)]
+	ReceiveVestingSchedules(Vec<RcVestingSchedule>),
+	#[codec(index = 10)]
+	ReceiveClaimsMessages(Vec<RcClaimsMessage>),
+	#[codec(index = 11)]
+	SwitchTeleportTracking { rc_total_issuance: Balance },
+	#[codec(index = 12)]
+	ReceivePreimageMessages(Vec<RcPreimageMessage>),
+	#[codec(index = 13)]
+	ReceiveScheduledTasks(Vec<RcScheduledTask>),
+	#[codec(index = 14)]
+	ReceiveIndices(Vec<RcIndex>),
+	#[codec(index = 15)]
+	ReceiveCrowdloanMessages(Vec<RcCrowdloanMessage>),
+}
+
+impl TranslateAccounts for AhMigratorCalls {
+	fn translate_accounts(self) -> Self {
+		use AhMigratorCalls::*;
+		match self {
+			ReceiveReferenda(referenda) => ReceiveReferenda(referenda.translate_accounts()),
+			ReceiveConvictionVotingMessages(messages) =>
+				ReceiveConvictionVotingMessages(messages.translate_accounts()),
+			ReceiveTreasuryMessages(messages) =>
+				ReceiveTreasuryMessages(messages.translate_accounts()),
+			ReceiveNomPoolsMessages(messages) =>
+				ReceiveNomPoolsMessages(messages.translate_accounts()),
+			ReceiveDelegatedStakingMessages(messages) =>
+				ReceiveDelegatedStakingMessages(messages.translate_accounts()),
+			ReceiveMultisigs(multisigs) => ReceiveMultisigs(multisigs.translate_accounts()),
+			ReceiveProxyMessages(messages) => ReceiveProxyMessages(messages.translate_accounts()),
+			ReceiveVestingSchedules(schedules) =>
+				ReceiveVestingSchedules(schedules.translate_accounts()),
+			ReceiveClaimsMessages(messages) => ReceiveClaimsMessages(messages.translate_accounts()),
+			ReceivePreimageMessages(messages) =>
+				ReceivePreimageMessages(messages.translate_accounts()),
+			ReceiveScheduledTasks(tasks) => ReceiveScheduledTasks(tasks.translate_accounts()),
+			// The accounts, indices and crowdloan reserves are translated while they are
+			// collected, the other calls do not refer to accounts.
+			call @ (ReceiveAccounts(_) |
+			UpdateStage(_) |
+			ReceiveIndices(_) |
+			ReceiveCrowdloanMessages(_) |
+			SwitchTeleportTracking { .. }) => call,
+		}
+	}
+}
+
+/// The pallets of the Asset Hub runtime that are called by the migration.
+///
+/// The codec index must correspond to the index of `AhMigrator` in the `construct_runtime` of
+/// Asset Hub.
+#[derive(Encode, Decode)]
+pub(crate) enum AssetHubRuntimePallets {
+	#[codec(index = 254)]
+	AhMigrator(AhMigratorCalls),
+}
+
+#[frame_support::pallet]
+pub mod pallet_rc_migrator {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Contains, LockIdentifier},
+	};
+	use frame_system::pallet_prelude::*;
+	use paseo_runtime_constants::ahm::{
+		MigratedPallet, MigrationProgress, PalletProgress, PortableFreezeReason,
+		PortableGovernanceOrigin, PortableHoldReason, PortableReferendumInfo,
+	};
+	use polkadot_primitives::{AccountId, AccountIndex, Balance, Hash};
+	use sp_runtime::{
+		traits::{Convert, SaturatedConversion},
+		Saturating,
+	};
+	use xcm::prelude::*;
+
+	use super::WeightInfo;
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::rc-migrator";
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<
+			AccountId = AccountId,
+			AccountData = pallet_balances::AccountData<Balance>,
+			Hash = Hash,
+		> + pallet_balances::Config<Balance = Balance>
+		+ pallet_referenda::Config
+		+ pallet_conviction_voting::Config
+		+ pallet_treasury::Config
+		+ pallet_bounties::Config
+		+ pallet_child_bounties::Config
+		+ pallet_nomination_pools::Config
+		+ pallet_delegated_staking::Config
+		+ pallet_staking::Config
+		+ pallet_multisig::Config
+		+ pallet_proxy::Config
+		+ pallet_vesting::Config
+		+ polkadot_runtime_common::claims::Config
+		+ pallet_preimage::Config
+		+ pallet_scheduler::Config
+		+ pallet_indices::Config<AccountIndex = AccountIndex>
+		+ polkadot_runtime_common::slots::Config
+		+ polkadot_runtime_common::crowdloan::Config
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin that is allowed to control the migration.
+		type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The origin of Asset Hub, which reports the items it integrated.
+		type AssetHubOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The router used to send the migration messages to Asset Hub.
+		type SendXcm: SendXcm;
+		/// The location of Asset Hub.
+		type AssetHubLocation: Get<Location>;
+		/// The account tracking the balance teleported from the Relay Chain.
+		///
+		/// Migrated balances are moved into this account, exactly like a teleport to Asset Hub.
+		type CheckingAccount: Get<AccountId>;
+		/// Converts the hold reasons of the Relay Chain into their portable representation.
+		type PortableHoldReason: Convert<
+			<Self as pallet_balances::Config>::RuntimeHoldReason,
+			Option<PortableHoldReason>,
+		>;
+		/// Converts the freeze reasons of the Relay Chain into their portable representation.
+		type PortableFreezeReason: Convert<
+			<Self as pallet_balances::Config>::FreezeIdentifier,
+			Option<PortableFreezeReason>,
+		>;
+		/// Converts the referenda of the Relay Chain into their portable representation.
+		///
+		/// Referenda with an origin that does not exist on Asset Hub are converted to `None`.
+		type PortableReferendumInfo: Convert<
+			pallet_referenda::ReferendumInfoOf<Self, ()>,
+			Option<PortableReferendumInfo>,
+		>;
+		/// Converts the origins of scheduled tasks into their portable representation.
+		///
+		/// Tasks with an origin that does not exist on Asset Hub are dropped there.
+		type PortableSchedulerOrigin: Convert<
+			<Self as pallet_scheduler::Config>::PalletsOrigin,
+			Option<PortableGovernanceOrigin>,
+		>;
+		/// The locks of pallets that stay active on the Relay Chain, like staking.
+		///
+		/// These locks and the funds they lock are not migrated.
+		type RcOnlyLocks: Contains<LockIdentifier>;
+		/// The reserves of pallets that stay on the Relay Chain, like the deposits of parachains
+		/// and HRMP channels.
+		///
+		/// These reserves are not migrated. They are noted once, in the block in which the
+		/// scheduled migration starts.
+		type RcOnlyReserves: RcOnlyReservesProvider;
+		/// The maximum number of accounts migrated in a single block.
+		#[pallet::constant]
+		type MaxAccountsPerBlock: Get<u32>;
+		/// The maximum number of items of any other pallet migrated in a single block.
+		#[pallet::constant]
+		type MaxItemsPerBlock: Get<u32>;
+		/// The weight required on Asset Hub to integrate a single account.
+		#[pallet::constant]
+		type AhWeightPerAccount: Get<Weight>;
+		/// The weight required on Asset Hub to integrate a single item of any other pallet.
+		#[pallet::constant]
+		type AhWeightPerItem: Get<Weight>;
+		/// The weight required on Asset Hub to update the migration stage.
+		#[pallet::constant]
+		type AhUpdateStageWeight: Get<Weight>;
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The current stage of the migration.
+	#[pallet::storage]
+	pub type RcMigrationStage<T: Config> = StorageValue<_, MigrationStage, ValueQuery>;
+
+	/// The last account that was migrated, `None` if no account was migrated yet.
+	#[pallet::storage]
+	pub type AccountsCursor<T: Config> = StorageValue<_, AccountId, OptionQuery>;
+
+	/// The reserves of the accounts that stay on the Relay Chain, see [`Config::RcOnlyReserves`].
+	#[pallet::storage]
+	pub type KeptReserves<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId, Balance, ValueQuery>;
+
+	/// Whether Asset Hub is the issuer of the native token and tracks the teleports.
+	#[pallet::storage]
+	pub type TeleportsTrackedOnAssetHub<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The number of bytes of the next preimage that were already sent.
+	#[pallet::storage]
+	pub type PreimageOffset<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The position of the migration of the lease and crowdloan reserves.
+	#[pallet::storage]
+	pub type CrowdloanCursor<T: Config> =
+		StorageValue<_, crowdloan::CrowdloanPosition, OptionQuery>;
+
+	/// The position of the removal of the scheduler storage that is not migrated, `None` while
+	/// the agendas are migrated.
+	#[pallet::storage]
+	pub type SchedulerCursor<T: Config> =
+		StorageValue<_, scheduler::SchedulerPosition, OptionQuery>;
+
+	/// The number of items that were sent to Asset Hub, per pallet.
+	#[pallet::storage]
+	pub type SentItems<T: Config> = StorageMap<_, Twox64Concat, MigratedPallet, u32, ValueQuery>;
+
+	/// The number of items that Asset Hub reported as integrated or failed, per pallet.
+	///
+	/// `sent` is kept in [`SentItems`] and stays zero.
+	#[pallet::storage]
+	pub type ProcessedItems<T: Config> =
+		StorageMap<_, Twox64Concat, MigratedPallet, PalletProgress, ValueQuery>;
+
+	/// The free balance of the accounts that was sent to Asset Hub.
+	#[pallet::storage]
+	pub type MovedBalance<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The last error that prevented a batch from being migrated.
+	#[pallet::storage]
+	pub type LastError<T: Config> = StorageValue<_, DispatchError, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The migration moved from one stage to another.
+		StageTransition { old: MigrationStage, new: MigrationStage },
+		/// A batch of accounts was sent to Asset Hub.
+		AccountsBatchSent { count: u32, total: Balance },
+		/// An account could not be withdrawn and stays on the Relay Chain.
+		AccountWithdrawFailed { who: AccountId, error: DispatchError },
+		/// The sovereign account of a parachain is migrated to its account on Asset Hub.
+		AccountTranslated { para_id: u32, from: AccountId, to: AccountId },
+		/// A batch of items of `pallet` was sent to Asset Hub.
+		BatchSent { pallet: MigratedPallet, count: u32 },
+		/// A referendum could not be converted and was dropped.
+		ReferendumSkipped { index: u32 },
+		/// The bonded account of a pool is not a virtual staker, its stake stays on the Relay
+		/// Chain.
+		PoolStakeSkipped { pool: u32, stash: AccountId },
+		/// Asset Hub became the issuer of the native token.
+		///
+		/// The checking account keeps the balance that can be teleported out of the Relay Chain.
+		TeleportTrackingSwitched { rc_total_issuance: Balance, checking_balance: Balance },
+		/// Asset Hub processed a batch of items of `pallet`, of which `count_bad` failed.
+		ProcessedOnAssetHub { pallet: MigratedPallet, count_good: u32, count_bad: u32 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The migration message could not be sent to Asset Hub.
+		XcmSendError,
+		/// The accounts are not migrated yet.
+		MigrationNotDone,
+		/// The teleports are already tracked on Asset Hub.
+		AlreadySwitched,
+		/// Asset Hub failed to integrate some items, see its `LastError`.
+		FailedOnAssetHub,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			match RcMigrationStage::<T>::get() {
+				MigrationStage::Scheduled { start } if now.saturated_into::<u32>() >= start => {
+					weight.saturating_accrue(Self::note_kept_reserves());
+					Self::transition(MigrationStage::Ongoing(MigratedPallet::Accounts));
+					weight.saturating_accrue(T::DbWeight::get().writes(1));
+				},
+				MigrationStage::Ongoing(pallet) => {
+					let (result, used) = Self::migrate_pallet(pallet);
+					weight.saturating_accrue(used);
+					match result {
+						Ok(true) => {
+							Self::transition(
+								pallet.next().map_or(MigrationStage::Done, MigrationStage::Ongoing),
+							);
+							weight.saturating_accrue(T::DbWeight::get().writes(1));
+						},
+						Ok(false) => {},
+						// Nothing was committed, the same batch is retried in the next block.
+						Err(e) => {
+							log::error!(
+								target: LOG_TARGET,
+								"Failed to migrate a batch of {:?}: {:?}",
+								pallet,
+								e
+							);
+							LastError::<T>::put(e);
+						},
+					}
+				},
+				MigrationStage::Pending |
+				MigrationStage::Scheduled { .. } |
+				MigrationStage::Done => {},
+			}
+
+			weight
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MaxAccountsPerBlock::get() > 0,
+				"at least one account must be migrated per block"
+			);
+			assert!(T::MaxItemsPerBlock::get() > 0, "at least one item must be migrated per block");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the migration stage.
+		///
+		/// The migration is started by scheduling it, `Ongoing` stages resume from the cursor of
+		/// the respective pallet. Starting the migration of the accounts directly schedules it for
+		/// the next block, which notes the reserves that stay on the Relay Chain.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_set_stage())]
+		pub fn force_set_stage(origin: OriginFor<T>, stage: MigrationStage) -> DispatchResult {
+			<T as Config>::ManagerOrigin::ensure_origin(origin)?;
+			let stage = match stage {
+				MigrationStage::Ongoing(MigratedPallet::Accounts)
+					if !RcMigrationStage::<T>::get().is_ongoing() =>
+					MigrationStage::Scheduled {
+						start: frame_system::Pallet::<T>::block_number().saturated_into(),
+					},
+				stage => stage,
+			};
+			Self::transition(stage);
+			Ok(())
+		}
+
+		/// Make Asset Hub the issuer of the native token.
+		///
+		/// From now on the Relay Chain only tracks the balance that was teleported to it, while
+		/// Asset Hub tracks the balance on all other chains. Asset Hub is informed in the same
+		/// transaction, teleports that are in flight are accounted on both sides.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::switch_teleport_tracking())]
+		pub fn switch_teleport_tracking(origin: OriginFor<T>) -> DispatchResult {
+			<T as Config>::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				RcMigrationStage::<T>::get() == MigrationStage::Done,
+				Error::<T>::MigrationNotDone
+			);
+
+			let rc_total_issuance = Self::do_switch_teleport_tracking()?;
+			Self::send_to_asset_hub(
+				AhMigratorCalls::SwitchTeleportTracking { rc_total_issuance },
+				T::AhUpdateStageWeight::get(),
+			)?;
+			Ok(())
+		}
+
+		/// Receive the number of items of a batch that Asset Hub processed.
+		///
+		/// Failures on Asset Hub are recorded as [`Error::FailedOnAssetHub`] in [`LastError`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_processed())]
+		pub fn receive_processed(
+			origin: OriginFor<T>,
+			pallet: MigratedPallet,
+			count_good: u32,
+			count_bad: u32,
+		) -> DispatchResult {
+			T::AssetHubOrigin::ensure_origin(origin)?;
+			ProcessedItems::<T>::mutate(pallet, |progress| {
+				progress.acknowledged.saturating_accrue(count_good);
+				progress.failed.saturating_accrue(count_bad);
+			});
+			if count_bad > 0 {
+				LastError::<T>::put(DispatchError::from(Error::<T>::FailedOnAssetHub));
+			}
+			Self::deposit_event(Event::ProcessedOnAssetHub { pallet, count_good, count_bad });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Migrate the next batch of `pallet`.
+		///
+		/// Returns whether `pallet` is fully migrated, a failed batch is rolled back.
+		pub(crate) fn migrate_pallet(
+			pallet: MigratedPallet,
+		) -> (Result<bool, DispatchError>, Weight) {
+			let step: fn() -> (Result<bool, DispatchError>, Weight) = match pallet {
+				MigratedPallet::Accounts => Self::migrate_accounts_step,
+				MigratedPallet::Referenda => Self::migrate_referenda,
+				MigratedPallet::ConvictionVoting => Self::migrate_conviction_voting,
+				MigratedPallet::Treasury => Self::migrate_treasury,
+				MigratedPallet::NomPools => Self::migrate_nom_pools,
+				MigratedPallet::DelegatedStaking => Self::migrate_delegated_staking,
+				MigratedPallet::Multisig => Self::migrate_multisigs,
+				MigratedPallet::Proxy => Self::migrate_proxies,
+				MigratedPallet::Vesting => Self::migrate_vesting,
+				MigratedPallet::Claims => Self::migrate_claims,
+				MigratedPallet::Preimage => Self::migrate_preimages,
+				MigratedPallet::Scheduler => Self::migrate_scheduler,
+				MigratedPallet::Indices => Self::migrate_indices,
+				MigratedPallet::Crowdloan => Self::migrate_crowdloans,
+			};
+			step()
+		}
+
+		/// Migrate the batch of accounts after [`AccountsCursor`] and advance the cursor.
+		fn migrate_accounts_step() -> (Result<bool, DispatchError>, Weight) {
+			let (result, mut weight) = Self::migrate_accounts(AccountsCursor::<T>::get());
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			let result = result.map(|last_key| match last_key {
+				Some(last_key) => {
+					AccountsCursor::<T>::put(last_key);
+					false
+				},
+				None => {
+					AccountsCursor::<T>::kill();
+					true
+				},
+			});
+			(result, weight)
+		}
+
+		/// Move the migration to `new` and inform Asset Hub about it.
+		pub(crate) fn transition(new: MigrationStage) {
+			let old = RcMigrationStage::<T>::get();
+			if old == new {
+				return;
+			}
+			RcMigrationStage::<T>::put(new);
+			Self::deposit_event(Event::StageTransition { old, new });
+
+			// Asset Hub pauses its user activity based on the stage, a failure is only logged to
+			// not block the Relay Chain.
+			let _ = Self::send_to_asset_hub(
+				AhMigratorCalls::UpdateStage(new),
+				T::AhUpdateStageWeight::get(),
+			);
+		}
+
+		/// Whether state is being moved to Asset Hub.
+		pub fn is_ongoing() -> bool {
+			RcMigrationStage::<T>::get().is_ongoing()
+		}
+
+		/// The progress of the migration on the Relay Chain.
+		///
+		/// The acknowledged and failed items are the ones Asset Hub reported so far.
+		pub fn progress() -> MigrationProgress {
+			MigrationProgress {
+				stage: RcMigrationStage::<T>::get(),
+				accounts_cursor: AccountsCursor::<T>::get(),
+				items: MigratedPallet::ALL
+					.into_iter()
+					.map(|pallet| {
+						(
+							pallet,
+							PalletProgress {
+								sent: SentItems::<T>::get(pallet),
+								..ProcessedItems::<T>::get(pallet)
+							},
+						)
+					})
+					.collect(),
+				moved: MovedBalance::<T>::get(),
+				last_error: LastError::<T>::get(),
+			}
+		}
+
+		/// Send a batch of `count` items of `pallet` to Asset Hub.
+		///
+		/// The accounts of the items are replaced with their accounts on Asset Hub.
+		pub(crate) fn send_batch(
+			pallet: MigratedPallet,
+			call: AhMigratorCalls,
+			count: u32,
+		) -> Result<(), Error<T>> {
+			let weight = T::AhWeightPerItem::get().saturating_mul(count as u64);
+			Self::send_to_asset_hub(call.translate_accounts(), weight)?;
+			SentItems::<T>::mutate(pallet, |sent| sent.saturating_accrue(count));
+			Self::deposit_event(Event::BatchSent { pallet, count });
+			Ok(())
+		}
+
+		/// Send a call of the `AhMigrator` pallet to Asset Hub.
+		///
+		/// `weight` is the weight the call may consume on Asset Hub.
+		pub(crate) fn send_to_asset_hub(
+			call: AhMigratorCalls,
+			weight: Weight,
+		) -> Result<(), Error<T>> {
+			let message = Xcm(vec![
+				Instruction::UnpaidExecution {
+					weight_limit: WeightLimit::Unlimited,
+					check_origin: None,
+				},
+				Instruction::Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most: weight,
+					call: AssetHubRuntimePallets::AhMigrator(call).encode().into(),
+				},
+			]);
+
+			send_xcm::<T::SendXcm>(T::AssetHubLocation::get(), message).map_err(|e| {
+				log::error!(target: LOG_TARGET, "Failed to send message to Asset Hub: {:?}", e);
+				Error::<T>::XcmSendError
+			})?;
+			Ok(())
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use frame_benchmarking::v2::*;
+	use frame_support::traits::EnsureOrigin;
+	use paseo_runtime_constants::ahm::MigratedPallet;
+	use sp_runtime::traits::Get;
+
+	#[benchmarks(where <T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>)]
+	mod benchmarks {
+		use super::*;
+
+		/// Starting the migration, which schedules it and informs Asset Hub.
+		#[benchmark]
+		fn force_set_stage() -> Result<(), BenchmarkError> {
+			let origin = <T as Config>::ManagerOrigin::try_successful_origin()
+				.map_err(|_| BenchmarkError::Weightless)?;
+
+			#[extrinsic_call]
+			_(
+				origin as <T as frame_system::Config>::RuntimeOrigin,
+				MigrationStage::Ongoing(MigratedPallet::Accounts),
+			);
+
+			assert!(matches!(RcMigrationStage::<T>::get(), MigrationStage::Scheduled { .. }));
+			Ok(())
+		}
+
+		#[benchmark]
+		fn switch_teleport_tracking() -> Result<(), BenchmarkError> {
+			let origin = <T as Config>::ManagerOrigin::try_successful_origin()
+				.map_err(|_| BenchmarkError::Weightless)?;
+			RcMigrationStage::<T>::put(MigrationStage::Done);
+
+			#[extrinsic_call]
+			_(origin as <T as frame_system::Config>::RuntimeOrigin);
+
+			assert!(TeleportsTrackedOnAssetHub::<T>::get());
+			Ok(())
+		}
+
+		/// A batch with failed items, which also records the error.
+		#[benchmark]
+		fn receive_processed() {
+			let origin = pallet_xcm::Origin::Xcm(T::AssetHubLocation::get());
+
+			#[extrinsic_call]
+			_(origin, MigratedPallet::Accounts, 1, 1);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Accounts).failed, 1);
+			assert!(LastError::<T>::get().is_some());
+		}
+	}
+}
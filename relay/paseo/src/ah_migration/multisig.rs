@@ -0,0 +1,95 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_multisig`.
+//!
+//! Pending multisig operations are not migrated, their calls are encoded for the Relay Chain and
+//! can not be executed on Asset Hub. They are removed and their deposits are refunded. The part of
+//! a deposit that is still reserved on the Relay Chain is unreserved here, the part that was
+//! migrated together with the account of the depositor is refunded on Asset Hub.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::ReservableCurrency,
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{transcode, MigratedPallet, PortableMultisig, RcMultisig};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of multisig operations.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_multisigs() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::multisig_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let mut batch = Vec::new();
+			let mut drained = 0;
+			for (_, _, multisig) in pallet_multisig::Multisigs::<T>::drain().take(max) {
+				drained += 1;
+				match transcode::<_, PortableMultisig>(&multisig) {
+					Ok(multisig) => batch.extend(Self::refund_multisig(multisig)),
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to convert multisig, dropping it: {:?}",
+						e
+					),
+				}
+			}
+
+			let done = drained < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Multisig,
+				AhMigratorCalls::ReceiveMultisigs(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Unreserve the deposit of a multisig operation on the Relay Chain.
+	///
+	/// Returns the part of the deposit that has to be refunded on Asset Hub, if any.
+	fn refund_multisig(multisig: PortableMultisig) -> Option<RcMultisig> {
+		let migrated = <Balances<T> as ReservableCurrency<_>>::unreserve(
+			&multisig.depositor,
+			multisig.deposit,
+		);
+		(migrated > 0).then(|| RcMultisig { creator: multisig.depositor, deposit: migrated })
+	}
+
+	/// Upper bound of the weight to migrate a single multisig operation on the Relay Chain.
+	fn multisig_weight() -> Weight {
+		// The multisig and the account of the depositor are read and written.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
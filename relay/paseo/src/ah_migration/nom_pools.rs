@@ -0,0 +1,234 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_nomination_pools`.
+//!
+//! Every storage item is removed from the Relay Chain as it is sent. The rewards of the pools are
+//! held by accounts derived from the pallet id, the bonded funds are held from the pool members.
+//! Both were already migrated together with all other accounts.
+//!
+//! The bonded accounts of the pools stake with `pallet_staking`. Their ledgers, reward
+//! destinations and nominations are sent together with the pools and removed from the Relay
+//! Chain. This only applies to virtual stakers, the bonded accounts of pools that were not migrated
+//! to `pallet_delegated_staking` keep their funds and their stake on the Relay Chain.
+
+use super::*;
+use frame_election_provider_support::SortedListProvider;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use pallet_nomination_pools::{
+	BondedPools, ClaimPermissions, GlobalMaxCommission, LastPoolId, MaxPoolMembers,
+	MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond, PoolMembers,
+	ReversePoolIdLookup, RewardPools, SubPoolsStorage, TotalValueLocked,
+};
+#[cfg(feature = "try-runtime")]
+use paseo_runtime_constants::ahm::NomPoolsSummary;
+use paseo_runtime_constants::ahm::{
+	transcode, MigratedPallet, PortableNomPoolsStorageValues, PortablePoolStake, RcNomPoolsMessage,
+};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of nomination pool items.
+	///
+	/// Returns whether all items are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_nom_pools() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::nom_pools_item_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let (batch, drained) = Self::take_nom_pools_messages(max);
+			let done = drained < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::NomPools,
+				AhMigratorCalls::ReceiveNomPoolsMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Take up to `max` items from storage, returning the messages and the number of items taken.
+	///
+	/// Items that can not be converted are dropped with an error log.
+	fn take_nom_pools_messages(max: usize) -> (Vec<RcNomPoolsMessage>, usize) {
+		let mut batch = Vec::new();
+		let mut drained = 0;
+
+		// Convert an item and push it to the batch, or log why it is dropped.
+		macro_rules! push {
+			( $what:expr, $message:expr ) => {
+				drained += 1;
+				match $message {
+					Ok(message) => batch.push(message),
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to convert {}, dropping it: {:?}",
+						$what,
+						e
+					),
+				}
+			};
+		}
+
+		if drained < max {
+			if let Some(values) = Self::take_nom_pools_values().transpose() {
+				push!("storage values", values.map(RcNomPoolsMessage::StorageValues));
+			}
+		}
+		for (who, member) in PoolMembers::<T>::drain().take(max - drained) {
+			push!(
+				"PoolMembers",
+				transcode(&member).map(|m| RcNomPoolsMessage::PoolMembers(who, m))
+			);
+		}
+		let mut pools = BondedPools::<T>::drain();
+		while drained < max {
+			// The stake of a pool is an item of its own that is sent with the pool. Without room
+			// for it the pool is left for the next batch, unless the batch would stay empty.
+			if drained + 1 == max && drained > 0 {
+				drained = max;
+				break
+			}
+			let Some((id, pool)) = pools.next() else { break };
+			push!("BondedPools", transcode(&pool).map(|p| RcNomPoolsMessage::BondedPools(id, p)));
+			if let Some(stake) = Self::take_pool_stake(id).transpose() {
+				push!("PoolStake", stake.map(RcNomPoolsMessage::PoolStake));
+			}
+		}
+		for (id, pool) in RewardPools::<T>::drain().take(max - drained) {
+			push!("RewardPools", transcode(&pool).map(|p| RcNomPoolsMessage::RewardPools(id, p)));
+		}
+		for (id, pools) in SubPoolsStorage::<T>::drain().take(max - drained) {
+			push!(
+				"SubPoolsStorage",
+				transcode(&pools).map(|p| RcNomPoolsMessage::SubPoolsStorage(id, p))
+			);
+		}
+		for (id, metadata) in Metadata::<T>::drain().take(max - drained) {
+			drained += 1;
+			batch.push(RcNomPoolsMessage::Metadata(id, metadata.into_inner()));
+		}
+		for (who, id) in ReversePoolIdLookup::<T>::drain().take(max - drained) {
+			drained += 1;
+			batch.push(RcNomPoolsMessage::ReversePoolIdLookup(who, id));
+		}
+		for (who, permission) in ClaimPermissions::<T>::drain().take(max - drained) {
+			push!(
+				"ClaimPermissions",
+				transcode(&permission).map(|p| RcNomPoolsMessage::ClaimPermissions(who, p))
+			);
+		}
+
+		(batch, drained)
+	}
+
+	/// Take all storage values of the pallet, `None` if none of them is set.
+	fn take_nom_pools_values() -> Result<Option<PortableNomPoolsStorageValues>, codec::Error> {
+		let values = PortableNomPoolsStorageValues {
+			total_value_locked: transcode(&TotalValueLocked::<T>::take())?,
+			min_join_bond: transcode(&MinJoinBond::<T>::take())?,
+			min_create_bond: transcode(&MinCreateBond::<T>::take())?,
+			max_pools: MaxPools::<T>::take(),
+			max_pool_members: MaxPoolMembers::<T>::take(),
+			max_pool_members_per_pool: MaxPoolMembersPerPool::<T>::take(),
+			global_max_commission: GlobalMaxCommission::<T>::take(),
+			last_pool_id: LastPoolId::<T>::take(),
+		};
+		Ok((values != Default::default()).then_some(values))
+	}
+
+	/// Take the staking state of the bonded account of the pool `id`, `None` if it does not stake.
+	///
+	/// Only the stake of virtual stakers is migrated, their funds are held by the delegators. The
+	/// funds of other bonded accounts stay locked on the Relay Chain, and so does their stake.
+	pub(crate) fn take_pool_stake(id: u32) -> Result<Option<PortablePoolStake>, codec::Error> {
+		let stash = pallet_nomination_pools::Pallet::<T>::generate_bonded_account(id);
+		if pallet_staking::VirtualStakers::<T>::take(&stash).is_none() {
+			if pallet_staking::Ledger::<T>::contains_key(&stash) {
+				log::warn!(
+					target: LOG_TARGET,
+					"The bonded account of pool {} is not a virtual staker, keeping its stake",
+					id
+				);
+				Self::deposit_event(Event::PoolStakeSkipped { pool: id, stash });
+			}
+			return Ok(None)
+		}
+		let Some(ledger) = pallet_staking::Ledger::<T>::take(&stash) else { return Ok(None) };
+		pallet_staking::Bonded::<T>::remove(&stash);
+		let payee = pallet_staking::Payee::<T>::take(&stash);
+		let nominations = pallet_staking::Nominators::<T>::take(&stash);
+		// Only nominators are in the voter list.
+		let _ = <T as pallet_staking::Config>::VoterList::on_remove(&stash);
+
+		Ok(Some(PortablePoolStake {
+			ledger: transcode(&ledger)?,
+			payee: payee.map(|payee| transcode(&payee)).transpose()?,
+			nominations: nominations.map(|nominations| transcode(&nominations)).transpose()?,
+		}))
+	}
+
+	/// Upper bound of the weight to migrate a single item on the Relay Chain.
+	fn nom_pools_item_weight() -> Weight {
+		// The storage values are read and removed at once. The stake of a pool removes the ledger,
+		// bond, payee, nominations, virtual staker flag and voter list node of its bonded account.
+		T::DbWeight::get().reads_writes(10, 10)
+	}
+
+	/// Summary of the nomination pools and delegations that are left on the Relay Chain.
+	#[cfg(feature = "try-runtime")]
+	pub fn nom_pools_summary() -> NomPoolsSummary {
+		let mut summary = NomPoolsSummary::default();
+		for (id, pool) in BondedPools::<T>::iter() {
+			summary.add_pool(&transcode(&pool).expect("bonded pools are portable"));
+			let stash = pallet_nomination_pools::Pallet::<T>::generate_bonded_account(id);
+			// The stake of other bonded accounts stays on the Relay Chain.
+			if !pallet_staking::VirtualStakers::<T>::contains_key(&stash) {
+				continue
+			}
+			if let Some(ledger) = pallet_staking::Ledger::<T>::get(&stash) {
+				summary.add_ledger(
+					&transcode(&ledger).expect("staking ledgers are portable"),
+					pallet_staking::Nominators::<T>::contains_key(&stash),
+				);
+			}
+		}
+		for member in PoolMembers::<T>::iter_values() {
+			summary.add_member(&transcode(&member).expect("pool members are portable"));
+		}
+		for sub_pools in SubPoolsStorage::<T>::iter_values() {
+			summary.add_sub_pools(&transcode(&sub_pools).expect("sub pools are portable"));
+		}
+		for delegation in delegated_staking::alias::Delegators::<T>::iter_values() {
+			summary.add_delegation(&delegation);
+		}
+		summary
+	}
+}
@@ -0,0 +1,129 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_preimage`.
+//!
+//! The request statuses are sent first, followed by the preimages, which are split into chunks of
+//! at most [`PREIMAGE_CHUNK_LEN`] bytes. A preimage is removed from the Relay Chain once its last
+//! chunk was sent. The deposits were already migrated together with the accounts.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{MigratedPallet, RcPreimageMessage, PREIMAGE_CHUNK_LEN};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+/// Storage items of `pallet_preimage` that are not public.
+pub(crate) mod alias {
+	use frame_support::{pallet_prelude::OptionQuery, storage_alias, Identity};
+	use paseo_runtime_constants::ahm::PortableRequestStatus;
+	use polkadot_primitives::Hash;
+	use sp_std::vec::Vec;
+
+	#[storage_alias]
+	pub type StatusFor<T: pallet_preimage::Config> =
+		StorageMap<pallet_preimage::Pallet<T>, Identity, Hash, PortableRequestStatus, OptionQuery>;
+
+	#[storage_alias]
+	pub type RequestStatusFor<T: pallet_preimage::Config> =
+		StorageMap<pallet_preimage::Pallet<T>, Identity, Hash, PortableRequestStatus, OptionQuery>;
+
+	#[storage_alias]
+	pub type PreimageFor<T: pallet_preimage::Config> =
+		StorageMap<pallet_preimage::Pallet<T>, Identity, (Hash, u32), Vec<u8>, OptionQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of preimages and their request statuses.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_preimages() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::preimage_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let (batch, done) = Self::take_preimage_messages(max);
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Preimage,
+				AhMigratorCalls::ReceivePreimageMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Take up to `max` items from storage, together with whether storage is empty afterwards.
+	///
+	/// The chunks of a batch carry at most [`PREIMAGE_CHUNK_LEN`] bytes in total.
+	fn take_preimage_messages(max: usize) -> (Vec<RcPreimageMessage>, bool) {
+		let mut batch = Vec::new();
+
+		batch.extend(
+			alias::RequestStatusFor::<T>::drain()
+				.take(max)
+				.map(|(hash, status)| RcPreimageMessage::RequestStatus { hash, status }),
+		);
+		batch.extend(
+			alias::StatusFor::<T>::drain()
+				.take(max - batch.len())
+				.map(|(hash, status)| RcPreimageMessage::LegacyStatus { hash, status }),
+		);
+
+		let mut bytes = 0;
+		while batch.len() < max && bytes < PREIMAGE_CHUNK_LEN {
+			let Some(((hash, len), data)) = alias::PreimageFor::<T>::iter().next() else {
+				return (batch, true)
+			};
+			let offset = PreimageOffset::<T>::get();
+			let end = offset.saturating_add(PREIMAGE_CHUNK_LEN - bytes).min(data.len() as u32);
+			batch.push(RcPreimageMessage::Chunk {
+				hash,
+				len,
+				offset,
+				data: data[offset as usize..end as usize].to_vec(),
+			});
+			bytes += end - offset;
+
+			if end as usize == data.len() {
+				alias::PreimageFor::<T>::remove((hash, len));
+				PreimageOffset::<T>::kill();
+			} else {
+				PreimageOffset::<T>::put(end);
+			}
+		}
+
+		(batch, false)
+	}
+
+	/// Upper bound of the weight to migrate a single item on the Relay Chain.
+	fn preimage_weight() -> Weight {
+		// The item and the offset are read and written.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
@@ -0,0 +1,126 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_proxy`.
+//!
+//! The proxies and announcements are removed from the Relay Chain as they are sent. The part of
+//! their deposits that is still reserved on the Relay Chain is unreserved here. Asset Hub
+//! recomputes the deposits with its own, lower, deposit parameters.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::ReservableCurrency,
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{
+	transcode, MigratedPallet, PortableAnnouncement, PortableProxyDefinition, RcProxyMessage,
+};
+use polkadot_primitives::{AccountId, Balance};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of proxies and announcements.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_proxies() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::proxy_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let (batch, drained) = Self::take_proxy_messages(max);
+			let done = drained < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Proxy,
+				AhMigratorCalls::ReceiveProxyMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Take up to `max` items from storage, returning the messages and the number of items taken.
+	///
+	/// Items that can not be converted are dropped with an error log, their deposits are still
+	/// unreserved.
+	fn take_proxy_messages(max: usize) -> (Vec<RcProxyMessage>, usize) {
+		let mut batch = Vec::new();
+		let mut drained = 0;
+
+		for (delegator, proxies) in pallet_proxy::Proxies::<T>::drain().take(max) {
+			drained += 1;
+			match transcode::<_, (Vec<PortableProxyDefinition>, Balance)>(&proxies) {
+				Ok((proxies, deposit)) => batch.push(RcProxyMessage::Proxies {
+					deposit: Self::unreserve_proxy_deposit(&delegator, deposit),
+					delegator,
+					proxies,
+				}),
+				Err(e) => log::error!(
+					target: LOG_TARGET,
+					"Failed to convert proxies of {:?}, dropping them: {:?}",
+					delegator,
+					e
+				),
+			}
+		}
+		for (delegate, announcements) in
+			pallet_proxy::Announcements::<T>::drain().take(max - drained)
+		{
+			drained += 1;
+			match transcode::<_, (Vec<PortableAnnouncement>, Balance)>(&announcements) {
+				Ok((announcements, deposit)) => batch.push(RcProxyMessage::Announcements {
+					deposit: Self::unreserve_proxy_deposit(&delegate, deposit),
+					delegate,
+					announcements,
+				}),
+				Err(e) => log::error!(
+					target: LOG_TARGET,
+					"Failed to convert announcements of {:?}, dropping them: {:?}",
+					delegate,
+					e
+				),
+			}
+		}
+
+		(batch, drained)
+	}
+
+	/// Unreserve `deposit` of `who` on the Relay Chain.
+	///
+	/// Returns the part of the deposit that was migrated together with the account.
+	fn unreserve_proxy_deposit(who: &AccountId, deposit: Balance) -> Balance {
+		<Balances<T> as ReservableCurrency<_>>::unreserve(who, deposit)
+	}
+
+	/// Upper bound of the weight to migrate a single item on the Relay Chain.
+	fn proxy_weight() -> Weight {
+		// The proxies or announcements and the account are read and written.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
@@ -0,0 +1,91 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_referenda`.
+//!
+//! Referenda are removed from the Relay Chain as they are sent, together with their metadata.
+//! Asset Hub re-schedules the alarms of the ongoing referenda, so the alarms that are still
+//! scheduled on the Relay Chain find no referendum to service.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::MigratedPallet;
+use sp_core::Get;
+use sp_runtime::{traits::Convert, DispatchError};
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of referenda.
+	///
+	/// Returns whether all referenda are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_referenda() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get();
+		let weight = Self::referendum_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let mut batch = Vec::new();
+			let mut drained = 0;
+			for (index, info) in
+				pallet_referenda::ReferendumInfoFor::<T>::drain().take(max as usize)
+			{
+				drained += 1;
+				let metadata = pallet_referenda::MetadataOf::<T>::take(index);
+				match T::PortableReferendumInfo::convert(info) {
+					Some(info) => batch.push(RcReferendum { index, info, metadata }),
+					None => {
+						log::warn!(
+							target: LOG_TARGET,
+							"Referendum {} can not be migrated, dropping it",
+							index
+						);
+						Self::deposit_event(Event::ReferendumSkipped { index });
+					},
+				}
+			}
+
+			let done = drained < max;
+			if done {
+				// The queues and counters are re-computed by Asset Hub.
+				let _ = pallet_referenda::TrackQueue::<T>::clear(u32::MAX, None);
+				let _ = pallet_referenda::DecidingCount::<T>::clear(u32::MAX, None);
+			}
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Referenda,
+				AhMigratorCalls::ReceiveReferenda(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Upper bound of the weight to migrate a single referendum on the Relay Chain.
+	fn referendum_weight() -> Weight {
+		// The referendum and its metadata are read and removed.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
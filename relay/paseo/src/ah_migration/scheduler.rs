@@ -0,0 +1,201 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_scheduler`.
+//!
+//! The agendas are removed from the Relay Chain as they are sent. Their calls are encoded for the
+//! Relay Chain, they are translated on Asset Hub where possible and dropped otherwise. The
+//! preimages of the calls were already migrated. The lookup of named tasks and the retry
+//! configurations are not migrated, Asset Hub re-creates the lookup when scheduling the tasks.
+//! They are removed from the Relay Chain after the agendas, a bounded number of keys per block.
+
+use super::*;
+use codec::MaxEncodedLen;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Bounded, ConstU32},
+	weights::Weight,
+	BoundedVec,
+};
+use paseo_runtime_constants::ahm::{transcode, MigratedPallet, PortableScheduled, RcScheduledTask};
+use polkadot_primitives::BlockNumber;
+use scale_info::TypeInfo;
+use sp_core::Get;
+use sp_runtime::{
+	traits::{BlakeTwo256, Convert},
+	DispatchError, RuntimeDebug, SaturatedConversion,
+};
+
+/// Storage items of `pallet_scheduler` that are not public.
+pub(crate) mod alias {
+	use frame_support::{
+		pallet_prelude::OptionQuery, storage_alias, Blake2_128Concat, Twox64Concat,
+	};
+	use polkadot_primitives::BlockNumber;
+
+	#[storage_alias]
+	pub type Lookup<T: pallet_scheduler::Config> = StorageMap<
+		pallet_scheduler::Pallet<T>,
+		Twox64Concat,
+		[u8; 32],
+		(BlockNumber, u32),
+		OptionQuery,
+	>;
+
+	/// The retry configurations, encoded like `pallet_scheduler::RetryConfig`.
+	#[storage_alias]
+	pub type Retries<T: pallet_scheduler::Config> = StorageMap<
+		pallet_scheduler::Pallet<T>,
+		Blake2_128Concat,
+		(BlockNumber, u32),
+		(u8, u8, BlockNumber),
+		OptionQuery,
+	>;
+}
+
+/// The last key removed by `clear`.
+pub type ClearCursor = BoundedVec<u8, ConstU32<1024>>;
+
+/// The position of the removal of the scheduler storage that is not migrated.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SchedulerPosition {
+	/// All agendas were sent, the lookup is removed next.
+	Lookup(Option<ClearCursor>),
+	/// The lookup was removed, the retry configurations are removed next.
+	Retries(Option<ClearCursor>),
+}
+
+/// A scheduled task of the Relay Chain with a decoded origin.
+type ScheduledOf<T> =
+	PortableScheduled<Bounded<(), BlakeTwo256>, <T as pallet_scheduler::Config>::PalletsOrigin>;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the tasks of the next batch of agendas, or remove the next keys of the storage
+	/// that is not migrated once all agendas are sent.
+	///
+	/// Agendas are not split, a batch holds as many whole agendas as fit into
+	/// `MaxItemsPerBlock` tasks, but at least one. Returns whether the scheduler is migrated,
+	/// together with the consumed weight. If the batch cannot be sent, nothing is committed.
+	pub fn migrate_scheduler() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let max_tasks =
+			max.max(<T as pallet_scheduler::Config>::MaxScheduledPerBlock::get() as usize);
+		let weight = Self::scheduler_weight().saturating_mul(max_tasks as u64);
+
+		if let Some(position) = SchedulerCursor::<T>::get() {
+			let done = match Self::clear_scheduler(position, max as u32) {
+				Some(next) => {
+					SchedulerCursor::<T>::put(next);
+					false
+				},
+				None => {
+					SchedulerCursor::<T>::kill();
+					true
+				},
+			};
+			return (Ok(done), weight)
+		}
+
+		let result = with_transaction(|| {
+			let mut batch = Vec::new();
+			let mut agendas_done = true;
+			for (when, agenda) in pallet_scheduler::Agenda::<T>::iter() {
+				let tasks = agenda.iter().filter(|task| task.is_some()).count();
+				if !batch.is_empty() && batch.len() + tasks > max {
+					agendas_done = false;
+					break
+				}
+				pallet_scheduler::Agenda::<T>::remove(when);
+				let when: BlockNumber = when.saturated_into();
+				for (index, task) in agenda.into_iter().enumerate() {
+					let Some(task) = task else { continue };
+					match transcode::<_, ScheduledOf<T>>(&task) {
+						Ok(task) => batch.push(RcScheduledTask {
+							when,
+							index: index as u32,
+							task: PortableScheduled {
+								maybe_id: task.maybe_id,
+								priority: task.priority,
+								call: task.call,
+								maybe_periodic: task.maybe_periodic,
+								origin: T::PortableSchedulerOrigin::convert(task.origin),
+							},
+						}),
+						Err(e) => log::error!(
+							target: LOG_TARGET,
+							"Failed to convert task {:?} of agenda {:?}, dropping it: {:?}",
+							index,
+							when,
+							e
+						),
+					}
+				}
+			}
+
+			if agendas_done {
+				SchedulerCursor::<T>::put(SchedulerPosition::Lookup(None));
+				pallet_scheduler::IncompleteSince::<T>::kill();
+			}
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(false))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Scheduler,
+				AhMigratorCalls::ReceiveScheduledTasks(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(false)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Remove up to `limit` keys of the lookup and the retry configurations, starting at
+	/// `position`.
+	///
+	/// Returns the position to continue at, `None` once both are removed.
+	pub(crate) fn clear_scheduler(
+		position: SchedulerPosition,
+		limit: u32,
+	) -> Option<SchedulerPosition> {
+		// Without a cursor the removal restarts at the first remaining key, which is only less
+		// efficient within the same block.
+		let bound = |cursor: Vec<u8>| ClearCursor::try_from(cursor).ok();
+		match position {
+			SchedulerPosition::Lookup(cursor) => {
+				let result = alias::Lookup::<T>::clear(limit, cursor.as_deref());
+				Some(match result.maybe_cursor {
+					Some(cursor) => SchedulerPosition::Lookup(bound(cursor)),
+					None => SchedulerPosition::Retries(None),
+				})
+			},
+			SchedulerPosition::Retries(cursor) =>
+				alias::Retries::<T>::clear(limit, cursor.as_deref())
+					.maybe_cursor
+					.map(|cursor| SchedulerPosition::Retries(bound(cursor))),
+		}
+	}
+
+	/// Upper bound of the weight to migrate a single task on the Relay Chain.
+	fn scheduler_weight() -> Weight {
+		// The agenda is read and removed, or a key of the lookup or the retries is removed.
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
@@ -0,0 +1,220 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_treasury`, `pallet_bounties` and `pallet_child_bounties`.
+//!
+//! Every storage item is removed from the Relay Chain as it is sent, so the migration resumes at
+//! the first item that is left. The funds are held by accounts derived from the pallet ids, which
+//! are migrated together with all other accounts.
+//!
+//! Asset Hub can not learn the outcome of the XCM payments of the Relay Chain, so spends whose
+//! payment is not confirmed yet are sent as failed and can be paid again on Asset Hub.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		tokens::{Pay, PaymentStatus},
+		Currency,
+	},
+	weights::Weight,
+};
+use paseo_runtime_constants::ahm::{
+	transcode, MigratedPallet, PortablePaymentState, PortableSpendStatus, RcTreasuryMessage,
+};
+use polkadot_primitives::Balance;
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+/// Storage items of `pallet_treasury` that are not public.
+mod alias {
+	use frame_support::{pallet_prelude::ValueQuery, storage_alias};
+
+	#[storage_alias]
+	pub type ProposalCount<T: pallet_treasury::Config> =
+		StorageValue<pallet_treasury::Pallet<T>, u32, ValueQuery>;
+
+	#[storage_alias]
+	pub type SpendCount<T: pallet_treasury::Config> =
+		StorageValue<pallet_treasury::Pallet<T>, u32, ValueQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the next batch of treasury, bounty and child bounty items.
+	///
+	/// Returns whether all items are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_treasury() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::treasury_item_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let (batch, drained) = Self::take_treasury_messages(max);
+			let done = drained < max;
+			if done {
+				// The balance of the treasury is deactivated again by Asset Hub.
+				let deactivated = pallet_treasury::Deactivated::<T>::take();
+				match transcode::<_, Balance>(&deactivated) {
+					Ok(deactivated) =>
+						<pallet_balances::Pallet<T> as Currency<_>>::reactivate(deactivated),
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to reactivate the treasury balance: {:?}",
+						e
+					),
+				}
+			}
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Treasury,
+				AhMigratorCalls::ReceiveTreasuryMessages(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Take up to `max` items from storage, returning the messages and the number of items taken.
+	///
+	/// Items that can not be converted are dropped with an error log.
+	fn take_treasury_messages(max: usize) -> (Vec<RcTreasuryMessage>, usize) {
+		let mut batch = Vec::new();
+		let mut drained = 0;
+
+		// Convert an item and push it to the batch, or log why it is dropped.
+		macro_rules! push {
+			( $what:expr, $message:expr ) => {
+				drained += 1;
+				match $message {
+					Ok(message) => batch.push(message),
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to convert {}, dropping it: {:?}",
+						$what,
+						e
+					),
+				}
+			};
+		}
+
+		if drained < max && alias::ProposalCount::<T>::exists() {
+			drained += 1;
+			batch.push(RcTreasuryMessage::ProposalCount(alias::ProposalCount::<T>::take()));
+		}
+		for (id, proposal) in pallet_treasury::Proposals::<T>::drain().take(max - drained) {
+			push!("Proposals", transcode(&proposal).map(|p| RcTreasuryMessage::Proposals(id, p)));
+		}
+		if drained < max && pallet_treasury::Approvals::<T>::exists() {
+			let approvals = pallet_treasury::Approvals::<T>::take();
+			push!("Approvals", transcode(&approvals).map(RcTreasuryMessage::Approvals));
+		}
+		if drained < max && alias::SpendCount::<T>::exists() {
+			drained += 1;
+			batch.push(RcTreasuryMessage::SpendCount(alias::SpendCount::<T>::take()));
+		}
+		for (id, spend) in pallet_treasury::Spends::<T>::drain().take(max - drained) {
+			push!(
+				"Spends",
+				transcode(&spend).map(|s| RcTreasuryMessage::Spends(id, Self::settle_spend(s)))
+			);
+		}
+
+		if drained < max && pallet_bounties::BountyCount::<T>::exists() {
+			drained += 1;
+			batch.push(RcTreasuryMessage::BountyCount(pallet_bounties::BountyCount::<T>::take()));
+		}
+		for (id, bounty) in pallet_bounties::Bounties::<T>::drain().take(max - drained) {
+			push!("Bounties", transcode(&bounty).map(|b| RcTreasuryMessage::Bounties(id, b)));
+		}
+		for (id, description) in
+			pallet_bounties::BountyDescriptions::<T>::drain().take(max - drained)
+		{
+			drained += 1;
+			batch.push(RcTreasuryMessage::BountyDescriptions(id, description.into_inner()));
+		}
+		if drained < max && pallet_bounties::BountyApprovals::<T>::exists() {
+			drained += 1;
+			batch.push(RcTreasuryMessage::BountyApprovals(
+				pallet_bounties::BountyApprovals::<T>::take().into_inner(),
+			));
+		}
+
+		if drained < max && pallet_child_bounties::ChildBountyCount::<T>::exists() {
+			drained += 1;
+			batch.push(RcTreasuryMessage::ChildBountyCount(
+				pallet_child_bounties::ChildBountyCount::<T>::take(),
+			));
+		}
+		for (parent, count) in
+			pallet_child_bounties::ParentChildBounties::<T>::drain().take(max - drained)
+		{
+			drained += 1;
+			batch.push(RcTreasuryMessage::ParentChildBounties(parent, count));
+		}
+		for (parent, id, child_bounty) in
+			pallet_child_bounties::ChildBounties::<T>::drain().take(max - drained)
+		{
+			push!(
+				"ChildBounties",
+				transcode(&child_bounty).map(|c| RcTreasuryMessage::ChildBounties(parent, id, c))
+			);
+		}
+		for (id, description) in
+			pallet_child_bounties::ChildBountyDescriptions::<T>::drain().take(max - drained)
+		{
+			drained += 1;
+			batch.push(RcTreasuryMessage::ChildBountyDescriptions(id, description.into_inner()));
+		}
+		for (id, fee) in
+			pallet_child_bounties::ChildrenCuratorFees::<T>::drain().take(max - drained)
+		{
+			push!(
+				"ChildrenCuratorFees",
+				transcode(&fee).map(|fee| RcTreasuryMessage::ChildrenCuratorFees(id, fee))
+			);
+		}
+
+		(batch, drained)
+	}
+
+	/// Mark a spend as failed unless its XCM payment was confirmed.
+	fn settle_spend(mut spend: PortableSpendStatus) -> PortableSpendStatus {
+		if let PortablePaymentState::Attempted { id } = spend.status {
+			let confirmed = transcode(&id).is_ok_and(|id| {
+				<T as pallet_treasury::Config>::Paymaster::check_payment(id) ==
+					PaymentStatus::Success
+			});
+			if !confirmed {
+				spend.status = PortablePaymentState::Failed;
+			}
+		}
+		spend
+	}
+
+	/// Upper bound of the weight to migrate a single item on the Relay Chain.
+	fn treasury_item_weight() -> Weight {
+		// A spend also reads the response to its payment.
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+}
@@ -0,0 +1,113 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of `pallet_vesting`.
+//!
+//! The vesting schedules are removed from the Relay Chain as they are sent. Their lock was already
+//! migrated together with the account. Asset Hub evaluates the schedules with the block number of
+//! the Relay Chain, so they are sent unchanged.
+
+use super::*;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
+};
+#[cfg(feature = "try-runtime")]
+use paseo_runtime_constants::ahm::VestingSummary;
+use paseo_runtime_constants::ahm::{
+	transcode, MigratedPallet, PortableVestingInfo, RcVestingSchedule,
+};
+#[cfg(feature = "try-runtime")]
+use polkadot_primitives::BlockNumber;
+use sp_core::Get;
+use sp_runtime::DispatchError;
+
+impl<T: Config> Pallet<T> {
+	/// Migrate the vesting schedules of the next batch of accounts.
+	///
+	/// Returns whether all of them are migrated, together with the consumed weight. If the batch
+	/// cannot be sent, nothing is committed.
+	pub fn migrate_vesting() -> (Result<bool, DispatchError>, Weight) {
+		let max = T::MaxItemsPerBlock::get() as usize;
+		let weight = Self::vesting_weight().saturating_mul(max as u64);
+
+		let result = with_transaction(|| {
+			let mut batch = Vec::new();
+			let mut drained = 0;
+			for (who, schedules) in pallet_vesting::Vesting::<T>::drain().take(max) {
+				drained += 1;
+				match transcode::<_, Vec<PortableVestingInfo>>(&schedules) {
+					Ok(schedules) => batch.push(RcVestingSchedule { who, schedules }),
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to convert vesting schedules of {:?}, dropping them: {:?}",
+						who,
+						e
+					),
+				}
+			}
+
+			let done = drained < max;
+			if batch.is_empty() {
+				return TransactionOutcome::Commit(Ok(done))
+			}
+
+			let count = batch.len() as u32;
+			match Self::send_batch(
+				MigratedPallet::Vesting,
+				AhMigratorCalls::ReceiveVestingSchedules(batch),
+				count,
+			) {
+				Ok(()) => TransactionOutcome::Commit(Ok(done)),
+				Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+			}
+		});
+
+		(result, weight)
+	}
+
+	/// Upper bound of the weight to migrate the vesting schedules of a single account.
+	fn vesting_weight() -> Weight {
+		// The schedules are read and removed.
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	/// The Relay Chain block numbers at which the locked amounts are compared by try-runtime.
+	#[cfg(feature = "try-runtime")]
+	pub fn vesting_sample_heights() -> Vec<BlockNumber> {
+		use paseo_runtime_constants::time::DAYS;
+		use sp_runtime::SaturatedConversion;
+
+		let now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+		[0, 30, 180, 365, 2 * 365]
+			.into_iter()
+			.map(|days| now.saturating_add(days * DAYS))
+			.collect()
+	}
+
+	/// Summary of the vesting schedules that are left on the Relay Chain.
+	#[cfg(feature = "try-runtime")]
+	pub fn vesting_summary() -> VestingSummary {
+		let mut summary = VestingSummary::new(Self::vesting_sample_heights());
+		for schedules in pallet_vesting::Vesting::<T>::iter_values() {
+			summary.add_schedules(
+				&transcode::<_, Vec<PortableVestingInfo>>(&schedules)
+					.expect("vesting schedules are portable"),
+			);
+		}
+		summary
+	}
+}
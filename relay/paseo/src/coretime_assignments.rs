@@ -0,0 +1,225 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Core assignments that do not fit in a single message from the Coretime chain.
+//!
+//! The `coretime` pallet only accepts the complete assignment of a core in one `assign_core` call.
+//! A heavily interlaced core has more assignments than the Coretime chain sends in one `Transact`,
+//! so the Coretime chain sends them in chunks to the `CoretimeAssignments` pallet instead. The
+//! chunks are collected until they cover the whole core, i.e. 57600 parts, and the assignment is
+//! then handed to `parachains_assigner_coretime` like `coretime::assign_core` would.
+
+pub use pallet_coretime_assignments::*;
+
+/// The weights of the calls of [`Pallet`].
+pub trait WeightInfo {
+	fn assign_core_chunk() -> frame_support::weights::Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet_coretime_assignments {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use pallet_broker::{CoreAssignment, CoreIndex as BrokerCoreIndex};
+	use polkadot_primitives::CoreIndex;
+	use runtime_parachains::assigner_coretime::{self, PartsOf57600};
+	use sp_runtime::Saturating;
+	use sp_std::vec::Vec;
+
+	use super::WeightInfo;
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::coretime-assignments";
+
+	/// An assignment of a core that is being reassembled.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(MaxAssignments))]
+	pub struct PendingAssignment<BlockNumber, MaxAssignments: Get<u32>> {
+		/// The block from which the assignment applies.
+		pub begin: BlockNumber,
+		/// The hint of the end of the assignment.
+		pub end_hint: Option<BlockNumber>,
+		/// The assignments received so far.
+		pub assignment: BoundedVec<(CoreAssignment, PartsOf57600), MaxAssignments>,
+		/// The number of chunks received so far.
+		pub chunks: u32,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + assigner_coretime::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin of the broker on the Coretime chain.
+		type BrokerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The maximum number of assignments of a single core.
+		#[pallet::constant]
+		type MaxAssignments: Get<u32>;
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The assignments of the cores that are being reassembled.
+	#[pallet::storage]
+	pub type PendingAssignments<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BrokerCoreIndex,
+		PendingAssignment<BlockNumberFor<T>, T::MaxAssignments>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A chunk of the assignment of a core was received.
+		AssignmentChunkReceived {
+			core: BrokerCoreIndex,
+			begin: BlockNumberFor<T>,
+			assignments: u32,
+		},
+		/// The chunks of the assignment of a core were reassembled and the core was assigned.
+		CoreAssigned { core: BrokerCoreIndex, begin: BlockNumberFor<T>, chunks: u32 },
+		/// An incomplete assignment was dropped because a newer one was started.
+		PendingAssignmentDropped { core: BrokerCoreIndex, begin: BlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The assignment of the core has too many entries.
+		TooManyAssignments,
+		/// The parts of the assignment exceed 57600.
+		OverScheduled,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Receive a chunk of the assignment of `core` starting at `begin`.
+		///
+		/// The chunks must be sent in order. The core is assigned once the chunks cover all of its
+		/// 57600 parts.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::assign_core_chunk())]
+		pub fn assign_core_chunk(
+			origin: OriginFor<T>,
+			core: BrokerCoreIndex,
+			begin: BlockNumberFor<T>,
+			assignment: Vec<(CoreAssignment, PartsOf57600)>,
+			end_hint: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::BrokerOrigin::ensure_origin(origin)?;
+
+			let mut pending = match PendingAssignments::<T>::take(core) {
+				Some(pending) if pending.begin == begin => pending,
+				maybe_pending => {
+					if let Some(dropped) = maybe_pending {
+						log::warn!(
+							target: LOG_TARGET,
+							"Dropping the incomplete assignment of core {} at {:?}",
+							core,
+							dropped.begin
+						);
+						Self::deposit_event(Event::PendingAssignmentDropped {
+							core,
+							begin: dropped.begin,
+						});
+					}
+					PendingAssignment { begin, end_hint, assignment: Default::default(), chunks: 0 }
+				},
+			};
+
+			let assignments = assignment.len() as u32;
+			pending
+				.assignment
+				.try_extend(assignment.into_iter())
+				.map_err(|_| Error::<T>::TooManyAssignments)?;
+			pending.end_hint = end_hint;
+			pending.chunks.saturating_inc();
+			Self::deposit_event(Event::AssignmentChunkReceived { core, begin, assignments });
+
+			let parts = pending
+				.assignment
+				.iter()
+				.try_fold(PartsOf57600::ZERO, |total, (_, parts)| total.checked_add(*parts))
+				.ok_or(Error::<T>::OverScheduled)?;
+			if !parts.is_full() {
+				PendingAssignments::<T>::insert(core, pending);
+				return Ok(());
+			}
+
+			let PendingAssignment { begin, end_hint, assignment, chunks } = pending;
+			assigner_coretime::Pallet::<T>::assign_core(
+				CoreIndex(u32::from(core)),
+				begin,
+				assignment.into_inner(),
+				end_hint,
+			)?;
+			Self::deposit_event(Event::CoreAssigned { core, begin, chunks });
+			Ok(())
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use frame_benchmarking::v2::*;
+	use frame_support::{traits::EnsureOrigin, BoundedVec};
+	use pallet_broker::CoreAssignment;
+	use runtime_parachains::assigner_coretime::PartsOf57600;
+	use sp_runtime::traits::Get;
+	use sp_std::vec::Vec;
+
+	#[benchmarks]
+	mod benchmarks {
+		use super::*;
+
+		/// The last chunk of an assignment with the most entries, which assigns the core.
+		#[benchmark]
+		fn assign_core_chunk() -> Result<(), BenchmarkError> {
+			let max = T::MaxAssignments::get();
+			let share = 57_600 / max;
+			let mut assignment: Vec<_> = (1..=max)
+				.map(|i| {
+					(CoreAssignment::Task(1000 + i), PartsOf57600::new_saturating(share as u16))
+				})
+				.collect();
+			assignment[0].1 = PartsOf57600::new_saturating((57_600 - share * (max - 1)) as u16);
+			let last = assignment.split_off(max as usize - 1);
+
+			let begin = 1u32.into();
+			PendingAssignments::<T>::insert(
+				0,
+				PendingAssignment {
+					begin,
+					end_hint: None,
+					assignment: BoundedVec::truncate_from(assignment),
+					chunks: 1,
+				},
+			);
+			let origin =
+				T::BrokerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+			#[extrinsic_call]
+			_(origin as T::RuntimeOrigin, 0, begin, last, None);
+
+			assert!(PendingAssignments::<T>::get(0).is_none());
+			Ok(())
+		}
+	}
+}
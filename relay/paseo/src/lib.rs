@@ -42,7 +42,7 @@ use runtime_parachains::{
 	inclusion::{AggregateMessageOrigin, UmpQueueId},
 	initializer as parachains_initializer, on_demand as parachains_on_demand,
 	origin as parachains_origin, paras as parachains_paras,
-	paras_inherent as parachains_paras_inherent, reward_points as parachains_reward_points,
+	paras_inherent as parachains_paras_inherent,
 	runtime_api_impl::{
 		v10 as parachains_runtime_api_impl, vstaging as parachains_vstaging_api_impl,
 	},
@@ -62,13 +62,16 @@ use frame_election_provider_support::{
 };
 use frame_support::{
 	construct_runtime,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
+	migrations::{FreezeChainOnFailedMigration, MigrationStatusHandler, MultiStepMigrator},
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
-		ConstU32, ConstU8, Contains, EitherOf, EitherOfDiverse, Everything, FromContains, Get,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage,
+		Bounded, ConstU32, ConstU8, Contains, EitherOf, EitherOfDiverse, EnsureOrigin,
+		EnsureOriginWithArg, Equals, EverythingBut, FromContains, Get, InstanceFilter,
+		KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier, PrivilegeCmp, ProcessMessage,
 		ProcessMessageError, WithdrawReasons,
 	},
 	weights::{
@@ -93,7 +96,7 @@ use sp_core::{OpaqueMetadata, H256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertInto,
 		Extrinsic as ExtrinsicT, IdentityLookup, Keccak256, OpaqueKeys, SaturatedConversion,
 		Verify,
 	},
@@ -128,7 +131,15 @@ pub use sp_runtime::BuildStorage;
 
 /// Constant values used within the runtime.
 use paseo_runtime_constants::{
-	currency::*, fee::*, proxy::ProxyType, system_parachain, time::*, TREASURY_PALLET_ID,
+	ahm::{
+		PortableFreezeReason, PortableGovernanceOrigin, PortableHoldReason, PortableReferendumInfo,
+	},
+	currency::*,
+	fee::*,
+	proxy::ProxyType,
+	system_parachain,
+	time::*,
+	TREASURY_PALLET_ID,
 };
 
 // Weights used in the runtime.
@@ -148,6 +159,19 @@ pub mod xcm_config;
 
 mod coretime_migration;
 
+// Asset Hub Migration.
+pub mod ah_migration;
+use ah_migration::pallet_rc_migrator;
+
+pub mod session_client;
+use session_client::pallet_session_client;
+
+pub mod on_demand_credits;
+use on_demand_credits::pallet_on_demand_credits;
+
+pub mod coretime_assignments;
+use coretime_assignments::pallet_coretime_assignments;
+
 /// Default logging target.
 pub const LOG_TARGET: &str = "runtime::paseo";
 
@@ -190,7 +214,7 @@ parameter_types! {
 }
 
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = EverythingBut<(MigrationPausedCalls, MbmPausedCalls)>;
 	type BlockWeights = BlockWeights;
 	type BlockLength = BlockLength;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -215,12 +239,31 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 	type SingleBlockMigrations = ();
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
 	type PostTransactions = ();
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = migrations::MultiBlock;
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = MbmStatusHandler;
+	type FailedMigrationHandler = FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
@@ -285,8 +328,10 @@ impl pallet_preimage::Config for Runtime {
 parameter_types! {
 	pub EpochDuration: u64 = EPOCH_DURATION_IN_SLOTS as u64;
 	pub const ExpectedBlockTime: Moment = MILLISECS_PER_BLOCK;
-	pub ReportLongevity: u64 =
-		BondingDuration::get() as u64 * SessionsPerEra::get() as u64 * EpochDuration::get();
+	// Equivocations can be reported as long as the offenders are bonded.
+	pub ReportLongevity: u64 = SetIdSessionEntries::get() as u64 * EpochDuration::get();
+	// The sessions of the bonding duration, the GRANDPA and BEEFY set ids are kept for them.
+	pub SetIdSessionEntries: u32 = BondingDuration::get() * SessionsPerEra::get();
 }
 
 impl pallet_babe::Config for Runtime {
@@ -344,15 +389,11 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ConstU32<8>;
 }
 
-parameter_types! {
-	pub BeefySetIdSessionEntries: u32 = BondingDuration::get() * SessionsPerEra::get();
-}
-
 impl pallet_beefy::Config for Runtime {
 	type BeefyId = BeefyId;
 	type MaxAuthorities = MaxAuthorities;
 	type MaxNominators = MaxNominators;
-	type MaxSetIdSessionEntries = BeefySetIdSessionEntries;
+	type MaxSetIdSessionEntries = SetIdSessionEntries;
 	type OnNewValidatorSet = BeefyMmrLeaf;
 	type AncestryHelper = BeefyMmrLeaf;
 	type WeightInfo = ();
@@ -453,7 +494,7 @@ impl pallet_timestamp::Config for Runtime {
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
-	type EventHandler = Staking;
+	type EventHandler = SessionClient;
 }
 
 impl_opaque_keys! {
@@ -470,10 +511,10 @@ impl_opaque_keys! {
 impl pallet_session::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ValidatorId = AccountId;
-	type ValidatorIdOf = pallet_staking::StashOf<Self>;
+	type ValidatorIdOf = session_client::ValidatorIdOf<Self>;
 	type ShouldEndSession = Babe;
 	type NextSessionRotation = Babe;
-	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, Staking>;
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Self, SessionClient>;
 	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
 	type Keys = SessionKeys;
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
@@ -623,7 +664,106 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type Score = sp_npos_elections::VoteWeight;
 }
 
+/// Dynamic params that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Parameters used to calculate the era payouts, see [`EraPayout`].
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod inflation {
+		/// The total issuance the yearly emission is calculated from.
+		#[codec(index = 0)]
+		pub static FixedTotalIssuance: Balance =
+			paseo_runtime_constants::staking::FIXED_TOTAL_ISSUANCE;
+
+		/// The yearly inflation rate, as a share of [`FixedTotalIssuance`].
+		#[codec(index = 1)]
+		pub static InflationRate: Perbill = paseo_runtime_constants::staking::INFLATION_RATE;
+	}
+
+	/// Parameters of staking.
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod staking {
+		/// The number of eras that staked funds must remain bonded for (28 days).
+		#[codec(index = 0)]
+		pub static BondingDuration: sp_staking::EraIndex =
+			prod_or_fast!(28, 28, "DOT_BONDING_DURATION");
+
+		/// The number of eras that slashes are deferred by, after computation.
+		///
+		/// Staking reads it through [`super::SlashDeferDuration`], which keeps it below
+		/// [`BondingDuration`].
+		#[codec(index = 1)]
+		pub static SlashDeferDuration: sp_staking::EraIndex =
+			prod_or_fast!(27, 27, "DOT_SLASH_DEFER_DURATION");
+	}
+
+	/// Parameters of the treasury.
+	#[dynamic_pallet_params]
+	#[codec(index = 2)]
+	pub mod treasury {
+		/// The share of the era emission that goes to the treasury.
+		#[codec(index = 0)]
+		pub static TreasuryShare: Perbill = paseo_runtime_constants::staking::TREASURY_SHARE;
+
+		/// The period between the spends of the treasury.
+		#[codec(index = 1)]
+		pub static SpendPeriod: BlockNumber = 24 * DAYS;
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Inflation(dynamic_params::inflation::Parameters::InflationRate(
+			dynamic_params::inflation::InflationRate,
+			Some(Perbill::from_percent(8)),
+		))
+	}
+}
+
+/// Defines what origin can modify which dynamic parameters.
+///
+/// `StakingAdmin` handles the inflation and staking parameters, and `Treasurer` the treasury
+/// parameters.
+pub struct DynamicParameterOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParameterOrigin {
+	type Success = ();
+
+	fn try_origin(
+		origin: RuntimeOrigin,
+		key: &RuntimeParametersKey,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		use crate::RuntimeParametersKey::*;
+
+		match key {
+			Inflation(_) | Staking(_) =>
+				EitherOf::<EnsureRoot<AccountId>, StakingAdmin>::try_origin(origin),
+			Treasury(_) => EitherOf::<EnsureRoot<AccountId>, Treasurer>::try_origin(origin),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		// Provide the origin for the parameter returned by `Default`:
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParameterOrigin;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
 /// Defines how much should the inflation be for an era given its duration.
+///
+/// The emission and its split are read from [`dynamic_params::inflation`] and
+/// [`dynamic_params::treasury`].
 pub struct EraPayout;
 impl pallet_staking::EraPayout<Balance> for EraPayout {
 	fn era_payout(
@@ -631,22 +771,12 @@ impl pallet_staking::EraPayout<Balance> for EraPayout {
 		_total_issuance: Balance,
 		era_duration_millis: u64,
 	) -> (Balance, Balance) {
-		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
-		// A normal-sized era will have 1 / 365.25 here:
-		let relative_era_len =
-			FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
-
-		// TI at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139), block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
-		let fixed_total_issuance: i128 = 1_487_502_468_008_283_162;
-		let fixed_inflation_rate = FixedU128::from_rational(8, 100);
-		let yearly_emission = fixed_inflation_rate.saturating_mul_int(fixed_total_issuance);
-
-		let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
-		// 15% to treasury, as per ref 1139.
-		let to_treasury = FixedU128::from_rational(15, 100).saturating_mul_int(era_emission);
-		let to_stakers = era_emission.saturating_sub(to_treasury);
-
-		(to_stakers.saturated_into(), to_treasury.saturated_into())
+		paseo_runtime_constants::staking::era_payout(
+			dynamic_params::inflation::FixedTotalIssuance::get(),
+			dynamic_params::inflation::InflationRate::get(),
+			dynamic_params::treasury::TreasuryShare::get(),
+			era_duration_millis,
+		)
 	}
 }
 
@@ -654,17 +784,7 @@ parameter_types! {
 	// Six sessions in an era (24 hours).
 	pub const SessionsPerEra: SessionIndex = prod_or_fast!(6, 1);
 
-	// 28 eras for unbonding (28 days).
-	pub BondingDuration: sp_staking::EraIndex = prod_or_fast!(
-		28,
-		28,
-		"DOT_BONDING_DURATION"
-	);
-	pub SlashDeferDuration: sp_staking::EraIndex = prod_or_fast!(
-		27,
-		27,
-		"DOT_SLASH_DEFER_DURATION"
-	);
+	pub BondingDuration: sp_staking::EraIndex = dynamic_params::staking::BondingDuration::get();
 	pub const MaxExposurePageSize: u32 = 512;
 	// Note: this is not really correct as Max Nominators is (MaxExposurePageSize * page_count) but
 	// this is an unbounded number. We just set it to a reasonably high value, 1 full page
@@ -675,6 +795,18 @@ parameter_types! {
 	pub const MaxNominations: u32 = <NposCompactSolution16 as frame_election_provider_support::NposSolution>::LIMIT as u32;
 }
 
+/// The slash defer duration of [`dynamic_params::staking`].
+///
+/// Staking requires the slashes to be applied before the slashed funds are unbonded, so it is kept
+/// below the bonding duration.
+pub struct SlashDeferDuration;
+impl Get<sp_staking::EraIndex> for SlashDeferDuration {
+	fn get() -> sp_staking::EraIndex {
+		dynamic_params::staking::SlashDeferDuration::get()
+			.min(BondingDuration::get().saturating_sub(1))
+	}
+}
+
 impl pallet_staking::Config for Runtime {
 	type Currency = Balances;
 	type CurrencyBalance = Balance;
@@ -727,7 +859,6 @@ parameter_types! {
 	pub const ProposalBond: Permill = Permill::from_percent(5);
 	pub const ProposalBondMinimum: Balance = 100 * DOLLARS;
 	pub const ProposalBondMaximum: Balance = 500 * DOLLARS;
-	pub const SpendPeriod: BlockNumber = 24 * DAYS;
 	pub const Burn: Permill = Permill::from_percent(1);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const PayoutSpendPeriod: BlockNumber = 30 * DAYS;
@@ -752,7 +883,7 @@ impl pallet_treasury::Config for Runtime {
 	type Currency = Balances;
 	type RejectOrigin = EitherOfDiverse<EnsureRoot<AccountId>, Treasurer>;
 	type RuntimeEvent = RuntimeEvent;
-	type SpendPeriod = SpendPeriod;
+	type SpendPeriod = dynamic_params::treasury::SpendPeriod;
 	type Burn = Burn;
 	type BurnDestination = ();
 	type SpendFunds = Bounties;
@@ -820,7 +951,26 @@ impl pallet_child_bounties::Config for Runtime {
 impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
-	type OnOffenceHandler = Staking;
+	type OnOffenceHandler = SessionClient;
+}
+
+parameter_types! {
+	// Conservative upper bound of `RcClient::receive_session_report` on Asset Hub, which may plan
+	// a new era.
+	pub AhStakingReportWeight: Weight = Weight::from_parts(10_000_000_000, 1024 * 1024);
+}
+
+impl pallet_session_client::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
+	type AssetHubOrigin = pallet_xcm::EnsureXcm<Equals<xcm_config::AssetHubLocation>>;
+	type SendXcm = xcm_config::XcmRouter;
+	type AssetHubLocation = xcm_config::AssetHubLocation;
+	type LocalSessionManager = Staking;
+	type LocalOffenceHandler = Staking;
+	type MaxValidators = MaxActiveValidators;
+	type AhReportWeight = AhStakingReportWeight;
+	type WeightInfo = weights::pallet_session_client::WeightInfo<Runtime>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -832,17 +982,13 @@ parameter_types! {
 		Perbill::from_percent(90) * TransactionPriority::MAX;
 }
 
-parameter_types! {
-	pub MaxSetIdSessionEntries: u32 = BondingDuration::get() * SessionsPerEra::get();
-}
-
 impl pallet_grandpa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 
 	type WeightInfo = ();
 	type MaxAuthorities = MaxAuthorities;
 	type MaxNominators = MaxNominators;
-	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
+	type MaxSetIdSessionEntries = SetIdSessionEntries;
 
 	type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, GrandpaId)>>::Proof;
 
@@ -1143,7 +1289,7 @@ impl parachains_session_info::Config for Runtime {
 impl parachains_inclusion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type DisputesHandler = ParasDisputes;
-	type RewardValidators = parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>;
+	type RewardValidators = SessionClient;
 	type MessageQueue = MessageQueue;
 	type WeightInfo = weights::runtime_parachains_inclusion::WeightInfo<Runtime>;
 }
@@ -1293,8 +1439,28 @@ impl parachains_on_demand::Config for Runtime {
 	type PalletId = OnDemandPalletId;
 }
 
+parameter_types! {
+	pub const OnDemandCreditsPalletId: PalletId = PalletId(*b"py/odcrd");
+}
+
+impl pallet_on_demand_credits::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BrokerOrigin = on_demand_credits::EnsureParachain<BrokerId>;
+	type Currency = Balances;
+	type PalletId = OnDemandCreditsPalletId;
+	type WeightInfo = weights::pallet_on_demand_credits::WeightInfo<Runtime>;
+}
+
 impl parachains_assigner_coretime::Config for Runtime {}
 
+impl pallet_coretime_assignments::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BrokerOrigin = on_demand_credits::EnsureParachain<BrokerId>;
+	// The broker interlaces a core in at most 80 parts.
+	type MaxAssignments = ConstU32<100>;
+	type WeightInfo = weights::pallet_coretime_assignments::WeightInfo<Runtime>;
+}
+
 impl parachains_initializer::Config for Runtime {
 	type Randomness = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
 	type ForceOrigin = EnsureRoot<AccountId>;
@@ -1304,7 +1470,7 @@ impl parachains_initializer::Config for Runtime {
 
 impl parachains_disputes::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type RewardValidators = parachains_reward_points::RewardValidatorsWithEraPoints<Runtime>;
+	type RewardValidators = SessionClient;
 	type SlashingHandler = parachains_slashing::SlashValidatorsForDisputes<ParasSlashing>;
 	type WeightInfo = weights::runtime_parachains_disputes::WeightInfo<Runtime>;
 }
@@ -1507,6 +1673,285 @@ impl pallet_sudo::Config for Runtime {
 
 impl paras_sudo_wrapper::Config for Runtime {}
 
+parameter_types! {
+	pub const RcMigratorMaxAccountsPerBlock: u32 = 100;
+	// Conservative upper bound of `AhMigrator::receive_accounts` per account on Asset Hub.
+	pub const AhWeightPerAccount: Weight = Weight::from_parts(1_500_000_000, 30_000);
+	// Conservative upper bound of `AhMigrator::update_stage` and
+	// `AhMigrator::switch_teleport_tracking` on Asset Hub.
+	pub const AhUpdateStageWeight: Weight = Weight::from_parts(1_000_000_000, 20_000);
+	pub const RcMigratorMaxItemsPerBlock: u32 = 50;
+	// Conservative upper bound per item on Asset Hub, dominated by re-scheduling referenda.
+	pub const AhWeightPerItem: Weight = Weight::from_parts(2_000_000_000, 40_000);
+}
+
+/// Maps the hold reasons of this runtime to the reasons re-created on Asset Hub.
+pub struct RcToPortableHoldReason;
+impl Convert<RuntimeHoldReason, Option<PortableHoldReason>> for RcToPortableHoldReason {
+	fn convert(reason: RuntimeHoldReason) -> Option<PortableHoldReason> {
+		match reason {
+			RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage) =>
+				Some(PortableHoldReason::Preimage),
+			RuntimeHoldReason::StateTrieMigration(
+				pallet_state_trie_migration::HoldReason::SlashForMigrate,
+			) => Some(PortableHoldReason::StateTrieMigration),
+			RuntimeHoldReason::DelegatedStaking(
+				pallet_delegated_staking::HoldReason::StakingDelegation,
+			) => Some(PortableHoldReason::DelegatedStaking),
+		}
+	}
+}
+
+/// Maps the freeze reasons of this runtime to the reasons re-created on Asset Hub.
+pub struct RcToPortableFreezeReason;
+impl Convert<RuntimeFreezeReason, Option<PortableFreezeReason>> for RcToPortableFreezeReason {
+	fn convert(reason: RuntimeFreezeReason) -> Option<PortableFreezeReason> {
+		match reason {
+			RuntimeFreezeReason::NominationPools(
+				pallet_nomination_pools::FreezeReason::PoolMinBalance,
+			) => Some(PortableFreezeReason::NominationPools),
+		}
+	}
+}
+
+/// Maps the origins of this runtime to the origins that referenda and scheduled tasks have on
+/// Asset Hub.
+pub struct RcToPortableGovernanceOrigin;
+impl Convert<OriginCaller, Option<PortableGovernanceOrigin>> for RcToPortableGovernanceOrigin {
+	fn convert(origin: OriginCaller) -> Option<PortableGovernanceOrigin> {
+		use pallet_custom_origins::Origin;
+		Some(match origin {
+			OriginCaller::system(frame_system::RawOrigin::Root) => PortableGovernanceOrigin::Root,
+			OriginCaller::Origins(origin) => match origin {
+				Origin::StakingAdmin => PortableGovernanceOrigin::StakingAdmin,
+				Origin::Treasurer => PortableGovernanceOrigin::Treasurer,
+				Origin::FellowshipAdmin => PortableGovernanceOrigin::FellowshipAdmin,
+				Origin::GeneralAdmin => PortableGovernanceOrigin::GeneralAdmin,
+				Origin::AuctionAdmin => PortableGovernanceOrigin::AuctionAdmin,
+				Origin::LeaseAdmin => PortableGovernanceOrigin::LeaseAdmin,
+				Origin::ReferendumCanceller => PortableGovernanceOrigin::ReferendumCanceller,
+				Origin::ReferendumKiller => PortableGovernanceOrigin::ReferendumKiller,
+				Origin::SmallTipper => PortableGovernanceOrigin::SmallTipper,
+				Origin::BigTipper => PortableGovernanceOrigin::BigTipper,
+				Origin::SmallSpender => PortableGovernanceOrigin::SmallSpender,
+				Origin::MediumSpender => PortableGovernanceOrigin::MediumSpender,
+				Origin::BigSpender => PortableGovernanceOrigin::BigSpender,
+				Origin::WhitelistedCaller => PortableGovernanceOrigin::WhitelistedCaller,
+				Origin::WishForChange => PortableGovernanceOrigin::WishForChange,
+			},
+			_ => return None,
+		})
+	}
+}
+
+/// Maps the referenda of this runtime to their portable representation.
+pub struct RcToPortableReferendumInfo;
+impl Convert<pallet_referenda::ReferendumInfoOf<Runtime, ()>, Option<PortableReferendumInfo>>
+	for RcToPortableReferendumInfo
+{
+	fn convert(
+		info: pallet_referenda::ReferendumInfoOf<Runtime, ()>,
+	) -> Option<PortableReferendumInfo> {
+		use pallet_conviction_voting::Tally;
+		use pallet_referenda::{ReferendumInfo, ReferendumStatus};
+		Some(match info {
+			ReferendumInfo::Ongoing(status) => ReferendumInfo::Ongoing(ReferendumStatus {
+				track: status.track,
+				origin: RcToPortableGovernanceOrigin::convert(status.origin)?,
+				// Only the hash or the encoded call is kept, which have the same encoding.
+				proposal: match status.proposal {
+					Bounded::Legacy { hash, .. } =>
+						Bounded::Legacy { hash, dummy: Default::default() },
+					Bounded::Inline(call) => Bounded::Inline(call),
+					Bounded::Lookup { hash, len } => Bounded::Lookup { hash, len },
+				},
+				enactment: status.enactment,
+				submitted: status.submitted,
+				submission_deposit: status.submission_deposit,
+				decision_deposit: status.decision_deposit,
+				deciding: status.deciding,
+				tally: Tally::from_parts(
+					status.tally.ayes,
+					status.tally.nays,
+					status.tally.support,
+				),
+				in_queue: status.in_queue,
+				alarm: status.alarm,
+			}),
+			ReferendumInfo::Approved(moment, submission, decision) =>
+				ReferendumInfo::Approved(moment, submission, decision),
+			ReferendumInfo::Rejected(moment, submission, decision) =>
+				ReferendumInfo::Rejected(moment, submission, decision),
+			ReferendumInfo::Cancelled(moment, submission, decision) =>
+				ReferendumInfo::Cancelled(moment, submission, decision),
+			ReferendumInfo::TimedOut(moment, submission, decision) =>
+				ReferendumInfo::TimedOut(moment, submission, decision),
+			ReferendumInfo::Killed(moment) => ReferendumInfo::Killed(moment),
+		})
+	}
+}
+
+/// The locks that stay on the Relay Chain during the migration.
+///
+/// Staking stays active on the Relay Chain until the sessions are fed by Asset Hub, so the bonded
+/// funds must not leave.
+pub struct RcOnlyLocks;
+impl Contains<LockIdentifier> for RcOnlyLocks {
+	fn contains(id: &LockIdentifier) -> bool {
+		// The lock of `pallet_staking`.
+		id == b"staking "
+	}
+}
+
+/// The reserves that stay on the Relay Chain during the migration.
+///
+/// The registrar and HRMP are not migrated, so the deposits of registered parachains and of HRMP
+/// channels and requests remain reserved on the Relay Chain.
+pub struct RcOnlyReserves;
+impl pallet_rc_migrator::RcOnlyReservesProvider for RcOnlyReserves {
+	fn rc_only_reserves() -> (Vec<(AccountId, Balance)>, Weight) {
+		let mut reads = 1u64;
+		let mut reserves = BTreeMap::<AccountId, Balance>::new();
+		let mut add = |who: AccountId, amount: Balance| {
+			let reserve = reserves.entry(who).or_default();
+			*reserve = reserve.saturating_add(amount);
+		};
+		let sovereign = |para: ParaId| -> AccountId { para.into_account_truncating() };
+
+		for (_, info) in paras_registrar::Paras::<Runtime>::iter() {
+			reads += 1;
+			add(info.manager, info.deposit);
+		}
+		for (id, channel) in parachains_hrmp::HrmpChannels::<Runtime>::iter() {
+			reads += 1;
+			add(sovereign(id.sender), channel.sender_deposit);
+			add(sovereign(id.recipient), channel.recipient_deposit);
+		}
+		let recipient_deposit =
+			parachains_configuration::ActiveConfig::<Runtime>::get().hrmp_recipient_deposit;
+		for (id, request) in parachains_hrmp::HrmpOpenChannelRequests::<Runtime>::iter() {
+			reads += 1;
+			add(sovereign(id.sender), request.sender_deposit);
+			// The recipient reserves its deposit when it accepts the request.
+			if request.confirmed {
+				add(sovereign(id.recipient), recipient_deposit);
+			}
+		}
+
+		(reserves.into_iter().collect(), RocksDbWeight::get().reads(reads))
+	}
+}
+
+impl pallet_rc_migrator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type AssetHubOrigin = pallet_xcm::EnsureXcm<Equals<xcm_config::AssetHubLocation>>;
+	type SendXcm = xcm_config::XcmRouter;
+	type AssetHubLocation = xcm_config::AssetHubLocation;
+	type CheckingAccount = xcm_config::CheckAccount;
+	type PortableHoldReason = RcToPortableHoldReason;
+	type PortableFreezeReason = RcToPortableFreezeReason;
+	type PortableReferendumInfo = RcToPortableReferendumInfo;
+	type PortableSchedulerOrigin = RcToPortableGovernanceOrigin;
+	type RcOnlyLocks = RcOnlyLocks;
+	type RcOnlyReserves = RcOnlyReserves;
+	type MaxAccountsPerBlock = RcMigratorMaxAccountsPerBlock;
+	type MaxItemsPerBlock = RcMigratorMaxItemsPerBlock;
+	type AhWeightPerAccount = AhWeightPerAccount;
+	type AhWeightPerItem = AhWeightPerItem;
+	type AhUpdateStageWeight = AhUpdateStageWeight;
+	type WeightInfo = weights::pallet_rc_migrator::WeightInfo<Runtime>;
+}
+
+/// Calls that are paused while the Relay Chain state is migrated to Asset Hub.
+///
+/// Balance transfers, staking, governance, multisig and proxy calls are paused, so that the
+/// migrated state does not change under the migrator.
+pub struct MigrationPausedCalls;
+impl Contains<RuntimeCall> for MigrationPausedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		RcMigrator::is_ongoing() &&
+			matches!(
+				call,
+				// Transfers.
+				RuntimeCall::Balances(..) |
+					RuntimeCall::Indices(..) |
+					RuntimeCall::Vesting(..) |
+					RuntimeCall::Claims(..) |
+					RuntimeCall::XcmPallet(
+						pallet_xcm::Call::teleport_assets { .. } |
+							pallet_xcm::Call::limited_teleport_assets { .. } |
+							pallet_xcm::Call::reserve_transfer_assets { .. } |
+							pallet_xcm::Call::limited_reserve_transfer_assets { .. } |
+							pallet_xcm::Call::transfer_assets { .. } |
+							pallet_xcm::Call::transfer_assets_using_type_and_then { .. } |
+							pallet_xcm::Call::execute { .. }
+					) |
+					// Staking.
+					RuntimeCall::Staking(..) |
+					RuntimeCall::NominationPools(..) |
+					RuntimeCall::FastUnstake(..) |
+					RuntimeCall::VoterList(..) |
+					// Governance.
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::Bounties(..) |
+					RuntimeCall::ChildBounties(..) |
+					RuntimeCall::ConvictionVoting(..) |
+					RuntimeCall::Referenda(..) |
+					RuntimeCall::Whitelist(..) |
+					RuntimeCall::Preimage(..) |
+					RuntimeCall::Scheduler(..) |
+					// Leases and crowdloans.
+					RuntimeCall::Slots(..) |
+					RuntimeCall::Auctions(..) |
+					RuntimeCall::Crowdloan(..) |
+					// Multisigs and proxies.
+					RuntimeCall::Multisig(..) |
+					RuntimeCall::Proxy(..)
+			)
+	}
+}
+
+/// Calls that are paused while a multi-block migration runs.
+///
+/// Blocks only include inherents while a migration runs, this also pauses the calls that are
+/// dispatched through XCM or the scheduler. Only the calls that are needed to build blocks, to
+/// control the migration with sudo and to keep the Asset Hub Migration and the staking messages
+/// between the chains going stay available.
+pub struct MbmPausedCalls;
+impl Contains<RuntimeCall> for MbmPausedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		MultiBlockMigrations::ongoing() &&
+			!matches!(
+				call,
+				RuntimeCall::System(..) |
+					RuntimeCall::Timestamp(..) |
+					RuntimeCall::ParaInherent(..) |
+					RuntimeCall::MultiBlockMigrations(..) |
+					RuntimeCall::Sudo(..) |
+					// Messages of Asset Hub that keep the Asset Hub Migration and staking going.
+					RuntimeCall::RcMigrator(pallet_rc_migrator::Call::receive_processed { .. }) |
+					RuntimeCall::SessionClient(
+						pallet_session_client::Call::receive_validator_set { .. }
+					)
+			)
+	}
+}
+
+/// Logs the start and the end of multi-block migrations.
+///
+/// Calls are paused by [`MbmPausedCalls`] for as long as a migration runs.
+pub struct MbmStatusHandler;
+impl MigrationStatusHandler for MbmStatusHandler {
+	fn started() {
+		log::info!(target: "runtime::mbm", "Multi-block migrations started, pausing calls");
+	}
+
+	fn completed() {
+		log::info!(target: "runtime::mbm", "Multi-block migrations completed, resuming calls");
+	}
+}
+
 construct_runtime! {
 	pub enum Runtime
 	{
@@ -1576,6 +2021,12 @@ construct_runtime! {
 		// Staking extension for delegation
 		DelegatedStaking: pallet_delegated_staking = 41,
 
+		// Hands the sessions to the local staking or to staking on Asset Hub.
+		SessionClient: pallet_session_client = 42,
+
+		// Parameters that governance can change at runtime.
+		Parameters: pallet_parameters = 43,
+
 		// Parachains pallets. Start indices at 50 to leave room.
 		ParachainsOrigin: parachains_origin = 50,
 		Configuration: parachains_configuration = 51,
@@ -1593,6 +2044,8 @@ construct_runtime! {
 		ParasSlashing: parachains_slashing = 63,
 		OnDemand: parachains_on_demand = 64,
 		CoretimeAssignmentProvider: parachains_assigner_coretime = 65,
+		OnDemandCredits: pallet_on_demand_credits = 66,
+		CoretimeAssignments: pallet_coretime_assignments = 67,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar = 70,
@@ -1613,6 +2066,9 @@ construct_runtime! {
 		// Asset rate.
 		AssetRate: pallet_asset_rate = 101,
 
+		// Multi-block migrations.
+		MultiBlockMigrations: pallet_migrations = 102,
+
 		// BEEFY Bridges support.
 		Beefy: pallet_beefy = 200,
 		// MMR leaf construction must be after session in order to have a leaf's next_auth_set
@@ -1622,6 +2078,9 @@ construct_runtime! {
 
 		// Sudo.
 		ParaSudoWrapper: paras_sudo_wrapper = 250,
+
+		// Asset Hub Migration.
+		RcMigrator: pallet_rc_migrator = 254,
 		Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>, Config<T>} = 255,
 	}
 }
@@ -1662,10 +2121,16 @@ pub mod migrations {
 	use super::*;
 
 	/// Unreleased migrations. Add new ones here:
-	pub type Unreleased = ();
+	pub type Unreleased = (on_demand_credits::FundPot<Runtime>,);
 
 	/// Migrations/checks that do not need to be versioned and can run on every update.
 	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+
+	/// Multi-block migrations, run by `pallet_migrations` in the blocks after a runtime upgrade.
+	///
+	/// Each migration implements `SteppedMigration` and stores its cursor between the steps, so
+	/// that storage moves that do not fit into one block are spread over as many blocks as needed.
+	pub type MultiBlock = ();
 }
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1705,6 +2170,8 @@ mod benches {
 		[runtime_parachains::paras_inherent, ParaInherent]
 		[runtime_parachains::on_demand, OnDemand]
 		[runtime_parachains::coretime, Coretime]
+		[pallet_coretime_assignments, CoretimeAssignments]
+		[pallet_on_demand_credits, OnDemandCredits]
 		// Substrate
 		[pallet_bags_list, VoterList]
 		[pallet_balances, Balances]
@@ -1717,13 +2184,17 @@ mod benches {
 		[pallet_fast_unstake, FastUnstake]
 		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_parameters, Parameters]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
+		[pallet_rc_migrator, RcMigrator]
 		[pallet_scheduler, Scheduler]
 		[pallet_session, SessionBench::<Runtime>]
+		[pallet_session_client, SessionClient]
 		[pallet_staking, Staking]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
@@ -2381,6 +2852,22 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl paseo_runtime_constants::ahm::AhMigrationApi<Block> for Runtime {
+		fn translated_account(who: AccountId) -> AccountId {
+			paseo_runtime_constants::ahm::translate_account(who)
+		}
+
+		fn para_sovereign_account(para_id: u32) -> AccountId {
+			paseo_runtime_constants::ahm::sibling_sovereign_account(para_id.into())
+		}
+	}
+
+	impl paseo_runtime_constants::ahm::AhMigrationProgressApi<Block> for Runtime {
+		fn migration_progress() -> paseo_runtime_constants::ahm::MigrationProgress {
+			RcMigrator::progress()
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -2648,6 +3135,179 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn on_demand_credits_come_from_the_broker() {
+		use frame_support::{
+			assert_noop, assert_ok,
+			traits::{fungible::Inspect, OnRuntimeUpgrade},
+		};
+
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			let who = AccountId::from([1u8; 32]);
+			let amount = 10 * ExistentialDeposit::get();
+			let para =
+				|id: u32| RuntimeOrigin::from(parachains_origin::Origin::Parachain(id.into()));
+
+			// The migration funds the existential deposit of the pot once.
+			on_demand_credits::FundPot::<Runtime>::on_runtime_upgrade();
+			on_demand_credits::FundPot::<Runtime>::on_runtime_upgrade();
+			assert_eq!(
+				Balances::balance(&OnDemandCredits::account_id()),
+				ExistentialDeposit::get()
+			);
+
+			// Only the broker can credit accounts.
+			assert_noop!(
+				OnDemandCredits::credit_account(RuntimeOrigin::signed(who.clone()), who.clone(), 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				OnDemandCredits::credit_account(para(1000), who.clone(), 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(OnDemandCredits::credit_account(para(BrokerId::get()), who.clone(), amount));
+
+			// The credits are backed by the pot, which is kept alive.
+			assert_eq!(pallet_on_demand_credits::Credits::<Runtime>::get(&who), amount);
+			assert_eq!(
+				Balances::balance(&OnDemandCredits::account_id()),
+				amount + ExistentialDeposit::get()
+			);
+
+			// Accounts without credits cannot spend them.
+			assert_noop!(
+				OnDemandCredits::place_order_with_credits(
+					RuntimeOrigin::signed(AccountId::from([2u8; 32])),
+					amount,
+					1000.into()
+				),
+				pallet_on_demand_credits::Error::<Runtime>::NoCredits
+			);
+		});
+	}
+
+	#[test]
+	fn on_demand_orders_are_paid_with_credits() {
+		use frame_support::{
+			assert_ok,
+			traits::{fungible::Inspect, OnRuntimeUpgrade},
+		};
+
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			let who = AccountId::from([1u8; 32]);
+			let amount = 10 * ExistentialDeposit::get();
+			let broker =
+				RuntimeOrigin::from(parachains_origin::Origin::Parachain(BrokerId::get().into()));
+			on_demand_credits::FundPot::<Runtime>::on_runtime_upgrade();
+			assert_ok!(OnDemandCredits::credit_account(broker, who.clone(), amount));
+
+			// Without any traffic the spot price is the base fee.
+			let spot_price = parachains_configuration::ActiveConfig::<Runtime>::get()
+				.scheduler_params
+				.on_demand_base_fee;
+			assert!(spot_price < amount);
+			assert_ok!(OnDemandCredits::place_order_with_credits(
+				RuntimeOrigin::signed(who.clone()),
+				amount,
+				2000.into()
+			));
+
+			// Only the spot price is deducted from the credits and the pot stays alive.
+			System::assert_last_event(
+				pallet_on_demand_credits::Event::OrderPlacedWithCredits {
+					who: who.clone(),
+					para_id: 2000.into(),
+					spent: spot_price,
+				}
+				.into(),
+			);
+			assert_eq!(
+				pallet_on_demand_credits::Credits::<Runtime>::get(&who),
+				amount - spot_price
+			);
+			assert_eq!(
+				Balances::balance(&OnDemandCredits::account_id()),
+				ExistentialDeposit::get() + amount - spot_price
+			);
+		});
+	}
+
+	#[test]
+	fn chunked_core_assignments_are_reassembled() {
+		use frame_support::{assert_noop, assert_ok};
+		use pallet_broker::CoreAssignment;
+		use parachains_assigner_coretime::PartsOf57600;
+
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			let broker = || {
+				RuntimeOrigin::from(parachains_origin::Origin::Parachain(BrokerId::get().into()))
+			};
+			// The worst case interlacing of the broker: 80 tasks sharing the 57600 parts.
+			let assignment: Vec<_> = (0..80)
+				.map(|i| (CoreAssignment::Task(2000 + i), PartsOf57600::new_saturating(720)))
+				.collect();
+
+			let mut chunks = assignment.chunks(28).peekable();
+			while let Some(chunk) = chunks.next() {
+				assert_ok!(CoretimeAssignments::assign_core_chunk(
+					broker(),
+					0,
+					10,
+					chunk.to_vec(),
+					None
+				));
+				// The core is only assigned once all the parts were received.
+				let pending = pallet_coretime_assignments::PendingAssignments::<Runtime>::get(0);
+				assert_eq!(pending.is_some(), chunks.peek().is_some());
+			}
+			System::assert_last_event(
+				pallet_coretime_assignments::Event::<Runtime>::CoreAssigned {
+					core: 0,
+					begin: 10,
+					chunks: 3,
+				}
+				.into(),
+			);
+
+			// Assignments beyond 57600 parts are rejected.
+			assert_ok!(CoretimeAssignments::assign_core_chunk(
+				broker(),
+				1,
+				10,
+				assignment[..79].to_vec(),
+				None
+			));
+			assert_noop!(
+				CoretimeAssignments::assign_core_chunk(
+					broker(),
+					1,
+					10,
+					vec![(CoreAssignment::Pool, PartsOf57600::new_saturating(1440))],
+					None
+				),
+				pallet_coretime_assignments::Error::<Runtime>::OverScheduled
+			);
+
+			// Only the broker can assign cores.
+			assert_noop!(
+				CoretimeAssignments::assign_core_chunk(
+					RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+					2,
+					10,
+					assignment,
+					None
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert!(pallet_coretime_assignments::PendingAssignments::<Runtime>::get(2).is_none());
+		});
+	}
+
 	#[test]
 	fn ensure_xcm_metadata_is_correct() {
 		let path = xcm::VersionedXcm::<()>::type_info().path;
@@ -2700,106 +3360,206 @@ mod multiplier_tests {
 
 	#[test]
 	fn staking_inflation_correct_single_era() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123, // ignored
-			456, // ignored
-			MILLISECONDS_PER_DAY,
-		);
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+				123, // ignored
+				456, // ignored
+				MILLISECONDS_PER_DAY,
+			);
 
-		// Values are within 0.1%
-		assert_relative_eq!(to_stakers as f64, (27_693 * UNITS) as f64, max_relative = 0.001);
-		assert_relative_eq!(to_treasury as f64, (4_887 * UNITS) as f64, max_relative = 0.001);
-		// Total per day is ~32,580  PAS
-		assert_relative_eq!(
-			(to_stakers as f64 + to_treasury as f64),
-			(32_580 * UNITS) as f64,
-			max_relative = 0.001
-		);
+			// Values are within 0.1%
+			assert_relative_eq!(to_stakers as f64, (27_693 * UNITS) as f64, max_relative = 0.001);
+			assert_relative_eq!(to_treasury as f64, (4_887 * UNITS) as f64, max_relative = 0.001);
+			// Total per day is ~32,580  PAS
+			assert_relative_eq!(
+				(to_stakers as f64 + to_treasury as f64),
+				(32_580 * UNITS) as f64,
+				max_relative = 0.001
+			);
+		});
 	}
 
 	#[test]
 	fn staking_inflation_correct_longer_era() {
-		// Twice the era duration means twice the emission:
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123, // ignored
-			456, // ignored
-			2 * MILLISECONDS_PER_DAY,
-		);
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			// Twice the era duration means twice the emission:
+			let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+				123, // ignored
+				456, // ignored
+				2 * MILLISECONDS_PER_DAY,
+			);
 
-		assert_relative_eq!(
-			to_stakers as f64,
-			(27_693 * UNITS) as f64 * 2.0,
-			max_relative = 0.001
-		);
-		assert_relative_eq!(
-			to_treasury as f64,
-			(4_887 * UNITS) as f64 * 2.0,
-			max_relative = 0.001
-		);
+			assert_relative_eq!(
+				to_stakers as f64,
+				(27_693 * UNITS) as f64 * 2.0,
+				max_relative = 0.001
+			);
+			assert_relative_eq!(
+				to_treasury as f64,
+				(4_887 * UNITS) as f64 * 2.0,
+				max_relative = 0.001
+			);
+		});
 	}
 
 	#[test]
 	fn staking_inflation_correct_whole_year() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123,                                  // ignored
-			456,                                  // ignored
-			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
-		);
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+				123,                                  // ignored
+				456,                                  // ignored
+				(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
+			);
 
-		// Our yearly emissions is about 12M PAS:
-		let yearly_emission = 11_909_325 * UNITS;
-		assert_relative_eq!(
-			to_stakers as f64 + to_treasury as f64,
-			yearly_emission as f64,
-			max_relative = 0.001
-		);
+			// Our yearly emissions is about 12M PAS:
+			let yearly_emission = 11_909_325 * UNITS;
+			assert_relative_eq!(
+				to_stakers as f64 + to_treasury as f64,
+				yearly_emission as f64,
+				max_relative = 0.001
+			);
 
-		assert_relative_eq!(to_stakers as f64, yearly_emission as f64 * 0.85, max_relative = 0.001);
-		assert_relative_eq!(
-			to_treasury as f64,
-			yearly_emission as f64 * 0.15,
-			max_relative = 0.001
-		);
+			assert_relative_eq!(
+				to_stakers as f64,
+				yearly_emission as f64 * 0.85,
+				max_relative = 0.001
+			);
+			assert_relative_eq!(
+				to_treasury as f64,
+				yearly_emission as f64 * 0.15,
+				max_relative = 0.001
+			);
+		});
 	}
 
 	// 10 years into the future, our values do not overflow.
 	#[test]
 	fn staking_inflation_correct_not_overflow() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123,                                 // ignored
-			456,                                 // ignored
-			(36525 * MILLISECONDS_PER_DAY) / 10, // 10 years
-		);
-		let initial_ti: i128 = 1_487_502_468_008_283_162;
-		let projected_total_issuance = (to_stakers as i128 + to_treasury as i128) + initial_ti;
-
-		// In 2034, there will be about 267 million PAS in existence.
-		assert_relative_eq!(
-			projected_total_issuance as f64,
-			(267_750_000 * UNITS) as f64,
-			max_relative = 0.001
-		);
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+				123,                                 // ignored
+				456,                                 // ignored
+				(36525 * MILLISECONDS_PER_DAY) / 10, // 10 years
+			);
+			let initial_ti: i128 = 1_487_502_468_008_283_162;
+			let projected_total_issuance = (to_stakers as i128 + to_treasury as i128) + initial_ti;
+
+			// In 2034, there will be about 267 million PAS in existence.
+			assert_relative_eq!(
+				projected_total_issuance as f64,
+				(267_750_000 * UNITS) as f64,
+				max_relative = 0.001
+			);
+		});
 	}
 
 	// Print percent per year, just as convenience.
 	#[test]
 	fn staking_inflation_correct_print_percent() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
-			123,                                  // ignored
-			456,                                  // ignored
-			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
-		);
-		let yearly_emission = to_stakers + to_treasury;
-		let mut ti: i128 = 1_487_502_468_008_283_162;
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+				123,                                  // ignored
+				456,                                  // ignored
+				(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
+			);
+			let yearly_emission = to_stakers + to_treasury;
+			let mut ti: i128 = 1_487_502_468_008_283_162;
 
-		for y in 0..10 {
-			let new_ti = ti + yearly_emission as i128;
-			let inflation = 100.0 * (new_ti - ti) as f64 / ti as f64;
-			println!("Year {y} inflation: {inflation}%");
-			ti = new_ti;
+			for y in 0..10 {
+				let new_ti = ti + yearly_emission as i128;
+				let inflation = 100.0 * (new_ti - ti) as f64 / ti as f64;
+				println!("Year {y} inflation: {inflation}%");
+				ti = new_ti;
 
-			assert!(inflation <= 8.0 && inflation > 2.0, "sanity check");
-		}
+				assert!(inflation <= 8.0 && inflation > 2.0, "sanity check");
+			}
+		});
+	}
+
+	#[test]
+	fn staking_inflation_follows_dynamic_params() {
+		use dynamic_params::{inflation, treasury};
+		use frame_support::{assert_noop, assert_ok};
+		use pallet_custom_origins::Origin::{StakingAdmin, Treasurer};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let inflation_rate =
+				RuntimeParameters::Inflation(inflation::Parameters::InflationRate(
+					inflation::InflationRate,
+					Some(Perbill::from_percent(4)),
+				));
+			let treasury_share = RuntimeParameters::Treasury(treasury::Parameters::TreasuryShare(
+				treasury::TreasuryShare,
+				Some(Perbill::from_percent(0)),
+			));
+			let (to_stakers, to_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+
+			// Only `StakingAdmin` sets the inflation rate, halving the inflation halves both.
+			assert_noop!(
+				Parameters::set_parameter(Treasurer.into(), inflation_rate.clone()),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Parameters::set_parameter(StakingAdmin.into(), inflation_rate));
+			let (halved_stakers, halved_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+			assert_relative_eq!(
+				halved_stakers as f64,
+				to_stakers as f64 / 2.0,
+				max_relative = 0.001
+			);
+			assert_relative_eq!(
+				halved_treasury as f64,
+				to_treasury as f64 / 2.0,
+				max_relative = 0.001
+			);
+
+			// Only `Treasurer` sets the share of the treasury.
+			assert_noop!(
+				Parameters::set_parameter(StakingAdmin.into(), treasury_share.clone()),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(Parameters::set_parameter(Treasurer.into(), treasury_share));
+			let (to_stakers, to_treasury) =
+				super::EraPayout::era_payout(123, 456, MILLISECONDS_PER_DAY);
+			assert_eq!(to_treasury, 0);
+			assert_eq!(to_stakers, halved_stakers + halved_treasury);
+		});
+	}
+
+	#[test]
+	fn staking_durations_follow_dynamic_params() {
+		use dynamic_params::staking;
+		use frame_support::assert_ok;
+		use pallet_custom_origins::Origin::StakingAdmin;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let set = |parameter| {
+				assert_ok!(Parameters::set_parameter(
+					StakingAdmin.into(),
+					RuntimeParameters::Staking(parameter)
+				))
+			};
+			assert_eq!(<Runtime as pallet_staking::Config>::BondingDuration::get(), 28);
+			assert_eq!(<Runtime as pallet_staking::Config>::SlashDeferDuration::get(), 27);
+
+			// The equivocation reports and the set ids of GRANDPA and BEEFY are kept for the
+			// sessions of the bonding duration.
+			set(staking::Parameters::BondingDuration(staking::BondingDuration, Some(14)));
+			let sessions = 14 * SessionsPerEra::get();
+			assert_eq!(<Runtime as pallet_staking::Config>::BondingDuration::get(), 14);
+			assert_eq!(
+				<Runtime as pallet_grandpa::Config>::MaxSetIdSessionEntries::get(),
+				sessions
+			);
+			assert_eq!(<Runtime as pallet_beefy::Config>::MaxSetIdSessionEntries::get(), sessions);
+			assert_eq!(ReportLongevity::get(), sessions as u64 * EpochDuration::get());
+
+			// The slashes are always deferred for less than the bonding duration.
+			assert_eq!(<Runtime as pallet_staking::Config>::SlashDeferDuration::get(), 13);
+			set(staking::Parameters::SlashDeferDuration(staking::SlashDeferDuration, Some(7)));
+			assert_eq!(<Runtime as pallet_staking::Config>::SlashDeferDuration::get(), 7);
+		});
 	}
 
 	#[test]
@@ -2951,6 +3711,793 @@ mod staking_tests {
 			);
 		});
 	}
+
+	#[test]
+	fn session_client_hands_over_to_asset_hub() {
+		use pallet_session::historical::SessionManager;
+		use paseo_runtime_constants::staking::{SessionClientMode, ValidatorSetReport};
+
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			let validator = AccountId::from([1u8; 32]);
+			assert_ok!(SessionClient::set_mode(RuntimeOrigin::root(), SessionClientMode::AssetHub));
+
+			// Reward points are kept for Asset Hub instead of going to the local staking.
+			SessionClient::reward_by_ids(vec![(validator.clone(), 20), (validator.clone(), 20)]);
+			assert_eq!(pallet_session_client::ValidatorPoints::<Runtime>::get(&validator), 40);
+
+			// Only Asset Hub can send validator sets.
+			let report =
+				ValidatorSetReport { planned_session: 5, validators: vec![validator.clone()] };
+			assert_noop!(
+				SessionClient::receive_validator_set(RuntimeOrigin::root(), report.clone()),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			let asset_hub = pallet_xcm::Origin::Xcm(xcm_config::AssetHubLocation::get());
+			assert_ok!(SessionClient::receive_validator_set(asset_hub.into(), report));
+
+			// The set is handed to the session pallet at the next rotation, and only once.
+			assert_eq!(
+				<SessionClient as SessionManager<_, _>>::new_session(4),
+				Some(vec![(validator, Default::default())])
+			);
+			assert_eq!(<SessionClient as SessionManager<_, _>>::new_session(5), None);
+		});
+	}
+
+	#[test]
+	fn offences_that_cannot_be_reported_are_kept() {
+		use frame_support::traits::Hooks;
+		use paseo_runtime_constants::staking::SessionClientMode;
+		use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let offender = AccountId::from([1u8; 32]);
+			let details = |who: &AccountId| OffenceDetails {
+				offender: (who.clone(), Default::default()),
+				reporters: vec![],
+			};
+			assert_ok!(SessionClient::set_mode(RuntimeOrigin::root(), SessionClientMode::AssetHub));
+
+			// Asset Hub is not reachable in this test, so the offences stay pending with the
+			// highest slash of each offender.
+			<SessionClient as OnOffenceHandler<_, _, _>>::on_offence(
+				&[details(&offender)],
+				&[Perbill::from_percent(10)],
+				0,
+			);
+			<SessionClient as OnOffenceHandler<_, _, _>>::on_offence(
+				&[details(&offender)],
+				&[Perbill::from_percent(5)],
+				0,
+			);
+			assert_eq!(
+				pallet_session_client::PendingOffences::<Runtime>::get(0, &offender),
+				Some(Perbill::from_percent(10))
+			);
+
+			// They are tried again, and kept as long as they cannot be sent.
+			SessionClient::on_initialize(1);
+			assert_eq!(
+				pallet_session_client::PendingOffences::<Runtime>::get(0, &offender),
+				Some(Perbill::from_percent(10))
+			);
+		});
+	}
+
+	#[test]
+	fn validators_of_asset_hub_can_set_session_keys() {
+		use paseo_runtime_constants::staking::SessionClientMode;
+		use sp_runtime::traits::Convert;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			// The account is not bonded on the Relay Chain.
+			let validator = AccountId::from([1u8; 32]);
+			assert_eq!(session_client::ValidatorIdOf::<Runtime>::convert(validator.clone()), None);
+
+			assert_ok!(SessionClient::set_mode(RuntimeOrigin::root(), SessionClientMode::AssetHub));
+			assert_eq!(
+				session_client::ValidatorIdOf::<Runtime>::convert(validator.clone()),
+				Some(validator)
+			);
+		});
+	}
+}
+
+#[cfg(test)]
+mod ah_migration_tests {
+	use super::*;
+	use crate::ah_migration::AhMigratorCalls;
+	use frame_support::{
+		assert_ok,
+		traits::{
+			fungible::{Inspect, Mutate},
+			schedule::DispatchTime,
+			LockableCurrency, ReservableCurrency,
+		},
+	};
+	use paseo_runtime_constants::ahm::{
+		sibling_sovereign_account, translate_para_sovereign_account, MigratedPallet,
+		MigrationStage, PortableAgentLedger, PortableAnnouncement, PortableBondedPoolInner,
+		PortableBounty, PortableBountyStatus, PortableChildBounty, PortableChildBountyStatus,
+		PortableClaimPermission, PortableCommission, PortableCommissionClaimPermission,
+		PortableDelegation, PortableLock, PortableLockReasons, PortableNominations,
+		PortablePaymentState, PortablePoolMember, PortablePoolRoles, PortablePoolStake,
+		PortablePoolState, PortableProxyDefinition, PortableRequestStatus,
+		PortableRewardDestination, PortableSpendStatus, PortableStakingLedger,
+		PortableTreasuryProposal, PortableVestingInfo, RcClaimsMessage, RcConvictionVotingMessage,
+		RcDelegatedStakingMessage, RcMultisig, RcNomPoolsMessage, RcPreimageMessage,
+		RcProxyMessage, RcReferendum, RcTreasuryMessage, RcVestingSchedule, TranslateAccounts,
+	};
+	use polkadot_runtime_common::claims::EthereumAddress;
+	use sp_core::H256;
+	use xcm_builder::MintLocation;
+
+	#[test]
+	fn withdraw_account_unlocks_and_teleports_everything() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			let who = AccountId::from([1u8; 32]);
+			let amount = ExistentialDeposit::get() * 100;
+			Balances::set_balance(&who, amount);
+			assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&who, amount / 4));
+			<Balances as LockableCurrency<_>>::set_lock(
+				*b"vesting ",
+				&who,
+				amount / 2,
+				WithdrawReasons::all(),
+			);
+			let issuance = <Balances as Inspect<_>>::total_issuance();
+
+			let account = RcMigrator::withdraw_account(&who).unwrap().unwrap();
+
+			assert_eq!(account.free, amount);
+			assert_eq!(account.reserved, amount / 4);
+			assert_eq!(account.unnamed_reserve, amount / 4);
+			assert_eq!(
+				account.locks,
+				vec![PortableLock {
+					id: *b"vesting ",
+					amount: amount / 2,
+					reasons: PortableLockReasons::All
+				}]
+			);
+			// The account is reaped and its balance is accounted as teleported.
+			assert_eq!(<Balances as Inspect<_>>::total_balance(&who), 0);
+			assert_eq!(
+				<Balances as Inspect<_>>::total_balance(&xcm_config::CheckAccount::get()),
+				amount
+			);
+			assert_eq!(<Balances as Inspect<_>>::total_issuance(), issuance);
+			// Nothing to migrate on the second visit.
+			assert_eq!(RcMigrator::withdraw_account(&who), Ok(None));
+		});
+	}
+
+	#[test]
+	fn processed_items_reported_by_asset_hub_are_recorded() {
+		use crate::ah_migration::Error as RcMigratorError;
+		use frame_support::assert_noop;
+		use sp_runtime::DispatchError;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let asset_hub: RuntimeOrigin =
+				pallet_xcm::Origin::Xcm(xcm_config::AssetHubLocation::get()).into();
+			assert_noop!(
+				RcMigrator::receive_processed(
+					RuntimeOrigin::signed(AccountId::from([1u8; 32])),
+					MigratedPallet::Proxy,
+					1,
+					0
+				),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(RcMigrator::receive_processed(
+				asset_hub.clone(),
+				MigratedPallet::Proxy,
+				3,
+				0
+			));
+			assert_eq!(RcMigrator::progress().last_error, None);
+			assert_ok!(RcMigrator::receive_processed(asset_hub, MigratedPallet::Proxy, 2, 1));
+
+			let progress = RcMigrator::progress();
+			let (_, proxy) = progress
+				.items
+				.iter()
+				.find(|(pallet, _)| *pallet == MigratedPallet::Proxy)
+				.unwrap();
+			assert_eq!((proxy.acknowledged, proxy.failed), (5, 1));
+			assert_eq!(
+				progress.last_error,
+				Some(RcMigratorError::<Runtime>::FailedOnAssetHub.into())
+			);
+		});
+	}
+
+	#[test]
+	fn scheduler_lookup_and_retries_are_cleared_in_steps() {
+		use crate::ah_migration::scheduler::{alias, SchedulerPosition};
+
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			for i in 0..3u8 {
+				alias::Lookup::<Runtime>::insert([i; 32], (10, i as u32));
+				alias::Retries::<Runtime>::insert((10, i as u32), (3, 0, 5));
+			}
+		});
+		// `clear` only limits the removal of keys that are committed to the backend.
+		ext.commit_all().unwrap();
+		ext.execute_with(|| {
+			let next = RcMigrator::clear_scheduler(SchedulerPosition::Lookup(None), 2);
+			assert!(matches!(next, Some(SchedulerPosition::Lookup(Some(_)))));
+			assert_eq!(alias::Lookup::<Runtime>::iter_keys().count(), 1);
+
+			let next = RcMigrator::clear_scheduler(next.unwrap(), 2);
+			assert_eq!(next, Some(SchedulerPosition::Retries(None)));
+			assert_eq!(alias::Lookup::<Runtime>::iter_keys().count(), 0);
+			assert_eq!(alias::Retries::<Runtime>::iter_keys().count(), 3);
+
+			let next = RcMigrator::clear_scheduler(next.unwrap(), 2);
+			assert!(matches!(next, Some(SchedulerPosition::Retries(Some(_)))));
+			assert_eq!(alias::Retries::<Runtime>::iter_keys().count(), 1);
+
+			assert_eq!(RcMigrator::clear_scheduler(next.unwrap(), 2), None);
+			assert_eq!(alias::Retries::<Runtime>::iter_keys().count(), 0);
+		});
+	}
+
+	#[test]
+	fn crowdloan_messages_are_taken_in_batches() {
+		use crate::ah_migration::crowdloan::alias;
+		use paseo_runtime_constants::ahm::RcCrowdloanMessage;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let [alice, bob, depositor] = [1u8, 2, 3].map(|i| AccountId::from([i; 32]));
+			let para_id = ParaId::from(2000);
+			let period = LeasePeriod::get();
+			alias::Leases::<Runtime>::insert(
+				para_id,
+				vec![
+					Some((alice.clone(), 10)),
+					Some((bob.clone(), 20)),
+					None,
+					Some((alice.clone(), 30)),
+				],
+			);
+			alias::Funds::<Runtime>::insert(
+				para_id,
+				crowdloan::FundInfo {
+					depositor: depositor.clone(),
+					verifier: None,
+					deposit: 5,
+					raised: 50,
+					end: 100,
+					cap: 1_000,
+					last_contribution: crowdloan::LastContribution::Never,
+					first_period: 0,
+					last_period: 3,
+					fund_index: 0,
+				},
+			);
+			for i in 10u8..15 {
+				let contributor = AccountId::from([i; 32]);
+				crowdloan::Pallet::<Runtime>::contribution_put(0, &contributor, &10, &[]);
+			}
+			let is_contribution = |message: &RcCrowdloanMessage| {
+				matches!(
+					message,
+					RcCrowdloanMessage::CrowdloanContribution {
+						withdraw_block: 100,
+						amount: 10,
+						..
+					}
+				)
+			};
+
+			// The lease reserves of a parachain are never split, even if they exceed `max`.
+			let (batch, done) = RcMigrator::take_crowdloan_messages(1);
+			assert!(!done);
+			assert_eq!(
+				batch,
+				vec![
+					RcCrowdloanMessage::LeaseReserve {
+						unreserve_block: 4 * period,
+						para_id,
+						who: alice,
+						amount: 30,
+					},
+					RcCrowdloanMessage::LeaseReserve {
+						unreserve_block: 2 * period,
+						para_id,
+						who: bob,
+						amount: 20,
+					},
+				]
+			);
+
+			// The crowdloan did not win the lease, it can be withdrawn at its end.
+			let (batch, done) = RcMigrator::take_crowdloan_messages(3);
+			assert!(!done);
+			assert_eq!(
+				batch[0],
+				RcCrowdloanMessage::CrowdloanDeposit {
+					unreserve_block: 100,
+					para_id,
+					depositor,
+					amount: 5,
+				}
+			);
+			assert_eq!(batch[1..].iter().filter(|m| is_contribution(m)).count(), 2);
+
+			let (batch, done) = RcMigrator::take_crowdloan_messages(3);
+			assert!(!done);
+			assert_eq!(batch.iter().filter(|m| is_contribution(m)).count(), 3);
+
+			let (batch, done) = RcMigrator::take_crowdloan_messages(3);
+			assert!(done && batch.is_empty());
+			assert_eq!(crowdloan::Pallet::<Runtime>::contribution_iterate(0).count(), 0);
+			assert_eq!(pallet_rc_migrator::CrowdloanCursor::<Runtime>::get(), None);
+		});
+	}
+
+	#[test]
+	fn lease_end_is_the_end_of_the_last_lease() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let [alice, bob] = [1u8, 2].map(|i| AccountId::from([i; 32]));
+			let period = LeasePeriod::get();
+			// The first lease is the one of the current, second lease period.
+			System::set_block_number(period + 5);
+			let leases = vec![Some((alice.clone(), 10)), None, Some((alice.clone(), 10)), None];
+
+			assert_eq!(RcMigrator::lease_end(&leases, &alice), Some(4 * period));
+			assert_eq!(RcMigrator::lease_end(&leases[..1], &alice), Some(2 * period));
+			assert_eq!(RcMigrator::lease_end(&leases, &bob), None);
+		});
+	}
+
+	#[test]
+	fn only_the_stake_of_virtual_stakers_is_migrated() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			System::set_block_number(1);
+			let stash = NominationPools::generate_bonded_account(1);
+			pallet_staking::Ledger::<Runtime>::insert(
+				&stash,
+				pallet_staking::StakingLedger::<Runtime>::new(stash.clone(), 100),
+			);
+
+			assert_eq!(RcMigrator::take_pool_stake(1), Ok(None));
+			assert!(pallet_staking::Ledger::<Runtime>::contains_key(&stash));
+			System::assert_has_event(
+				pallet_rc_migrator::Event::PoolStakeSkipped { pool: 1, stash: stash.clone() }
+					.into(),
+			);
+
+			pallet_staking::VirtualStakers::<Runtime>::insert(&stash, ());
+			let stake = RcMigrator::take_pool_stake(1).unwrap().unwrap();
+			assert_eq!(stake.ledger.total, 100);
+			assert!(!pallet_staking::Ledger::<Runtime>::contains_key(&stash));
+			assert!(!pallet_staking::VirtualStakers::<Runtime>::contains_key(&stash));
+		});
+	}
+
+	#[test]
+	fn registrar_deposits_stay_reserved_on_the_relay() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let manager = AccountId::from([1u8; 32]);
+			let deposit = ExistentialDeposit::get() * 10;
+			Balances::set_balance(&manager, deposit * 10);
+			assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&manager, deposit));
+			// `ParaInfo` has private fields, it encodes as the manager, the deposit and the lock.
+			let info = paras_registrar::ParaInfo::decode(
+				&mut &(manager.clone(), deposit, None::<bool>).encode()[..],
+			)
+			.unwrap();
+			paras_registrar::Paras::<Runtime>::insert(ParaId::from(2000), info);
+
+			RcMigrator::note_kept_reserves();
+			assert_eq!(pallet_rc_migrator::KeptReserves::<Runtime>::get(&manager), deposit);
+			let account = RcMigrator::withdraw_account(&manager).unwrap().unwrap();
+
+			// The registrar releases the deposit on the Relay Chain when the para is deregistered.
+			assert_eq!(account.unnamed_reserve, 0);
+			assert_eq!(<Balances as ReservableCurrency<_>>::reserved_balance(&manager), deposit);
+		});
+	}
+
+	#[test]
+	fn withdraw_account_keeps_staking_locks_and_relay_only_reserves() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			let who = AccountId::from([1u8; 32]);
+			let amount = ExistentialDeposit::get() * 100;
+			Balances::set_balance(&who, amount);
+			assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&who, amount / 4));
+			pallet_rc_migrator::KeptReserves::<Runtime>::insert(&who, amount / 4);
+			<Balances as LockableCurrency<_>>::set_lock(
+				*b"staking ",
+				&who,
+				amount / 2,
+				WithdrawReasons::all(),
+			);
+
+			let account = RcMigrator::withdraw_account(&who).unwrap().unwrap();
+
+			// Only what is neither bonded nor reserved for the Relay Chain is sent.
+			assert_eq!(account.free, amount / 2);
+			assert_eq!(account.reserved, 0);
+			assert_eq!(account.unnamed_reserve, 0);
+			assert!(account.locks.is_empty());
+			assert_eq!(<Balances as Inspect<_>>::total_balance(&who), amount / 2);
+			assert_eq!(<Balances as ReservableCurrency<_>>::reserved_balance(&who), amount / 4);
+			assert_eq!(pallet_balances::Locks::<Runtime>::get(&who).len(), 1);
+		});
+	}
+
+	#[test]
+	fn para_sovereign_accounts_are_translated_to_sibling_accounts() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			let para_id = ParaId::from(2000);
+			let who: AccountId = para_id.into_account_truncating();
+			Balances::set_balance(&who, ExistentialDeposit::get() * 10);
+
+			let account = RcMigrator::withdraw_account(&who).unwrap().unwrap();
+
+			let sibling: AccountId =
+				polkadot_parachain_primitives::primitives::Sibling::from(para_id)
+					.into_account_truncating();
+			assert_eq!(account.who, sibling);
+			assert_eq!(account.free, ExistentialDeposit::get() * 10);
+		});
+
+		let user = AccountId::from([1u8; 32]);
+		assert_eq!(translate_para_sovereign_account(&user), None);
+	}
+
+	#[test]
+	fn switching_teleport_tracking_keeps_the_circulating_supply() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			let checking_account = xcm_config::CheckAccount::get();
+			let local = ExistentialDeposit::get() * 100;
+			Balances::set_balance(&AccountId::from([1u8; 32]), local);
+			// Balance that was teleported to other chains.
+			Balances::set_balance(&checking_account, local * 10);
+			let issuance = <Balances as Inspect<_>>::total_issuance();
+			assert_eq!(
+				RcMigrator::teleport_tracking(),
+				Some((checking_account.clone(), MintLocation::Local))
+			);
+
+			assert_eq!(RcMigrator::do_switch_teleport_tracking(), Ok(issuance));
+
+			assert_eq!(
+				RcMigrator::teleport_tracking(),
+				Some((checking_account.clone(), MintLocation::NonLocal))
+			);
+			// Only the balance on the Relay Chain can be teleported out.
+			let checking_balance = <Balances as Inspect<_>>::total_balance(&checking_account);
+			assert_eq!(checking_balance, local);
+			assert_eq!(<Balances as Inspect<_>>::total_issuance() - checking_balance, local);
+
+			// The balance of the checking account is only set once.
+			assert_eq!(
+				RcMigrator::do_switch_teleport_tracking(),
+				Err(pallet_rc_migrator::Error::<Runtime>::AlreadySwitched.into())
+			);
+		});
+	}
+
+	#[test]
+	fn user_activity_is_paused_while_migration_is_ongoing() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			type Filter = <Runtime as frame_system::Config>::BaseCallFilter;
+			let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+				dest: AccountId::from([1u8; 32]).into(),
+				value: ExistentialDeposit::get(),
+			});
+			let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+			let execute = RuntimeCall::XcmPallet(pallet_xcm::Call::execute {
+				message: Box::new(xcm::VersionedXcm::from(xcm::v4::Xcm(vec![]))),
+				max_weight: Weight::zero(),
+			});
+
+			for stage in [MigrationStage::Pending, MigrationStage::Scheduled { start: 10 }] {
+				pallet_rc_migrator::RcMigrationStage::<Runtime>::put(stage);
+				assert!(Filter::contains(&transfer));
+			}
+
+			pallet_rc_migrator::RcMigrationStage::<Runtime>::put(MigrationStage::Ongoing(
+				MigratedPallet::Accounts,
+			));
+			assert!(!Filter::contains(&transfer));
+			assert!(!Filter::contains(&execute));
+			assert!(Filter::contains(&remark));
+
+			pallet_rc_migrator::RcMigrationStage::<Runtime>::put(MigrationStage::Done);
+			assert!(Filter::contains(&transfer));
+		});
+	}
+
+	/// The sovereign account of a parachain on the Relay Chain and its account on Asset Hub.
+	fn sovereign_and_sibling() -> (AccountId, AccountId) {
+		let para_id = ParaId::from(2000);
+		(para_id.into_account_truncating(), sibling_sovereign_account(para_id))
+	}
+
+	#[test]
+	fn migration_calls_carry_translated_accounts() {
+		let (sovereign, sibling) = sovereign_and_sibling();
+		let user = AccountId::from([1u8; 32]);
+		let address = EthereumAddress([1u8; 20]);
+		// Every message that refers to accounts, built once for the sovereign account of a
+		// parachain and once for its sibling account on Asset Hub.
+		let calls = |who: &AccountId| {
+			let deposit = pallet_referenda::Deposit { who: who.clone(), amount: 100 };
+			let stake = PortablePoolStake {
+				ledger: PortableStakingLedger {
+					stash: who.clone(),
+					total: 100,
+					active: 100,
+					unlocking: vec![],
+					legacy_claimed_rewards: vec![],
+				},
+				payee: Some(PortableRewardDestination::Account(who.clone())),
+				nominations: Some(PortableNominations {
+					targets: vec![who.clone()],
+					submitted_in: 0,
+					suppressed: false,
+				}),
+			};
+			let pool = PortableBondedPoolInner {
+				commission: PortableCommission {
+					current: Some((Perbill::from_percent(5), who.clone())),
+					max: None,
+					change_rate: None,
+					throttle_from: None,
+					claim_permission: Some(PortableCommissionClaimPermission::Account(who.clone())),
+				},
+				member_counter: 1,
+				points: 100,
+				roles: PortablePoolRoles {
+					depositor: who.clone(),
+					root: Some(who.clone()),
+					nominator: Some(who.clone()),
+					bouncer: None,
+				},
+				state: PortablePoolState::Open,
+			};
+			let member = PortablePoolMember {
+				pool_id: 1,
+				points: 100,
+				last_recorded_reward_counter: Default::default(),
+				unbonding_eras: vec![],
+			};
+			vec![
+				AhMigratorCalls::ReceiveReferenda(vec![
+					RcReferendum {
+						index: 0,
+						info: PortableReferendumInfo::Ongoing(pallet_referenda::ReferendumStatus {
+							track: 0,
+							origin: PortableGovernanceOrigin::Root,
+							proposal: Bounded::Lookup { hash: H256::repeat_byte(1), len: 3 },
+							enactment: DispatchTime::After(0),
+							submitted: 1,
+							submission_deposit: deposit.clone(),
+							decision_deposit: Some(deposit.clone()),
+							deciding: None,
+							tally: pallet_conviction_voting::Tally::from_parts(0, 0, 0),
+							in_queue: false,
+							alarm: None,
+						}),
+						metadata: None,
+					},
+					RcReferendum {
+						index: 1,
+						info: PortableReferendumInfo::Approved(
+							1,
+							Some(deposit.clone()),
+							Some(deposit),
+						),
+						metadata: None,
+					},
+				]),
+				AhMigratorCalls::ReceiveConvictionVotingMessages(vec![
+					RcConvictionVotingMessage::VotingFor(
+						who.clone(),
+						0,
+						pallet_conviction_voting::Voting::Delegating(
+							pallet_conviction_voting::Delegating {
+								balance: 100,
+								target: who.clone(),
+								conviction: pallet_conviction_voting::Conviction::None,
+								delegations: Default::default(),
+								prior: Default::default(),
+							},
+						),
+					),
+					RcConvictionVotingMessage::ClassLocksFor(who.clone(), vec![(0, 100)]),
+				]),
+				AhMigratorCalls::ReceiveTreasuryMessages(vec![
+					RcTreasuryMessage::Proposals(
+						0,
+						PortableTreasuryProposal {
+							proposer: who.clone(),
+							value: 100,
+							beneficiary: who.clone(),
+							bond: 10,
+						},
+					),
+					RcTreasuryMessage::Spends(
+						0,
+						PortableSpendStatus {
+							asset_kind: VersionedLocatableAsset::V4 {
+								location: Location::new(0, [Junction::Parachain(1000)]),
+								asset_id: Location::here().into(),
+							},
+							amount: 100,
+							beneficiary: VersionedLocation::V4(Location::new(
+								0,
+								[Junction::AccountId32 { network: None, id: who.clone().into() }],
+							)),
+							valid_from: 0,
+							expire_at: 100,
+							status: PortablePaymentState::Pending,
+						},
+					),
+					RcTreasuryMessage::Bounties(
+						0,
+						PortableBounty {
+							proposer: who.clone(),
+							value: 100,
+							fee: 1,
+							curator_deposit: 10,
+							bond: 10,
+							status: PortableBountyStatus::PendingPayout {
+								curator: who.clone(),
+								beneficiary: who.clone(),
+								unlock_at: 100,
+							},
+						},
+					),
+					RcTreasuryMessage::ChildBounties(
+						0,
+						0,
+						PortableChildBounty {
+							parent_bounty: 0,
+							value: 10,
+							fee: 1,
+							curator_deposit: 1,
+							status: PortableChildBountyStatus::Active { curator: who.clone() },
+						},
+					),
+				]),
+				AhMigratorCalls::ReceiveNomPoolsMessages(vec![
+					RcNomPoolsMessage::PoolMembers(who.clone(), member),
+					RcNomPoolsMessage::BondedPools(1, pool),
+					RcNomPoolsMessage::ClaimPermissions(
+						who.clone(),
+						PortableClaimPermission::Permissioned,
+					),
+					RcNomPoolsMessage::PoolStake(stake),
+				]),
+				AhMigratorCalls::ReceiveDelegatedStakingMessages(vec![
+					RcDelegatedStakingMessage::Agents(
+						who.clone(),
+						PortableAgentLedger {
+							payee: who.clone(),
+							total_delegated: 100,
+							unclaimed_withdrawals: 0,
+							pending_slash: 0,
+						},
+					),
+					RcDelegatedStakingMessage::Delegators(
+						who.clone(),
+						PortableDelegation { agent: who.clone(), amount: 100 },
+					),
+				]),
+				AhMigratorCalls::ReceiveMultisigs(vec![RcMultisig {
+					creator: who.clone(),
+					deposit: 100,
+				}]),
+				AhMigratorCalls::ReceiveProxyMessages(vec![
+					RcProxyMessage::Proxies {
+						delegator: who.clone(),
+						deposit: 100,
+						proxies: vec![
+							PortableProxyDefinition {
+								delegate: who.clone(),
+								proxy_type: ProxyType::Any,
+								delay: 0,
+							},
+							PortableProxyDefinition {
+								delegate: user.clone(),
+								proxy_type: ProxyType::Staking,
+								delay: 0,
+							},
+						],
+					},
+					RcProxyMessage::Announcements {
+						delegate: who.clone(),
+						deposit: 100,
+						announcements: vec![PortableAnnouncement {
+							real: who.clone(),
+							call_hash: Default::default(),
+							height: 1,
+						}],
+					},
+				]),
+				AhMigratorCalls::ReceiveVestingSchedules(vec![RcVestingSchedule {
+					who: who.clone(),
+					schedules: vec![PortableVestingInfo {
+						locked: 100,
+						per_block: 1,
+						starting_block: 0,
+					}],
+				}]),
+				AhMigratorCalls::ReceiveClaimsMessages(vec![
+					RcClaimsMessage::Claims(address, 100),
+					RcClaimsMessage::Preclaims(who.clone(), address),
+				]),
+				AhMigratorCalls::ReceivePreimageMessages(vec![
+					RcPreimageMessage::RequestStatus {
+						hash: H256::repeat_byte(1),
+						status: PortableRequestStatus::Unrequested {
+							ticket: (who.clone(), 100),
+							len: 3,
+						},
+					},
+					RcPreimageMessage::LegacyStatus {
+						hash: H256::repeat_byte(2),
+						status: PortableRequestStatus::Requested {
+							maybe_ticket: Some((who.clone(), 100)),
+							count: 1,
+							maybe_len: Some(3),
+						},
+					},
+					RcPreimageMessage::Chunk {
+						hash: H256::repeat_byte(1),
+						len: 3,
+						offset: 0,
+						data: vec![1, 2, 3],
+					},
+				]),
+			]
+		};
+
+		for (index, (call, expected)) in
+			calls(&sovereign).into_iter().zip(calls(&sibling)).enumerate()
+		{
+			assert_eq!(
+				call.translate_accounts().encode(),
+				expected.encode(),
+				"the accounts of call {index} are not translated"
+			);
+		}
+	}
+
+	#[test]
+	fn governance_origins_are_portable() {
+		assert_eq!(
+			RcToPortableGovernanceOrigin::convert(frame_system::RawOrigin::Root.into()),
+			Some(PortableGovernanceOrigin::Root)
+		);
+		assert_eq!(
+			RcToPortableGovernanceOrigin::convert(
+				pallet_custom_origins::Origin::WhitelistedCaller.into()
+			),
+			Some(PortableGovernanceOrigin::WhitelistedCaller)
+		);
+		assert_eq!(
+			RcToPortableGovernanceOrigin::convert(
+				frame_system::RawOrigin::Signed(AccountId::from([1u8; 32])).into()
+			),
+			None
+		);
+	}
 }
 
 #[cfg(all(test, feature = "try-runtime"))]
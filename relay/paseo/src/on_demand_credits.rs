@@ -0,0 +1,299 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! On-demand coretime credits bought on the Coretime chain.
+//!
+//! `pallet_broker::purchase_credit` takes the payment on the Coretime chain and asks the Relay
+//! Chain to credit the beneficiary. The `coretime` pallet of our SDK version does not implement
+//! `credit_account` yet, so the Coretime chain sends the credits to the `OnDemandCredits` pallet:
+//!
+//! - the broker credits an account with [`Pallet::credit_account`]; the credited amount is minted
+//!   into the pot of the pallet, as the payment was already taken on the Coretime chain,
+//! - the pot holds the existential deposit on top of the credits, so that it stays alive when it
+//!   places the orders. It is funded at genesis or by the [`FundPot`] migration,
+//! - the account spends its credits with [`Pallet::place_order_with_credits`], which places the
+//!   order with `parachains_on_demand` on behalf of the pot and deducts the spot price from the
+//!   credits.
+
+pub use pallet_on_demand_credits::*;
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::{EnsureOrigin, Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use runtime_parachains::origin as parachains_origin;
+
+/// The weights of the calls of [`Pallet`].
+pub trait WeightInfo {
+	fn credit_account() -> Weight;
+	fn place_order_with_credits() -> Weight;
+}
+
+/// Ensures that the origin is the parachain `ParaId`.
+///
+/// This is how `ChildParachainAsNative` expresses a `Transact` with the `Native` origin kind, which
+/// is what the Coretime chain sends.
+pub struct EnsureParachain<ParaId>(PhantomData<ParaId>);
+impl<O, ParaId: Get<u32>> EnsureOrigin<O> for EnsureParachain<ParaId>
+where
+	O: Into<Result<parachains_origin::Origin, O>> + From<parachains_origin::Origin>,
+{
+	type Success = ();
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|origin| match origin {
+			parachains_origin::Origin::Parachain(id) if id == ParaId::get().into() => Ok(()),
+			origin => Err(O::from(origin)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(parachains_origin::Origin::Parachain(ParaId::get().into())))
+	}
+}
+
+/// Tops the pot of the credits up to the existential deposit.
+///
+/// Needed once on chains where the pallet was added after genesis, does nothing afterwards.
+pub struct FundPot<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for FundPot<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if let Err(e) = Pallet::<T>::fund_pot() {
+			log::error!(target: LOG_TARGET, "Failed to fund the pot of the credits: {:?}", e);
+		}
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::traits::fungible::Inspect;
+		frame_support::ensure!(
+			<T as Config>::Currency::balance(&Pallet::<T>::account_id()) >=
+				<T as Config>::Currency::minimum_balance(),
+			"The pot of the credits is below the existential deposit"
+		);
+		Ok(())
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet_on_demand_credits {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::fungible::{Inspect, Mutate},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use polkadot_primitives::{AccountId, Balance, Id as ParaId};
+	use runtime_parachains::{
+		configuration,
+		on_demand::{self, WeightInfo as _},
+	};
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		ArithmeticError,
+	};
+
+	use super::WeightInfo;
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::on-demand-credits";
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<AccountId = AccountId> + on_demand::Config + configuration::Config
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin of the broker on the Coretime chain.
+		type BrokerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The currency of the credits. Must be the currency of `parachains_on_demand`.
+		type Currency: Mutate<AccountId, Balance = Balance>;
+		/// The id of the pot holding the funds of the credits.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The credits of the accounts.
+	#[pallet::storage]
+	pub type Credits<T: Config> = StorageMap<_, Twox64Concat, AccountId, Balance, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::fund_pot().expect("the pot of the credits can be funded at genesis");
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account was credited by the broker.
+		AccountCredited { who: AccountId, amount: Balance },
+		/// An on-demand order was paid with credits.
+		OrderPlacedWithCredits { who: AccountId, para_id: ParaId, spent: Balance },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has no credits.
+		NoCredits,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Credit `who` with `amount` bought on the Coretime chain.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::credit_account())]
+		pub fn credit_account(
+			origin: OriginFor<T>,
+			who: AccountId,
+			amount: Balance,
+		) -> DispatchResult {
+			T::BrokerOrigin::ensure_origin(origin)?;
+
+			<T as Config>::Currency::mint_into(&Self::account_id(), amount)?;
+			Credits::<T>::try_mutate(&who, |credits| {
+				*credits = credits.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::AccountCredited { who, amount });
+			Ok(())
+		}
+
+		/// Place an on-demand order for `para_id`, paid with the credits of the sender.
+		///
+		/// At most `max_amount` of the credits is spent. The spot price is deducted from the
+		/// credits and the rest is left untouched.
+		///
+		/// The benchmark places the order into an empty queue, the cost of an order into a full
+		/// queue is added on top.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as on_demand::Config>::WeightInfo::place_order_keep_alive(
+				configuration::ActiveConfig::<T>::get().scheduler_params.on_demand_queue_max_size
+			)
+			.saturating_add(<T as Config>::WeightInfo::place_order_with_credits())
+		)]
+		pub fn place_order_with_credits(
+			origin: OriginFor<T>,
+			max_amount: Balance,
+			para_id: ParaId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let credits = Credits::<T>::get(&who);
+			ensure!(!credits.is_zero(), Error::<T>::NoCredits);
+
+			let pot = Self::account_id();
+			let before = <T as Config>::Currency::balance(&pot);
+			on_demand::Pallet::<T>::place_order_keep_alive(
+				frame_system::RawOrigin::Signed(pot.clone()).into(),
+				max_amount.min(credits),
+				para_id,
+			)?;
+			let spent = before.saturating_sub(<T as Config>::Currency::balance(&pot));
+			log::debug!(target: LOG_TARGET, "{:?} spent {} credits on {:?}", who, spent, para_id);
+
+			let remaining = credits.saturating_sub(spent);
+			if remaining.is_zero() {
+				Credits::<T>::remove(&who);
+			} else {
+				Credits::<T>::insert(&who, remaining);
+			}
+			Self::deposit_event(Event::OrderPlacedWithCredits { who, para_id, spent });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account of the pot holding the funds of the credits.
+		pub fn account_id() -> AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Mint the part of the existential deposit that the pot is missing.
+		pub(crate) fn fund_pot() -> DispatchResult {
+			let pot = Self::account_id();
+			let missing = <T as Config>::Currency::minimum_balance()
+				.saturating_sub(<T as Config>::Currency::balance(&pot));
+			if !missing.is_zero() {
+				<T as Config>::Currency::mint_into(&pot, missing)?;
+			}
+			Ok(())
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use frame_benchmarking::v2::*;
+	use frame_support::traits::fungible::{Inspect, Mutate};
+	use frame_system::RawOrigin;
+	use polkadot_primitives::{AccountId, Balance, Id as ParaId};
+
+	#[benchmarks]
+	mod benchmarks {
+		use super::*;
+
+		#[benchmark]
+		fn credit_account() -> Result<(), BenchmarkError> {
+			Pallet::<T>::fund_pot()?;
+			let who: AccountId = account("who", 0, 0);
+			let amount = <T as Config>::Currency::minimum_balance();
+			let origin =
+				T::BrokerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+			#[extrinsic_call]
+			_(origin as T::RuntimeOrigin, who.clone(), amount);
+
+			assert_eq!(Credits::<T>::get(&who), amount);
+			Ok(())
+		}
+
+		/// An order that leaves some credits, so that they are written back.
+		#[benchmark]
+		fn place_order_with_credits() -> Result<(), BenchmarkError> {
+			Pallet::<T>::fund_pot()?;
+			let who: AccountId = whitelisted_caller();
+			let amount: Balance =
+				<T as Config>::Currency::minimum_balance().saturating_mul(1_000_000);
+			<T as Config>::Currency::mint_into(&Pallet::<T>::account_id(), amount)?;
+			Credits::<T>::insert(&who, amount);
+
+			#[extrinsic_call]
+			_(RawOrigin::Signed(who.clone()), amount / 2, ParaId::from(1000));
+
+			assert!(Credits::<T>::get(&who) > 0);
+			Ok(())
+		}
+	}
+}
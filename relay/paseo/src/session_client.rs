@@ -0,0 +1,518 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo. If not, see <http://www.gnu.org/licenses/>.
+
+//! Relay Chain side of staking on Asset Hub.
+//!
+//! The `SessionClient` pallet sits between `pallet_session` and the staking system. In the
+//! [`SessionClientMode::Local`] mode it forwards everything to `pallet_staking` on the Relay Chain.
+//! In the [`SessionClientMode::AssetHub`] mode the validators are elected on Asset Hub instead:
+//!
+//! - the end of every session and the reward points of the validators are reported to the
+//!   `RcClient` pallet on Asset Hub,
+//! - the offences are reported to Asset Hub, which slashes the offenders. Reports that cannot be
+//!   sent are kept and sent again later,
+//! - Asset Hub sends the new validator sets back, which are handed to `pallet_session` at the next
+//!   session rotation.
+
+pub use pallet_session_client::*;
+
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use paseo_runtime_constants::staking::{OffenceReport, SessionClientMode, SessionReport};
+use polkadot_primitives::AccountId;
+use sp_runtime::traits::Convert;
+
+/// The weights of the calls of [`Pallet`].
+pub trait WeightInfo {
+	fn set_mode() -> frame_support::weights::Weight;
+	fn receive_validator_set(v: u32) -> frame_support::weights::Weight;
+}
+
+/// The calls of the `RcClient` pallet on Asset Hub.
+///
+/// The codec indices must correspond to the call indices of `pallet_rc_client`.
+#[derive(Encode, Decode)]
+pub(crate) enum RcClientCalls {
+	#[codec(index = 0)]
+	ReceiveSessionReport(SessionReport),
+	#[codec(index = 1)]
+	ReceiveOffenceReport(OffenceReport),
+}
+
+/// The pallets of the Asset Hub runtime that are called by the session client.
+///
+/// The codec index must correspond to the index of `RcClient` in the `construct_runtime` of
+/// Asset Hub.
+#[derive(Encode, Decode)]
+pub(crate) enum AssetHubRuntimePallets {
+	#[codec(index = 96)]
+	RcClient(RcClientCalls),
+}
+
+/// Converts an account into its validator id, see `pallet_session::Config::ValidatorIdOf`.
+///
+/// In the [`SessionClientMode::Local`] mode only the stashes of the local `pallet_staking` are
+/// validators. In the [`SessionClientMode::AssetHub`] mode the validators are bonded on Asset Hub,
+/// so every account is its own validator id and can set its session keys.
+pub struct ValidatorIdOf<T>(PhantomData<T>);
+impl<T: Config> Convert<AccountId, Option<AccountId>> for ValidatorIdOf<T> {
+	fn convert(who: AccountId) -> Option<AccountId> {
+		match Mode::<T>::get() {
+			SessionClientMode::Local => pallet_staking::StashOf::<T>::convert(who),
+			SessionClientMode::AssetHub => Some(who),
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet_session_client {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::ValidatorSet};
+	use frame_system::pallet_prelude::*;
+	use pallet_session::historical::IdentificationTuple;
+	use pallet_staking::Exposure;
+	use paseo_runtime_constants::staking::{SessionClientMode, ValidatorSetReport};
+	use polkadot_primitives::{AccountId, Balance, SessionIndex, ValidatorIndex};
+	use runtime_parachains::{disputes, inclusion, session_info, shared};
+	use sp_runtime::{Perbill, Saturating};
+	use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+	use sp_std::{vec, vec::Vec};
+	use xcm::prelude::*;
+
+	use super::WeightInfo;
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::session-client";
+
+	/// The reward points for authoring a block, the same as in `pallet_staking`.
+	pub const AUTHORING_POINTS: u32 = 20;
+	/// The reward points for backing a parachain candidate, the same as in
+	/// `runtime_parachains::reward_points`.
+	pub const BACKING_POINTS: u32 = 20;
+	/// The reward points for a statement in a dispute, the same as in
+	/// `runtime_parachains::reward_points`.
+	pub const DISPUTE_STATEMENT_POINTS: u32 = 20;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<AccountId = AccountId>
+		+ pallet_staking::Config<CurrencyBalance = Balance>
+		+ pallet_session::Config<ValidatorId = AccountId>
+		+ pallet_session::historical::Config<FullIdentification = Exposure<AccountId, Balance>>
+		+ session_info::Config
+		+ shared::Config
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Origin that is allowed to switch the mode.
+		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The origin of Asset Hub.
+		type AssetHubOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The router used to send the reports to Asset Hub.
+		type SendXcm: SendXcm;
+		/// The location of Asset Hub.
+		type AssetHubLocation: Get<Location>;
+		/// The session manager of the [`SessionClientMode::Local`] mode.
+		type LocalSessionManager: pallet_session::historical::SessionManager<
+			AccountId,
+			Exposure<AccountId, Balance>,
+		>;
+		/// The offence handler of the [`SessionClientMode::Local`] mode.
+		type LocalOffenceHandler: OnOffenceHandler<AccountId, IdentificationTuple<Self>, Weight>;
+		/// The maximum number of validators in a set received from Asset Hub.
+		#[pallet::constant]
+		type MaxValidators: Get<u32>;
+		/// The weight required on Asset Hub to process a report.
+		#[pallet::constant]
+		type AhReportWeight: Get<Weight>;
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Where the validator set comes from.
+	#[pallet::storage]
+	pub type Mode<T: Config> = StorageValue<_, SessionClientMode, ValueQuery>;
+
+	/// The last validator set received from Asset Hub, with the session it was planned for.
+	///
+	/// It is taken at the next session rotation.
+	#[pallet::storage]
+	pub type QueuedValidatorSet<T: Config> =
+		StorageValue<_, (SessionIndex, BoundedVec<AccountId, T::MaxValidators>), OptionQuery>;
+
+	/// The reward points of the validators that were not reported to Asset Hub yet.
+	#[pallet::storage]
+	pub type ValidatorPoints<T: Config> = StorageMap<_, Twox64Concat, AccountId, u32, ValueQuery>;
+
+	/// The offences that could not be reported to Asset Hub yet, by session and offender.
+	///
+	/// They are reported again in `on_initialize`, one session per block.
+	#[pallet::storage]
+	pub type PendingOffences<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SessionIndex, Twox64Concat, AccountId, Perbill>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The source of the validator set was switched.
+		ModeSet { mode: SessionClientMode },
+		/// A validator set was received from Asset Hub.
+		ValidatorSetReceived { planned_session: SessionIndex, validators: u32 },
+		/// A validator set of Asset Hub was handed to `pallet_session` for `session`.
+		ValidatorSetQueued { planned_session: SessionIndex, session: SessionIndex },
+		/// The end of a session was reported to Asset Hub.
+		SessionReportSent { end_index: SessionIndex, validators: u32 },
+		/// Offences were reported to Asset Hub.
+		OffenceReportSent { session: SessionIndex, offences: u32 },
+		/// The offences of `session` could not be reported to Asset Hub, they are reported again
+		/// later.
+		OffenceReportSendFailed { session: SessionIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The report could not be sent to Asset Hub.
+		XcmSendError,
+		/// The validator set is empty.
+		EmptyValidatorSet,
+		/// The validator set has more than `MaxValidators` validators.
+		TooManyValidators,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			match PendingOffences::<T>::iter_keys().next() {
+				Some((session, _)) => {
+					let offences = Self::send_pending_offences(session) as u64;
+					T::DbWeight::get().reads_writes(1 + offences, offences)
+				},
+				None => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set where the validator set comes from.
+		///
+		/// Switching back to [`SessionClientMode::Local`] hands the sessions back to the local
+		/// `pallet_staking`, which resumes from where it stopped.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_mode())]
+		pub fn set_mode(origin: OriginFor<T>, mode: SessionClientMode) -> DispatchResult {
+			<T as Config>::AdminOrigin::ensure_origin(origin)?;
+			Mode::<T>::put(mode);
+			Self::deposit_event(Event::ModeSet { mode });
+			Ok(())
+		}
+
+		/// Receive a new validator set from Asset Hub.
+		///
+		/// A set that was not taken yet is replaced.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_validator_set(
+			report.validators.len() as u32
+		))]
+		pub fn receive_validator_set(
+			origin: OriginFor<T>,
+			report: ValidatorSetReport,
+		) -> DispatchResult {
+			T::AssetHubOrigin::ensure_origin(origin)?;
+			let ValidatorSetReport { planned_session, validators } = report;
+			ensure!(!validators.is_empty(), Error::<T>::EmptyValidatorSet);
+			let validators: BoundedVec<_, T::MaxValidators> =
+				validators.try_into().map_err(|_| Error::<T>::TooManyValidators)?;
+
+			Self::deposit_event(Event::ValidatorSetReceived {
+				planned_session,
+				validators: validators.len() as u32,
+			});
+			QueuedValidatorSet::<T>::put((planned_session, validators));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Reward the validators with `points`.
+		///
+		/// The points go to the local `pallet_staking` or are kept until the end of the session is
+		/// reported to Asset Hub.
+		pub fn reward_by_ids(points: impl IntoIterator<Item = (AccountId, u32)>) {
+			match Mode::<T>::get() {
+				SessionClientMode::Local => pallet_staking::Pallet::<T>::reward_by_ids(points),
+				SessionClientMode::AssetHub =>
+					for (who, points) in points {
+						ValidatorPoints::<T>::mutate(who, |total| total.saturating_accrue(points));
+					},
+			}
+		}
+
+		/// Reward the validators of `session` with the given `indices` with `points`.
+		fn reward_only_active(
+			session: SessionIndex,
+			indices: impl IntoIterator<Item = ValidatorIndex>,
+			points: u32,
+		) where
+			<T as session_info::Config>::ValidatorSet:
+				ValidatorSet<AccountId, ValidatorId = AccountId>,
+		{
+			let Some(validators) = session_info::AccountKeys::<T>::get(session) else {
+				log::warn!(target: LOG_TARGET, "No account keys for session {}", session);
+				return
+			};
+			Self::reward_by_ids(
+				indices
+					.into_iter()
+					.filter_map(|index| validators.get(index.0 as usize).cloned())
+					.map(|who| (who, points)),
+			);
+		}
+
+		/// Report the end of `end_index` and the collected reward points to Asset Hub.
+		///
+		/// The points of at most `MaxValidators` validators are reported, the others and all points
+		/// that could not be sent are kept for the next report.
+		fn report_session_end(end_index: SessionIndex) {
+			let validator_points: Vec<_> =
+				ValidatorPoints::<T>::iter().take(T::MaxValidators::get() as usize).collect();
+			let rewarded: Vec<_> = validator_points.iter().map(|(who, _)| who.clone()).collect();
+			let report = SessionReport { end_index, validator_points };
+			if Self::send_to_asset_hub(RcClientCalls::ReceiveSessionReport(report)).is_ok() {
+				for who in &rewarded {
+					ValidatorPoints::<T>::remove(who);
+				}
+				let validators = rewarded.len() as u32;
+				Self::deposit_event(Event::SessionReportSent { end_index, validators });
+			}
+		}
+
+		/// Report the pending offences of `session` to Asset Hub, at most `MaxValidators` of them.
+		///
+		/// Returns the number of offences that were read. They stay pending if they cannot be
+		/// sent.
+		pub(crate) fn send_pending_offences(session: SessionIndex) -> u32 {
+			let offences: Vec<_> = PendingOffences::<T>::iter_prefix(session)
+				.take(T::MaxValidators::get() as usize)
+				.collect();
+			let count = offences.len() as u32;
+			let offenders: Vec<_> = offences.iter().map(|(who, _)| who.clone()).collect();
+			let report = OffenceReport { session, offences };
+			if Self::send_to_asset_hub(RcClientCalls::ReceiveOffenceReport(report)).is_err() {
+				Self::deposit_event(Event::OffenceReportSendFailed { session });
+				return count
+			}
+			for who in &offenders {
+				PendingOffences::<T>::remove(session, who);
+			}
+			Self::deposit_event(Event::OffenceReportSent { session, offences: count });
+			count
+		}
+
+		/// Disable the offenders of the current session, up to a third of the validators.
+		///
+		/// The Relay Chain disables the offenders right away, while the slashes are applied on
+		/// Asset Hub.
+		fn disable_offenders(offences: &[(AccountId, Perbill)]) {
+			let validators = pallet_session::Validators::<T>::decode_len().unwrap_or_default();
+			let limit = validators.saturating_sub(1) / 3;
+			for (who, _) in offences.iter().filter(|(_, fraction)| !fraction.is_zero()) {
+				let disabled = pallet_session::DisabledValidators::<T>::decode_len();
+				if disabled.unwrap_or_default() >= limit {
+					log::warn!(target: LOG_TARGET, "Not disabling {:?}, limit reached", who);
+					break
+				}
+				pallet_session::Pallet::<T>::disable(who);
+			}
+		}
+
+		/// Send a call of the `RcClient` pallet to Asset Hub.
+		pub(crate) fn send_to_asset_hub(call: RcClientCalls) -> Result<(), Error<T>> {
+			let message = Xcm(vec![
+				Instruction::UnpaidExecution {
+					weight_limit: WeightLimit::Unlimited,
+					check_origin: None,
+				},
+				Instruction::Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most: T::AhReportWeight::get(),
+					call: AssetHubRuntimePallets::RcClient(call).encode().into(),
+				},
+			]);
+
+			send_xcm::<T::SendXcm>(T::AssetHubLocation::get(), message).map_err(|e| {
+				log::error!(target: LOG_TARGET, "Failed to send report to Asset Hub: {:?}", e);
+				Error::<T>::XcmSendError
+			})?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config>
+		pallet_session::historical::SessionManager<AccountId, Exposure<AccountId, Balance>>
+		for Pallet<T>
+	{
+		fn new_session(
+			new_index: SessionIndex,
+		) -> Option<Vec<(AccountId, Exposure<AccountId, Balance>)>> {
+			match Mode::<T>::get() {
+				SessionClientMode::Local => T::LocalSessionManager::new_session(new_index),
+				SessionClientMode::AssetHub => {
+					let (planned_session, validators) = QueuedValidatorSet::<T>::take()?;
+					Self::deposit_event(Event::ValidatorSetQueued {
+						planned_session,
+						session: new_index,
+					});
+					// The exposures are only known to Asset Hub, which applies the slashes.
+					Some(validators.into_iter().map(|who| (who, Exposure::default())).collect())
+				},
+			}
+		}
+
+		fn new_session_genesis(
+			new_index: SessionIndex,
+		) -> Option<Vec<(AccountId, Exposure<AccountId, Balance>)>> {
+			match Mode::<T>::get() {
+				SessionClientMode::Local => T::LocalSessionManager::new_session_genesis(new_index),
+				SessionClientMode::AssetHub => Self::new_session(new_index),
+			}
+		}
+
+		fn start_session(start_index: SessionIndex) {
+			if Mode::<T>::get() == SessionClientMode::Local {
+				T::LocalSessionManager::start_session(start_index)
+			}
+		}
+
+		fn end_session(end_index: SessionIndex) {
+			match Mode::<T>::get() {
+				SessionClientMode::Local => T::LocalSessionManager::end_session(end_index),
+				SessionClientMode::AssetHub => Self::report_session_end(end_index),
+			}
+		}
+	}
+
+	impl<T: Config> pallet_authorship::EventHandler<AccountId, BlockNumberFor<T>> for Pallet<T> {
+		fn note_author(author: AccountId) {
+			Self::reward_by_ids(vec![(author, AUTHORING_POINTS)])
+		}
+	}
+
+	impl<T: Config> inclusion::RewardValidators for Pallet<T>
+	where
+		<T as session_info::Config>::ValidatorSet: ValidatorSet<AccountId, ValidatorId = AccountId>,
+	{
+		fn reward_backing(indices: impl IntoIterator<Item = ValidatorIndex>) {
+			let session = shared::CurrentSessionIndex::<T>::get();
+			Self::reward_only_active(session, indices, BACKING_POINTS);
+		}
+
+		fn reward_bitfields(_validators: impl IntoIterator<Item = ValidatorIndex>) {}
+	}
+
+	impl<T: Config> disputes::RewardValidators for Pallet<T>
+	where
+		<T as session_info::Config>::ValidatorSet: ValidatorSet<AccountId, ValidatorId = AccountId>,
+	{
+		fn reward_dispute_statement(
+			session: SessionIndex,
+			validators: impl IntoIterator<Item = ValidatorIndex>,
+		) {
+			Self::reward_only_active(session, validators, DISPUTE_STATEMENT_POINTS);
+		}
+	}
+
+	impl<T: Config> OnOffenceHandler<AccountId, IdentificationTuple<T>, Weight> for Pallet<T> {
+		fn on_offence(
+			offenders: &[OffenceDetails<AccountId, IdentificationTuple<T>>],
+			slash_fraction: &[Perbill],
+			session: SessionIndex,
+		) -> Weight {
+			if Mode::<T>::get() == SessionClientMode::Local {
+				return T::LocalOffenceHandler::on_offence(offenders, slash_fraction, session)
+			}
+
+			let offences: Vec<_> = offenders
+				.iter()
+				.zip(slash_fraction)
+				.map(|(details, fraction)| (details.offender.0.clone(), *fraction))
+				.collect();
+			let count = offences.len() as u32;
+			if session == pallet_session::CurrentIndex::<T>::get() {
+				Self::disable_offenders(&offences);
+			}
+
+			let report = OffenceReport { session, offences: offences.clone() };
+			if Self::send_to_asset_hub(RcClientCalls::ReceiveOffenceReport(report)).is_ok() {
+				Self::deposit_event(Event::OffenceReportSent { session, offences: count });
+			} else {
+				// Keep the offences to report them again, with the highest slash of each offender.
+				for (who, fraction) in offences {
+					PendingOffences::<T>::mutate(session, who, |pending| {
+						*pending = Some(pending.map_or(fraction, |pending| pending.max(fraction)))
+					});
+				}
+				Self::deposit_event(Event::OffenceReportSendFailed { session });
+			}
+			T::DbWeight::get().reads_writes(4 + count as u64, 1 + 2 * count as u64)
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use frame_benchmarking::v2::*;
+	use frame_support::{traits::EnsureOrigin, BoundedVec};
+	use paseo_runtime_constants::staking::ValidatorSetReport;
+	use sp_runtime::traits::Get;
+	use sp_std::vec::Vec;
+
+	#[benchmarks(where <T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>)]
+	mod benchmarks {
+		use super::*;
+
+		#[benchmark]
+		fn set_mode() -> Result<(), BenchmarkError> {
+			let origin = <T as Config>::AdminOrigin::try_successful_origin()
+				.map_err(|_| BenchmarkError::Weightless)?;
+
+			#[extrinsic_call]
+			_(origin as T::RuntimeOrigin, SessionClientMode::AssetHub);
+
+			assert_eq!(Mode::<T>::get(), SessionClientMode::AssetHub);
+			Ok(())
+		}
+
+		/// A validator set that replaces one that was not taken yet.
+		#[benchmark]
+		fn receive_validator_set(v: Linear<1, { T::MaxValidators::get() }>) {
+			let validators: Vec<AccountId> = (0..v).map(|i| account("validator", i, 0)).collect();
+			let queued = BoundedVec::truncate_from(validators.clone());
+			QueuedValidatorSet::<T>::put((1, queued));
+			let report = ValidatorSetReport { planned_session: 2, validators };
+			let origin = pallet_xcm::Origin::Xcm(T::AssetHubLocation::get());
+
+			#[extrinsic_call]
+			_(origin, report);
+
+			assert_eq!(QueuedValidatorSet::<T>::get().map(|(planned, _)| planned), Some(2));
+		}
+	}
+}
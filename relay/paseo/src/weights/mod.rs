@@ -24,18 +24,23 @@ pub mod pallet_beefy_mmr;
 pub mod pallet_bounties;
 pub mod pallet_child_bounties;
 pub mod pallet_conviction_voting;
+pub mod pallet_coretime_assignments;
 pub mod pallet_election_provider_multi_phase;
 pub mod pallet_fast_unstake;
 pub mod pallet_indices;
 pub mod pallet_message_queue;
+pub mod pallet_migrations;
 pub mod pallet_multisig;
 pub mod pallet_nomination_pools;
+pub mod pallet_on_demand_credits;
 pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_rc_migrator;
 pub mod pallet_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_session;
+pub mod pallet_session_client;
 pub mod pallet_staking;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
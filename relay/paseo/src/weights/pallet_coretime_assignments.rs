@@ -0,0 +1,50 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_coretime_assignments`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: the execution time covers
+//! `assign_core` of `runtime_parachains::coretime` with 100 assignments several times over and the
+//! proof size covers the pending assignment next to the core schedule. The Coretime chain sends
+//! `assign_core_chunk` with a fixed weight, keep it above these. Replace this file with the output
+//! of:
+//!
+//! target/production/polkadot benchmark pallet --chain=./paseo-chain-spec.json \
+//!   --pallet=pallet_coretime_assignments --extrinsic=* --steps=50 --repeat=20 \
+//!   --wasm-execution=compiled --heap-pages=4096 --header=./file_header.txt \
+//!   --output=./relay/paseo/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_coretime_assignments`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::coretime_assignments::WeightInfo for WeightInfo<T> {
+	/// Storage: `CoretimeAssignments::PendingAssignments` (r:1 w:1)
+	/// Storage: `CoretimeAssignmentProvider::CoreDescriptors` (r:1 w:1)
+	/// Storage: `CoretimeAssignmentProvider::CoreSchedules` (r:0 w:1)
+	fn assign_core_chunk() -> Weight {
+		Weight::from_parts(250_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,27 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_migrations`
+//!
+//! The pallet has not been benchmarked against this runtime yet, so these are the reference
+//! weights shipped with the pallet. Replace this file with the output of:
+//!
+//! target/production/polkadot benchmark pallet --chain=./paseo-chain-spec.json --pallet=pallet_migrations \
+//!   --extrinsic=* --steps=50 --repeat=20 --wasm-execution=compiled --heap-pages=4096 \
+//!   --header=./file_header.txt --output=./relay/paseo/src/weights/
+
+/// Weight functions for `pallet_migrations`.
+pub type WeightInfo<T> = pallet_migrations::weights::SubstrateWeight<T>;
@@ -0,0 +1,62 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_on_demand_credits`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: the execution time covers a mint
+//! and an on-demand order into an empty queue several times over and the proof size covers the
+//! accounts and storage items that are touched. The Coretime chain sends `credit_account` with a
+//! fixed weight, keep it above these. Replace this file with the output of:
+//!
+//! target/production/polkadot benchmark pallet --chain=./paseo-chain-spec.json \
+//!   --pallet=pallet_on_demand_credits --extrinsic=* --steps=50 --repeat=20 \
+//!   --wasm-execution=compiled --heap-pages=4096 --header=./file_header.txt \
+//!   --output=./relay/paseo/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_on_demand_credits`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::on_demand_credits::WeightInfo for WeightInfo<T> {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `OnDemandCredits::Credits` (r:1 w:1)
+	fn credit_account() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `OnDemandCredits::Credits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OnDemandAssignmentProvider::QueueStatus` (r:1 w:1)
+	/// Storage: `OnDemandAssignmentProvider::Revenue` (r:1 w:1)
+	/// Storage: `OnDemandAssignmentProvider::ParaIdAffinity` (r:1 w:0)
+	/// Storage: `OnDemandAssignmentProvider::FreeEntries` (r:1 w:1)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	fn place_order_with_credits() -> Weight {
+		Weight::from_parts(200_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10_000))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+}
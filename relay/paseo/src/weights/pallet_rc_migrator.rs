@@ -0,0 +1,73 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_rc_migrator`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: the execution time covers the
+//! storage accesses and the message that is sent to Asset Hub several times over and the proof
+//! size covers the storage items that are touched. Asset Hub sends `receive_processed` with a
+//! fixed weight, keep it above these. Replace this file with the output of:
+//!
+//! target/production/polkadot benchmark pallet --chain=./paseo-chain-spec.json \
+//!   --pallet=pallet_rc_migrator --extrinsic=* --steps=50 --repeat=20 \
+//!   --wasm-execution=compiled --heap-pages=4096 --header=./file_header.txt \
+//!   --output=./relay/paseo/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_rc_migrator`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::ah_migration::WeightInfo for WeightInfo<T> {
+	/// Storage: `RcMigrator::RcMigrationStage` (r:1 w:1)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Storage: `Dmp::DeliveryFeeFactor` (r:1 w:0)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	fn force_set_stage() -> Weight {
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10_000))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `RcMigrator::RcMigrationStage` (r:1 w:0)
+	/// Storage: `RcMigrator::TeleportsTrackedOnAssetHub` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Storage: `Dmp::DeliveryFeeFactor` (r:1 w:0)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	fn switch_teleport_tracking() -> Weight {
+		Weight::from_parts(150_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 15_000))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `RcMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `RcMigrator::LastError` (r:0 w:1)
+	fn receive_processed() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4_000))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
@@ -0,0 +1,53 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_session_client`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: the execution time covers the
+//! decoding of a validator set several times over and the proof size covers a full validator set.
+//! Asset Hub sends `receive_validator_set` with a fixed weight, keep it above these. Replace this
+//! file with the output of:
+//!
+//! target/production/polkadot benchmark pallet --chain=./paseo-chain-spec.json \
+//!   --pallet=pallet_session_client --extrinsic=* --steps=50 --repeat=20 \
+//!   --wasm-execution=compiled --heap-pages=4096 --header=./file_header.txt \
+//!   --output=./relay/paseo/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_session_client`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::session_client::WeightInfo for WeightInfo<T> {
+	/// Storage: `SessionClient::Mode` (r:0 w:1)
+	fn set_mode() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `SessionClient::QueuedValidatorSet` (r:0 w:1)
+	/// The range of component `v` is `[1, 1200]`.
+	fn receive_validator_set(v: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(200_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -58,8 +58,9 @@ parameter_types! {
 	pub UniversalLocation: InteriorLocation = [GlobalConsensus(ThisNetwork::get())].into();
 	/// The Checking Account, which holds any native assets that have been teleported out and not back in (yet).
 	pub CheckAccount: AccountId = XcmPallet::check_account();
-	/// The Checking Account along with the indication that the local chain is able to mint tokens.
-	pub LocalCheckAccount: (AccountId, MintLocation) = (CheckAccount::get(), MintLocation::Local);
+	/// The Checking Account along with the indication whether the local chain is able to mint
+	/// tokens. Asset Hub becomes the issuer once the migration switches the teleport tracking.
+	pub TeleportTracking: Option<(AccountId, MintLocation)> = crate::RcMigrator::teleport_tracking();
 	/// Account of the treasury pallet.
 	pub TreasuryAccount: AccountId = Treasury::account_id();
 }
@@ -89,7 +90,7 @@ pub type LocalAssetTransactor = FungibleAdapter<
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
 	AccountId,
 	// We track our teleports in/out to keep total issuance correct.
-	LocalCheckAccount,
+	TeleportTracking,
 >;
 
 /// The means that we convert an XCM origin `Location` into the runtime's `Origin` type for
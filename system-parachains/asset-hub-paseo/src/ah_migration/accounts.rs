@@ -0,0 +1,136 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the accounts migrated from the Relay Chain.
+//!
+//! The withdrawn balance of every account is minted and its holds, reserves, freezes and locks are
+//! re-created on top of it.
+
+use super::*;
+use frame_support::{
+	ensure,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		fungible::{Mutate, MutateFreeze, MutateHold},
+		LockableCurrency, ReservableCurrency, WithdrawReasons,
+	},
+};
+use paseo_runtime_constants::ahm::{MigratedPallet, PortableLockReasons, RcAccount};
+use sp_runtime::{traits::Convert, DispatchError, Saturating};
+use sp_std::vec::Vec;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of accounts, each one in its own storage transaction.
+	pub(crate) fn do_receive_accounts(accounts: Vec<RcAccount>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for account in accounts {
+			let (who, free) = (account.who.clone(), account.free);
+			let result = with_transaction(|| match Self::integrate_account(account) {
+				Ok(()) => TransactionOutcome::Commit(Ok(())),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			});
+			match result {
+				Ok(()) => count_good += 1,
+				Err(error) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to integrate account {:?}: {:?}",
+						who,
+						error
+					);
+					UnintegratedBalance::<T>::mutate(&who, |balance| {
+						balance.saturating_accrue(free)
+					});
+					Self::deposit_event(Event::AccountIntegrationFailed { who, error });
+					LastError::<T>::put(error);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Accounts, count_good, count_bad);
+		Self::deposit_event(Event::AccountBatchProcessed { count_good, count_bad });
+	}
+
+	/// Mint the balance of a Relay Chain account and re-create its holds, reserves, freezes and
+	/// locks.
+	///
+	/// Fails if the re-created holds and reserves or the locks and freezes do not match the
+	/// reserved and frozen amounts of the Relay Chain.
+	pub(crate) fn integrate_account(account: RcAccount) -> Result<(), DispatchError> {
+		let who = account.who;
+		let before = frame_system::Account::<T>::get(&who).data;
+
+		<Balances<T> as Mutate<_>>::mint_into(&who, account.free)?;
+		MovedBalance::<T>::mutate(|moved| moved.saturating_accrue(account.free));
+
+		for (reason, amount) in account.holds {
+			<Balances<T> as MutateHold<_>>::hold(
+				&T::PortableHoldReason::convert(reason),
+				&who,
+				amount,
+			)?;
+		}
+		if account.unnamed_reserve > 0 {
+			<Balances<T> as ReservableCurrency<_>>::reserve(&who, account.unnamed_reserve)?;
+			RcReserve::<T>::mutate(&who, |reserve| {
+				reserve.saturating_accrue(account.unnamed_reserve)
+			});
+		}
+		for (reason, amount) in account.freezes {
+			<Balances<T> as MutateFreeze<_>>::set_freeze(
+				&T::PortableFreezeReason::convert(reason),
+				&who,
+				amount,
+			)?;
+		}
+		for lock in account.locks {
+			let reasons = match lock.reasons {
+				PortableLockReasons::Fee => WithdrawReasons::TRANSACTION_PAYMENT,
+				PortableLockReasons::Misc =>
+					WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT),
+				PortableLockReasons::All => WithdrawReasons::all(),
+			};
+			<Balances<T> as LockableCurrency<_>>::set_lock(lock.id, &who, lock.amount, reasons);
+		}
+
+		let after = frame_system::Account::<T>::get(&who).data;
+		ensure!(
+			after.reserved.saturating_sub(before.reserved) == account.reserved,
+			Error::<T>::ReservedMismatch
+		);
+		ensure!(after.frozen == before.frozen.max(account.frozen), Error::<T>::FrozenMismatch);
+
+		Ok(())
+	}
+
+	/// Mint the balance of the account `who` that could not be integrated into `dest`.
+	pub(crate) fn do_restore_unintegrated_balance(
+		who: AccountId,
+		dest: AccountId,
+	) -> Result<(), DispatchError> {
+		let amount = UnintegratedBalance::<T>::get(&who);
+		ensure!(amount > 0, Error::<T>::NoUnintegratedBalance);
+
+		<Balances<T> as Mutate<_>>::mint_into(&dest, amount)?;
+		MovedBalance::<T>::mutate(|moved| moved.saturating_accrue(amount));
+		UnintegratedBalance::<T>::remove(&who);
+		Self::deposit_event(Event::UnintegratedBalanceRestored { who, dest, amount });
+		Ok(())
+	}
+}
@@ -0,0 +1,61 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Switch of the issuer of the native token from the Relay Chain to Asset Hub.
+//!
+//! The teleports of the native token are not tracked on Asset Hub before the switch. After the
+//! switch the checking account holds the balance on all other chains (`MintLocation::Local`).
+//!
+//! The total issuance of the Relay Chain at the time of the switch is the supply of all chains, as
+//! the checking account of the Relay Chain stands in for the balance on all other chains. The
+//! balance on all other chains is this supply minus the balance on Asset Hub.
+
+use super::*;
+use frame_support::{
+	ensure,
+	traits::fungible::{Inspect, Mutate},
+};
+use parachains_common::{AccountId, Balance};
+use sp_core::Get;
+use sp_runtime::DispatchError;
+use xcm_builder::MintLocation;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// The checking account of the native token, if the teleports are tracked.
+	pub fn teleport_tracking() -> Option<(AccountId, MintLocation)> {
+		TeleportsTracked::<T>::get().then(|| (T::CheckingAccount::get(), MintLocation::Local))
+	}
+
+	/// Credit the balance on all other chains to the checking account and start tracking the
+	/// teleports.
+	pub(crate) fn do_switch_teleport_tracking(
+		rc_total_issuance: Balance,
+	) -> Result<(), DispatchError> {
+		ensure!(!TeleportsTracked::<T>::get(), Error::<T>::AlreadyTracked);
+
+		let checking_account = T::CheckingAccount::get();
+		let local_balance = <Balances<T> as Inspect<_>>::total_issuance()
+			.saturating_sub(<Balances<T> as Inspect<_>>::total_balance(&checking_account));
+		let checking_balance = rc_total_issuance.saturating_sub(local_balance);
+
+		<Balances<T> as Mutate<_>>::set_balance(&checking_account, checking_balance);
+		TeleportsTracked::<T>::put(true);
+
+		Self::deposit_event(Event::TeleportTrackingSwitched { checking_balance });
+		Ok(())
+	}
+}
@@ -0,0 +1,118 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the pre-sale claims migrated from the Relay Chain.
+//!
+//! The claims can be claimed on Asset Hub with the same signatures as on the Relay Chain. Their
+//! vesting schedules start at Relay Chain block numbers, which `pallet_vesting` uses on Asset Hub.
+
+use super::*;
+use paseo_runtime_constants::ahm::{MigratedPallet, RcClaimsMessage};
+use sp_runtime::{DispatchError, Saturating};
+use sp_std::vec::Vec;
+
+/// Storage items of `polkadot_runtime_common::claims` that are not public.
+pub(crate) mod alias {
+	use frame_support::{
+		pallet_prelude::{OptionQuery, ValueQuery},
+		storage_alias, Identity,
+	};
+	use parachains_common::{AccountId, Balance, BlockNumber};
+	use polkadot_runtime_common::claims::{self, EthereumAddress, StatementKind};
+
+	#[storage_alias]
+	pub type Claims<T: claims::Config> =
+		StorageMap<claims::Pallet<T>, Identity, EthereumAddress, Balance, OptionQuery>;
+
+	#[storage_alias]
+	pub type Total<T: claims::Config> = StorageValue<claims::Pallet<T>, Balance, ValueQuery>;
+
+	#[storage_alias]
+	pub type Vesting<T: claims::Config> = StorageMap<
+		claims::Pallet<T>,
+		Identity,
+		EthereumAddress,
+		(Balance, Balance, BlockNumber),
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub type Signing<T: claims::Config> =
+		StorageMap<claims::Pallet<T>, Identity, EthereumAddress, StatementKind, OptionQuery>;
+
+	#[storage_alias]
+	pub type Preclaims<T: claims::Config> =
+		StorageMap<claims::Pallet<T>, Identity, AccountId, EthereumAddress, OptionQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of claims items.
+	pub(crate) fn do_receive_claims_messages(messages: Vec<RcClaimsMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_claims_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to integrate claims message: {:?}", e);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Claims, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Claims,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate a single item.
+	///
+	/// Existing claims are not overwritten.
+	pub(crate) fn integrate_claims_message(message: RcClaimsMessage) -> Result<(), DispatchError> {
+		match message {
+			RcClaimsMessage::Total(total) =>
+				alias::Total::<T>::mutate(|t| t.saturating_accrue(total)),
+			RcClaimsMessage::Claims(who, amount) => {
+				if alias::Claims::<T>::contains_key(&who) {
+					return Err("claim already exists".into())
+				}
+				alias::Claims::<T>::insert(who, amount);
+			},
+			RcClaimsMessage::Vesting(who, schedule) => {
+				if alias::Vesting::<T>::contains_key(&who) {
+					return Err("claim vesting already exists".into())
+				}
+				alias::Vesting::<T>::insert(who, schedule);
+			},
+			RcClaimsMessage::Signing(who, statement) => {
+				if alias::Signing::<T>::contains_key(&who) {
+					return Err("claim statement already exists".into())
+				}
+				alias::Signing::<T>::insert(who, statement);
+			},
+			RcClaimsMessage::Preclaims(who, address) => {
+				if alias::Preclaims::<T>::contains_key(&who) {
+					return Err("preclaim already exists".into())
+				}
+				alias::Preclaims::<T>::insert(who, address);
+			},
+		}
+		Ok(())
+	}
+}
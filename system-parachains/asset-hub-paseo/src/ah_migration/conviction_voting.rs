@@ -0,0 +1,96 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the votes and class locks migrated from the Relay Chain.
+//!
+//! The balance locks of the votes were already re-created together with the accounts, only the
+//! bookkeeping of `pallet_conviction_voting` is integrated here.
+
+use super::*;
+use codec::{Decode, Encode};
+use pallet_conviction_voting::{ClassOf, Voting, VotingOf};
+use parachains_common::{Balance, BlockNumber};
+use paseo_runtime_constants::ahm::{
+	transcode, MigratedPallet, PortableVoting, RcConvictionVotingMessage,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of votes and class locks.
+	pub(crate) fn do_receive_conviction_voting_messages(messages: Vec<RcConvictionVotingMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_conviction_voting_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to integrate conviction voting message: {:?}",
+						e
+					);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::ConvictionVoting, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::ConvictionVoting,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate the votes or class locks of a single account.
+	pub(crate) fn integrate_conviction_voting_message(
+		message: RcConvictionVotingMessage,
+	) -> Result<(), DispatchError> {
+		match message {
+			RcConvictionVotingMessage::VotingFor(who, class, voting) => {
+				let class = transcode::<_, ClassOf<T>>(&class).map_err(|_| "invalid class")?;
+				let voting = transcode::<_, VotingOf<T>>(&Self::translate_voting(voting))
+					.map_err(|_| "invalid votes")?;
+				pallet_conviction_voting::VotingFor::<T>::insert(who, class, voting);
+			},
+			RcConvictionVotingMessage::ClassLocksFor(who, locks) => {
+				let locks = transcode(&locks).map_err(|_| "too many class locks")?;
+				pallet_conviction_voting::ClassLocksFor::<T>::mutate(who, |l| *l = locks);
+			},
+		}
+		Ok(())
+	}
+
+	/// Translate the Relay Chain block numbers of the prior lock of `voting` to Asset Hub block
+	/// numbers.
+	pub(crate) fn translate_voting(mut voting: PortableVoting) -> PortableVoting {
+		match voting {
+			Voting::Casting(ref mut casting) => Self::translate_prior_lock(&mut casting.prior),
+			Voting::Delegating(ref mut delegating) =>
+				Self::translate_prior_lock(&mut delegating.prior),
+		}
+		voting
+	}
+
+	/// The prior lock does not expose its fields, but is encoded as `(BlockNumber, Balance)`.
+	fn translate_prior_lock<P: Encode + Decode>(prior: &mut P) {
+		let Ok((until, amount)) = transcode::<_, (BlockNumber, Balance)>(prior) else { return };
+		if let Ok(translated) = transcode(&(Self::rc_to_ah_block(until), amount)) {
+			*prior = translated;
+		}
+	}
+}
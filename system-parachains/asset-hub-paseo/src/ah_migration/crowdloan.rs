@@ -0,0 +1,171 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unwinding of the lease and crowdloan reserves of the Relay Chain.
+//!
+//! The reserves were migrated together with the accounts and stay reserved on Asset Hub until the
+//! Relay Chain reaches the end of the last lease of the parachain. From then on anyone can release
+//! them:
+//!
+//! - [`Pallet::unreserve_lease_deposit`] unreserves the reserve of a lease holder.
+//! - [`Pallet::withdraw_crowdloan_contribution`] pays a contribution back from the account of the
+//!   crowdloan, after unreserving the lease reserve of the crowdloan if needed.
+//! - [`Pallet::unreserve_crowdloan_deposit`] unreserves the deposit of the creator of a crowdloan,
+//!   once all contributions are withdrawn.
+//!
+//! Only the reserve that was migrated from the Relay Chain, as tracked in [`RcReserve`], is
+//! released. If the account of a lease holder could not be migrated, its reserves made on Asset
+//! Hub stay untouched.
+
+use super::*;
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	ensure,
+	traits::{fungible::Mutate, tokens::Preservation, ReservableCurrency},
+};
+use parachains_common::{AccountId, Balance, BlockNumber};
+use paseo_runtime_constants::ahm::{MigratedPallet, RcCrowdloanMessage};
+use sp_runtime::{traits::BlockNumberProvider, DispatchError};
+use sp_std::vec::Vec;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Record a batch of lease and crowdloan reserves.
+	pub(crate) fn do_receive_crowdloan_messages(messages: Vec<RcCrowdloanMessage>) {
+		let count_good = messages.len() as u32;
+
+		for message in messages {
+			Self::integrate_crowdloan_message(message);
+		}
+
+		Self::note_processed(MigratedPallet::Crowdloan, count_good, 0);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Crowdloan,
+			count_good,
+			count_bad: 0,
+		});
+	}
+
+	/// Record a single reserve until it can be released.
+	pub(crate) fn integrate_crowdloan_message(message: RcCrowdloanMessage) {
+		match message {
+			RcCrowdloanMessage::LeaseReserve { unreserve_block, para_id, who, amount } =>
+				RcLeaseReserve::<T>::insert((unreserve_block, para_id, who), amount),
+			RcCrowdloanMessage::CrowdloanContribution {
+				withdraw_block,
+				para_id,
+				contributor,
+				crowdloan_account,
+				amount,
+			} => RcCrowdloanContribution::<T>::insert(
+				(withdraw_block, para_id, contributor),
+				(crowdloan_account, amount),
+			),
+			RcCrowdloanMessage::CrowdloanDeposit {
+				unreserve_block,
+				para_id,
+				depositor,
+				amount,
+			} => RcCrowdloanDeposit::<T>::insert((unreserve_block, para_id, depositor), amount),
+		}
+	}
+
+	/// Unreserve the lease reserve of `who` for `para_id`.
+	pub(crate) fn do_unreserve_lease_deposit(
+		block: BlockNumber,
+		para_id: ParaId,
+		who: AccountId,
+	) -> Result<(), DispatchError> {
+		Self::ensure_rc_block_reached(block)?;
+		let amount =
+			RcLeaseReserve::<T>::take((block, para_id, &who)).ok_or(Error::<T>::NoReserve)?;
+
+		let amount = Self::unreserve_migrated(&who, amount);
+		Self::deposit_event(Event::LeaseReserveUnreserved { para_id, who, amount });
+		Ok(())
+	}
+
+	/// Pay the contribution of `contributor` to the crowdloan of `para_id` back.
+	pub(crate) fn do_withdraw_crowdloan_contribution(
+		block: BlockNumber,
+		para_id: ParaId,
+		contributor: AccountId,
+	) -> Result<(), DispatchError> {
+		Self::ensure_rc_block_reached(block)?;
+		let (crowdloan_account, amount) =
+			RcCrowdloanContribution::<T>::take((block, para_id, &contributor))
+				.ok_or(Error::<T>::NoContribution)?;
+
+		// The lease reserve of the crowdloan has to be released before it can pay back.
+		if RcLeaseReserve::<T>::contains_key((block, para_id, &crowdloan_account)) {
+			Self::do_unreserve_lease_deposit(block, para_id, crowdloan_account.clone())?;
+		}
+		<Balances<T> as Mutate<_>>::transfer(
+			&crowdloan_account,
+			&contributor,
+			amount,
+			Preservation::Expendable,
+		)?;
+
+		Self::deposit_event(Event::CrowdloanContributionWithdrawn { para_id, contributor, amount });
+		Ok(())
+	}
+
+	/// Unreserve the deposit of the creator of the crowdloan of `para_id`.
+	///
+	/// Fails as long as not all contributions are withdrawn.
+	pub(crate) fn do_unreserve_crowdloan_deposit(
+		block: BlockNumber,
+		para_id: ParaId,
+		depositor: AccountId,
+	) -> Result<(), DispatchError> {
+		Self::ensure_rc_block_reached(block)?;
+		ensure!(
+			RcCrowdloanContribution::<T>::iter_key_prefix((block, para_id)).next().is_none(),
+			Error::<T>::ContributionsRemaining
+		);
+		let amount = RcCrowdloanDeposit::<T>::take((block, para_id, &depositor))
+			.ok_or(Error::<T>::NoReserve)?;
+
+		let amount = Self::unreserve_migrated(&depositor, amount);
+		Self::deposit_event(Event::CrowdloanDepositUnreserved { para_id, depositor, amount });
+		Ok(())
+	}
+
+	/// Unreserve up to `amount` of the reserve of `who` that was migrated from the Relay Chain.
+	///
+	/// Returns the unreserved amount.
+	fn unreserve_migrated(who: &AccountId, amount: Balance) -> Balance {
+		let migrated = RcReserve::<T>::get(who);
+		let amount = amount.min(migrated);
+		let unreserved =
+			amount.saturating_sub(<Balances<T> as ReservableCurrency<_>>::unreserve(who, amount));
+		match migrated.saturating_sub(unreserved) {
+			0 => RcReserve::<T>::remove(who),
+			remaining => RcReserve::<T>::insert(who, remaining),
+		}
+		unreserved
+	}
+
+	/// Ensure that the Relay Chain reached `block`.
+	fn ensure_rc_block_reached(block: BlockNumber) -> Result<(), DispatchError> {
+		ensure!(
+			T::RcBlockNumberProvider::current_block_number() >= block,
+			Error::<T>::NotYetReleased
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,104 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the agents and delegations of `pallet_delegated_staking` migrated from the Relay
+//! Chain.
+//!
+//! The delegated funds are held from the delegators, these holds were already re-created together
+//! with the accounts.
+
+use super::*;
+use paseo_runtime_constants::ahm::{MigratedPallet, RcDelegatedStakingMessage};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// Storage items of `pallet_delegated_staking` that are not public.
+///
+/// The values are written with their portable representation, which has the same encoding.
+pub(crate) mod alias {
+	use frame_support::{pallet_prelude::OptionQuery, storage_alias, Twox64Concat};
+	use parachains_common::AccountId;
+	use paseo_runtime_constants::ahm::{PortableAgentLedger, PortableDelegation};
+
+	#[storage_alias]
+	pub type Agents<T: pallet_delegated_staking::Config> = CountedStorageMap<
+		pallet_delegated_staking::Pallet<T>,
+		Twox64Concat,
+		AccountId,
+		PortableAgentLedger,
+		OptionQuery,
+	>;
+
+	#[storage_alias]
+	pub type Delegators<T: pallet_delegated_staking::Config> = CountedStorageMap<
+		pallet_delegated_staking::Pallet<T>,
+		Twox64Concat,
+		AccountId,
+		PortableDelegation,
+		OptionQuery,
+	>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of agents and delegations.
+	pub(crate) fn do_receive_delegated_staking_messages(messages: Vec<RcDelegatedStakingMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_delegated_staking_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to integrate delegated staking message: {:?}",
+						e
+					);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::DelegatedStaking, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::DelegatedStaking,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate a single agent or delegation.
+	///
+	/// Existing entries are not overwritten.
+	pub(crate) fn integrate_delegated_staking_message(
+		message: RcDelegatedStakingMessage,
+	) -> Result<(), DispatchError> {
+		match message {
+			RcDelegatedStakingMessage::Agents(who, ledger) => {
+				if alias::Agents::<T>::contains_key(&who) {
+					return Err("agent already exists".into())
+				}
+				alias::Agents::<T>::insert(who, ledger);
+			},
+			RcDelegatedStakingMessage::Delegators(who, delegation) => {
+				if alias::Delegators::<T>::contains_key(&who) {
+					return Err("delegator already exists".into())
+				}
+				alias::Delegators::<T>::insert(who, delegation);
+			},
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,75 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the account indices migrated from the Relay Chain.
+//!
+//! Every index is claimed again for the same account, so that addresses given as an index keep
+//! resolving. The deposits were migrated as reserves together with the accounts of the owners and
+//! stay reserved. An index that was already claimed on Asset Hub keeps its owner on Asset Hub, the
+//! deposit of the Relay Chain owner is refunded.
+
+use super::*;
+use frame_support::traits::ReservableCurrency;
+use paseo_runtime_constants::ahm::{MigratedPallet, RcIndex};
+use sp_runtime::{DispatchError, Saturating};
+use sp_std::vec::Vec;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of account indices.
+	pub(crate) fn do_receive_indices(indices: Vec<RcIndex>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for index in indices {
+			match Self::integrate_index(index) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to integrate account index: {:?}", e);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Indices, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Indices,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Claim a single index for its Relay Chain owner.
+	///
+	/// Fails if the index is already claimed on Asset Hub, the deposit is refunded in that case.
+	pub(crate) fn integrate_index(index: RcIndex) -> Result<(), DispatchError> {
+		let RcIndex { index, who, deposit, frozen } = index;
+
+		if pallet_indices::Accounts::<T>::contains_key(index) {
+			let missing = <Balances<T> as ReservableCurrency<_>>::unreserve(&who, deposit);
+			RcDepositOutcomes::<T>::mutate(MigratedPallet::Indices, &who, |outcome| {
+				outcome.refunded.saturating_accrue(deposit.saturating_sub(missing));
+				if missing > 0 {
+					outcome.failed.saturating_inc();
+				}
+			});
+			return Err("account index already claimed on Asset Hub".into())
+		}
+
+		pallet_indices::Accounts::<T>::insert(index, (who, deposit, frozen));
+		Ok(())
+	}
+}
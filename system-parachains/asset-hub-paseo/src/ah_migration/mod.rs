@@ -0,0 +1,1221 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset Hub side of the Asset Hub Migration (AHM).
+//!
+//! The `AhMigrator` pallet receives the state of the Relay Chain in batches sent by the
+//! `RcMigrator` pallet and integrates it into the local pallets. Every call is only dispatchable
+//! by the Relay Chain.
+
+pub mod accounts;
+pub mod checking_account;
+pub mod claims;
+pub mod conviction_voting;
+pub mod crowdloan;
+pub mod delegated_staking;
+pub mod indices;
+pub mod multisig;
+pub mod nom_pools;
+pub mod preimage;
+pub mod proxy;
+pub mod referenda;
+pub mod scheduler;
+pub mod treasury;
+pub mod vesting;
+
+pub use pallet_ah_migrator::*;
+
+use codec::{Decode, Encode};
+use paseo_runtime_constants::ahm::MigratedPallet;
+
+/// The calls of the `RcMigrator` pallet on the Relay Chain.
+///
+/// The codec indices must correspond to the call indices of `pallet_rc_migrator`.
+#[derive(Encode, Decode)]
+pub(crate) enum RcMigratorCalls {
+	#[codec(index = 2)]
+	ReceiveProcessed { pallet: MigratedPallet, count_good: u32, count_bad: u32 },
+}
+
+/// The pallets of the Relay Chain runtime that are called by the migration.
+///
+/// The codec index must correspond to the index of `RcMigrator` in the `construct_runtime` of
+/// the Relay Chain.
+#[derive(Encode, Decode)]
+pub(crate) enum RelayRuntimePallets {
+	#[codec(index = 254)]
+	RcMigrator(RcMigratorCalls),
+}
+
+/// Weight functions needed for the `AhMigrator` pallet.
+pub trait WeightInfo {
+	fn receive_accounts(n: u32) -> frame_support::weights::Weight;
+	fn update_stage() -> frame_support::weights::Weight;
+	fn receive_referenda(n: u32) -> frame_support::weights::Weight;
+	fn receive_conviction_voting_messages(n: u32) -> frame_support::weights::Weight;
+	fn receive_treasury_messages(n: u32) -> frame_support::weights::Weight;
+	fn receive_nom_pools_messages(n: u32) -> frame_support::weights::Weight;
+	fn receive_delegated_staking_messages(n: u32) -> frame_support::weights::Weight;
+	fn receive_multisigs(n: u32) -> frame_support::weights::Weight;
+	fn receive_proxy_messages(n: u32) -> frame_support::weights::Weight;
+	fn receive_vesting_schedules(n: u32) -> frame_support::weights::Weight;
+	fn receive_claims_messages(n: u32) -> frame_support::weights::Weight;
+	fn switch_teleport_tracking() -> frame_support::weights::Weight;
+	fn receive_preimage_messages(n: u32) -> frame_support::weights::Weight;
+	fn receive_scheduled_tasks(n: u32) -> frame_support::weights::Weight;
+	fn receive_indices(n: u32) -> frame_support::weights::Weight;
+	fn receive_crowdloan_messages(n: u32) -> frame_support::weights::Weight;
+	fn unreserve_lease_deposit() -> frame_support::weights::Weight;
+	fn withdraw_crowdloan_contribution() -> frame_support::weights::Weight;
+	fn unreserve_crowdloan_deposit() -> frame_support::weights::Weight;
+	fn restore_unintegrated_balance() -> frame_support::weights::Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet_ah_migrator {
+	use super::{RcMigratorCalls, RelayRuntimePallets, WeightInfo};
+	use cumulus_primitives_core::ParaId;
+	use frame_support::{pallet_prelude::*, traits::fungibles};
+	use frame_system::pallet_prelude::*;
+	use parachains_common::{AccountId, Balance, BlockNumber, Hash};
+	use paseo_runtime_constants::{
+		ahm::{
+			MigratedPallet, MigrationProgress, MigrationStage, PalletProgress,
+			PortableFreezeReason, PortableGovernanceOrigin, PortableHoldReason,
+			PortableReferendumInfo, RcAccount, RcClaimsMessage, RcConvictionVotingMessage,
+			RcCrowdloanMessage, RcDelegatedStakingMessage, RcIndex, RcMultisig, RcNomPoolsMessage,
+			RcPreimageMessage, RcProxyMessage, RcReferendum, RcScheduledTask, RcTreasuryMessage,
+			RcVestingSchedule,
+		},
+		proxy::ProxyType as RcProxyType,
+	};
+	use polkadot_core_primitives::AccountIndex;
+	use sp_runtime::{
+		traits::{BlockNumberProvider, Convert, SaturatedConversion},
+		Saturating,
+	};
+	use sp_std::{vec, vec::Vec};
+	use xcm::prelude::*;
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::ah-migrator";
+
+	/// The maximum number of referenda that are cancelled per block because the preimage of
+	/// their proposal was not migrated.
+	pub const MAX_PENDING_PROPOSALS_PER_BLOCK: u32 = 10;
+
+	/// The number of Relay Chain blocks per Asset Hub block.
+	///
+	/// Relay Chain blocks are produced every 6 seconds, Asset Hub blocks every 12 seconds.
+	pub const RC_BLOCKS_PER_AH_BLOCK: BlockNumber = 2;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<
+			AccountId = AccountId,
+			AccountData = pallet_balances::AccountData<Balance>,
+			Hash = Hash,
+		> + pallet_balances::Config<Balance = Balance>
+		+ pallet_referenda::Config
+		+ pallet_conviction_voting::Config
+		+ pallet_treasury::Config
+		+ pallet_bounties::Config
+		+ pallet_child_bounties::Config
+		+ pallet_nomination_pools::Config
+		+ pallet_delegated_staking::Config
+		+ pallet_staking::Config
+		+ pallet_proxy::Config
+		+ pallet_vesting::Config
+		+ polkadot_runtime_common::claims::Config
+		+ pallet_preimage::Config
+		+ pallet_scheduler::Config
+		+ pallet_indices::Config<AccountIndex = AccountIndex>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin of the Relay Chain.
+		type RcOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The origin that can restore the balances of accounts that could not be integrated.
+		type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Converts the portable hold reasons of the Relay Chain into local hold reasons.
+		type PortableHoldReason: Convert<
+			PortableHoldReason,
+			<Self as pallet_balances::Config>::RuntimeHoldReason,
+		>;
+		/// Converts the portable freeze reasons of the Relay Chain into local freeze reasons.
+		type PortableFreezeReason: Convert<
+			PortableFreezeReason,
+			<Self as pallet_balances::Config>::FreezeIdentifier,
+		>;
+		/// Converts the portable referenda of the Relay Chain into local referenda.
+		///
+		/// The block numbers are already translated to Asset Hub block numbers.
+		type PortableReferendumInfo: Convert<
+			PortableReferendumInfo,
+			pallet_referenda::ReferendumInfoOf<Self, ()>,
+		>;
+		/// Converts the proxy types of the Relay Chain into local proxy types.
+		type RcProxyType: Convert<RcProxyType, Option<<Self as pallet_proxy::Config>::ProxyType>>;
+		/// Converts the portable origins of scheduled tasks of the Relay Chain into local origins.
+		type PortableSchedulerOrigin: Convert<
+			PortableGovernanceOrigin,
+			<Self as pallet_scheduler::Config>::PalletsOrigin,
+		>;
+		/// Maps the pallet and call index of a call of the Relay Chain to the indices of the same
+		/// call on Asset Hub.
+		///
+		/// Scheduled tasks whose call is not mapped are dropped.
+		type RcCallIndex: Convert<(u8, u8), Option<(u8, u8)>>;
+		/// The block number of the Relay Chain.
+		type RcBlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumber>;
+		/// The account tracking the balance of the native token on other chains, once Asset Hub
+		/// is its issuer.
+		type CheckingAccount: Get<AccountId>;
+		/// The account of the Relay Chain treasury on Asset Hub, which holds the assets of its
+		/// spends of assets of Asset Hub.
+		type RcTreasuryAccount: Get<AccountId>;
+		/// The assets of Asset Hub that the treasury pays its spends with.
+		type TreasuryAssets: fungibles::Mutate<AccountId, AssetId = Location, Balance = Balance>;
+		/// The router used to report the processed items to the Relay Chain.
+		type SendXcm: SendXcm;
+		/// The weight of `RcMigrator::receive_processed` on the Relay Chain.
+		type RcReportWeight: Get<Weight>;
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The current stage of the migration, as announced by the Relay Chain.
+	#[pallet::storage]
+	pub type AhMigrationStage<T: Config> = StorageValue<_, MigrationStage, ValueQuery>;
+
+	/// Whether Asset Hub is the issuer of the native token and tracks the teleports.
+	#[pallet::storage]
+	pub type TeleportsTracked<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The outcome of the deposits of the multisigs and proxies of the Relay Chain, per account.
+	///
+	/// Kept after the migration, so that the refunds of single accounts can be looked up.
+	#[pallet::storage]
+	pub type RcDepositOutcomes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MigratedPallet,
+		Blake2_128Concat,
+		AccountId,
+		DepositOutcome,
+		ValueQuery,
+	>;
+
+	/// The number of items that were processed, per pallet.
+	///
+	/// `sent` is only known to the Relay Chain and stays zero.
+	#[pallet::storage]
+	pub type ProcessedItems<T: Config> =
+		StorageMap<_, Twox64Concat, MigratedPallet, PalletProgress, ValueQuery>;
+
+	/// The free balance of the accounts that was minted on Asset Hub.
+	#[pallet::storage]
+	pub type MovedBalance<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The ongoing referenda of the Relay Chain whose proposal is translated once its preimage is
+	/// migrated, keyed by the hash of the preimage and the index of the referendum.
+	#[pallet::storage]
+	pub type RcPendingProposals<T: Config> =
+		StorageDoubleMap<_, Identity, Hash, Twox64Concat, u32, (), OptionQuery>;
+
+	/// The free balance of the accounts that could not be integrated.
+	///
+	/// It was already withdrawn on the Relay Chain and is restored by the
+	/// [`Config::ManagerOrigin`].
+	#[pallet::storage]
+	pub type UnintegratedBalance<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId, Balance, ValueQuery>;
+
+	/// The last error that prevented an item from being integrated.
+	#[pallet::storage]
+	pub type LastError<T: Config> = StorageValue<_, DispatchError, OptionQuery>;
+
+	/// The part of the reserve of an account that was migrated from the Relay Chain and not yet
+	/// released by the unwinding of leases and crowdloans.
+	#[pallet::storage]
+	pub type RcReserve<T: Config> = StorageMap<_, Blake2_128Concat, AccountId, Balance, ValueQuery>;
+
+	/// The reserves of the lease holders of the Relay Chain.
+	///
+	/// Keyed by the Relay Chain block from which on they can be unreserved, the parachain and the
+	/// lease holder.
+	#[pallet::storage]
+	pub type RcLeaseReserve<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, BlockNumber>,
+			NMapKey<Twox64Concat, ParaId>,
+			NMapKey<Blake2_128Concat, AccountId>,
+		),
+		Balance,
+		OptionQuery,
+	>;
+
+	/// The contributions to the crowdloans of the Relay Chain, with the account of the crowdloan
+	/// that pays them back.
+	///
+	/// Keyed by the Relay Chain block from which on they can be withdrawn, the parachain and the
+	/// contributor.
+	#[pallet::storage]
+	pub type RcCrowdloanContribution<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, BlockNumber>,
+			NMapKey<Twox64Concat, ParaId>,
+			NMapKey<Blake2_128Concat, AccountId>,
+		),
+		(AccountId, Balance),
+		OptionQuery,
+	>;
+
+	/// The deposits of the creators of the crowdloans of the Relay Chain.
+	///
+	/// Keyed like [`RcCrowdloanContribution`], they can be unreserved once all contributions are
+	/// withdrawn.
+	#[pallet::storage]
+	pub type RcCrowdloanDeposit<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, BlockNumber>,
+			NMapKey<Twox64Concat, ParaId>,
+			NMapKey<Blake2_128Concat, AccountId>,
+		),
+		Balance,
+		OptionQuery,
+	>;
+
+	/// What happened to the deposits of an account that were migrated from the Relay Chain.
+	#[derive(
+		Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+	)]
+	pub struct DepositOutcome {
+		/// The amount that stays reserved on Asset Hub.
+		pub reserved: Balance,
+		/// The amount that was refunded on Asset Hub.
+		pub refunded: Balance,
+		/// The number of items whose deposit could not be settled.
+		///
+		/// Their deposit stays reserved on Asset Hub.
+		pub failed: u32,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The migration moved from one stage to another.
+		StageTransition { old: MigrationStage, new: MigrationStage },
+		/// A batch of Relay Chain accounts was processed.
+		AccountBatchProcessed { count_good: u32, count_bad: u32 },
+		/// An account of the Relay Chain could not be integrated.
+		///
+		/// Nothing was minted for this account, its free balance is kept in
+		/// [`UnintegratedBalance`].
+		AccountIntegrationFailed { who: AccountId, error: DispatchError },
+		/// The balance of an account that could not be integrated was minted into `dest`.
+		UnintegratedBalanceRestored { who: AccountId, dest: AccountId, amount: Balance },
+		/// A batch of items of `pallet` was processed.
+		BatchProcessed { pallet: MigratedPallet, count_good: u32, count_bad: u32 },
+		/// Asset Hub became the issuer of the native token.
+		///
+		/// The checking account holds the balance on all other chains.
+		TeleportTrackingSwitched { checking_balance: Balance },
+		/// The proposal of an ongoing referendum of the Relay Chain could not be translated.
+		///
+		/// The referendum was cancelled, its deposits can be refunded.
+		ReferendumProposalRejected { index: u32, error: DispatchError },
+		/// A scheduled task of the Relay Chain could not be scheduled on Asset Hub.
+		///
+		/// `when` is the Relay Chain block the task was scheduled for and `index` its index in the
+		/// agenda of that block. The deposit of the preimage of its call was refunded.
+		ScheduledTaskDropped {
+			when: BlockNumber,
+			index: u32,
+			maybe_id: Option<[u8; 32]>,
+			call_hash: Hash,
+			error: DispatchError,
+		},
+		/// The reserve of a lease holder of the Relay Chain was unreserved.
+		LeaseReserveUnreserved { para_id: ParaId, who: AccountId, amount: Balance },
+		/// A contribution to a crowdloan of the Relay Chain was paid back.
+		CrowdloanContributionWithdrawn { para_id: ParaId, contributor: AccountId, amount: Balance },
+		/// The deposit of the creator of a crowdloan of the Relay Chain was unreserved.
+		CrowdloanDepositUnreserved { para_id: ParaId, depositor: AccountId, amount: Balance },
+		/// The account had more vesting schedules than Asset Hub allows, the last `merged` of them
+		/// were merged into a single schedule.
+		VestingSchedulesMerged { who: AccountId, merged: u32 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The Relay Chain did not reach the block from which on the reserve can be released.
+		NotYetReleased,
+		/// There is no such reserve.
+		NoReserve,
+		/// There is no such contribution.
+		NoContribution,
+		/// Not all contributions to the crowdloan are withdrawn yet.
+		ContributionsRemaining,
+		/// The account has no balance that could not be integrated.
+		NoUnintegratedBalance,
+		/// The integrated holds and reserves do not add up to the reserved amount on the Relay
+		/// Chain.
+		ReservedMismatch,
+		/// The integrated locks and freezes do not freeze the frozen amount on the Relay Chain.
+		FrozenMismatch,
+		/// The preimage of the proposal of a referendum was not migrated.
+		PreimageNotMigrated,
+		/// The teleports of the native token are already tracked on Asset Hub.
+		AlreadyTracked,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			// The proposals that still wait for their preimage once all preimages are migrated
+			// can not be translated anymore.
+			if AhMigrationStage::<T>::get().has_migrated(MigratedPallet::Preimage) {
+				weight.saturating_accrue(Self::reject_pending_proposals(
+					MAX_PENDING_PROPOSALS_PER_BLOCK,
+				));
+			}
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Receive a batch of accounts from the Relay Chain.
+		///
+		/// Each account is integrated on its own, failures are reported with an
+		/// [`Event::AccountIntegrationFailed`] and do not affect the rest of the batch.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_accounts(accounts.len() as u32))]
+		pub fn receive_accounts(origin: OriginFor<T>, accounts: Vec<RcAccount>) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_accounts(accounts);
+			Ok(())
+		}
+
+		/// Update the migration stage to the stage of the Relay Chain.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_stage())]
+		pub fn update_stage(origin: OriginFor<T>, stage: MigrationStage) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			let old = AhMigrationStage::<T>::get();
+			AhMigrationStage::<T>::put(stage);
+			Self::deposit_event(Event::StageTransition { old, new: stage });
+			Ok(())
+		}
+
+		/// Receive a batch of referenda from the Relay Chain.
+		///
+		/// Ongoing referenda are nudged, which re-schedules their alarms on Asset Hub.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_referenda(referenda.len() as u32))]
+		pub fn receive_referenda(
+			origin: OriginFor<T>,
+			referenda: Vec<RcReferendum>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_referenda(referenda);
+			Ok(())
+		}
+
+		/// Receive a batch of votes and class locks from the Relay Chain.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::receive_conviction_voting_messages(messages.len() as u32)
+		)]
+		pub fn receive_conviction_voting_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcConvictionVotingMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_conviction_voting_messages(messages);
+			Ok(())
+		}
+
+		/// Receive a batch of treasury, bounty and child bounty items from the Relay Chain.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_treasury_messages(messages.len() as u32))]
+		pub fn receive_treasury_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcTreasuryMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_treasury_messages(messages);
+			Ok(())
+		}
+
+		/// Receive a batch of nomination pools and pool members from the Relay Chain.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_nom_pools_messages(messages.len() as u32))]
+		pub fn receive_nom_pools_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcNomPoolsMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_nom_pools_messages(messages);
+			Ok(())
+		}
+
+		/// Receive a batch of agents and delegators of delegated staking from the Relay Chain.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::receive_delegated_staking_messages(messages.len() as u32)
+		)]
+		pub fn receive_delegated_staking_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcDelegatedStakingMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_delegated_staking_messages(messages);
+			Ok(())
+		}
+
+		/// Receive a batch of multisig deposits from the Relay Chain and refund them.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_multisigs(multisigs.len() as u32))]
+		pub fn receive_multisigs(
+			origin: OriginFor<T>,
+			multisigs: Vec<RcMultisig>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_multisigs(multisigs);
+			Ok(())
+		}
+
+		/// Receive a batch of proxies and announcements from the Relay Chain.
+		///
+		/// Their deposits are recomputed with the deposit parameters of Asset Hub.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_proxy_messages(messages.len() as u32))]
+		pub fn receive_proxy_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcProxyMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_proxy_messages(messages);
+			Ok(())
+		}
+
+		/// Receive the vesting schedules of a batch of accounts from the Relay Chain.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_vesting_schedules(messages.len() as u32))]
+		pub fn receive_vesting_schedules(
+			origin: OriginFor<T>,
+			messages: Vec<RcVestingSchedule>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_vesting_schedules(messages);
+			Ok(())
+		}
+
+		/// Receive a batch of pre-sale claims from the Relay Chain.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_claims_messages(messages.len() as u32))]
+		pub fn receive_claims_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcClaimsMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_claims_messages(messages);
+			Ok(())
+		}
+
+		/// Become the issuer of the native token.
+		///
+		/// Sent by the Relay Chain together with its total issuance at the time of the switch.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::switch_teleport_tracking())]
+		pub fn switch_teleport_tracking(
+			origin: OriginFor<T>,
+			rc_total_issuance: Balance,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_switch_teleport_tracking(rc_total_issuance)
+		}
+
+		/// Receive a batch of preimages and their request statuses from the Relay Chain.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_preimage_messages(messages.len() as u32))]
+		pub fn receive_preimage_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcPreimageMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_preimage_messages(messages);
+			Ok(())
+		}
+
+		/// Receive a batch of scheduled tasks from the Relay Chain.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_scheduled_tasks(tasks.len() as u32))]
+		pub fn receive_scheduled_tasks(
+			origin: OriginFor<T>,
+			tasks: Vec<RcScheduledTask>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_scheduled_tasks(tasks);
+			Ok(())
+		}
+
+		/// Receive a batch of claimed account indices from the Relay Chain.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_indices(indices.len() as u32))]
+		pub fn receive_indices(origin: OriginFor<T>, indices: Vec<RcIndex>) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_indices(indices);
+			Ok(())
+		}
+
+		/// Receive a batch of lease and crowdloan reserves from the Relay Chain.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_crowdloan_messages(messages.len() as u32))]
+		pub fn receive_crowdloan_messages(
+			origin: OriginFor<T>,
+			messages: Vec<RcCrowdloanMessage>,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			Self::do_receive_crowdloan_messages(messages);
+			Ok(())
+		}
+
+		/// Unreserve the reserve of the lease holder `who` of the parachain `para_id`.
+		///
+		/// Can be called by anyone once the Relay Chain reached `block`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::unreserve_lease_deposit())]
+		pub fn unreserve_lease_deposit(
+			origin: OriginFor<T>,
+			block: BlockNumber,
+			para_id: ParaId,
+			who: AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_unreserve_lease_deposit(block, para_id, who)
+		}
+
+		/// Pay the contribution of `contributor` to the crowdloan of the parachain `para_id` back.
+		///
+		/// Can be called by anyone once the Relay Chain reached `block`.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_crowdloan_contribution())]
+		pub fn withdraw_crowdloan_contribution(
+			origin: OriginFor<T>,
+			block: BlockNumber,
+			para_id: ParaId,
+			contributor: AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_withdraw_crowdloan_contribution(block, para_id, contributor)
+		}
+
+		/// Unreserve the deposit of the creator `depositor` of the crowdloan of the parachain
+		/// `para_id`.
+		///
+		/// Can be called by anyone once the Relay Chain reached `block` and all contributions are
+		/// withdrawn.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::unreserve_crowdloan_deposit())]
+		pub fn unreserve_crowdloan_deposit(
+			origin: OriginFor<T>,
+			block: BlockNumber,
+			para_id: ParaId,
+			depositor: AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_unreserve_crowdloan_deposit(block, para_id, depositor)
+		}
+
+		/// Mint the balance of the account `who` of the Relay Chain that could not be integrated
+		/// into `dest`.
+		///
+		/// The holds, reserves, freezes and locks of the account are not re-created.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::restore_unintegrated_balance())]
+		pub fn restore_unintegrated_balance(
+			origin: OriginFor<T>,
+			who: AccountId,
+			dest: AccountId,
+		) -> DispatchResult {
+			<T as Config>::ManagerOrigin::ensure_origin(origin)?;
+			Self::do_restore_unintegrated_balance(who, dest)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether state is being moved from the Relay Chain.
+		pub fn is_ongoing() -> bool {
+			AhMigrationStage::<T>::get().is_ongoing()
+		}
+
+		/// The progress of the migration on Asset Hub.
+		///
+		/// Only the processed items are known here, the Relay Chain reports the sent ones and the
+		/// cursor.
+		pub fn progress() -> MigrationProgress {
+			MigrationProgress {
+				stage: AhMigrationStage::<T>::get(),
+				accounts_cursor: None,
+				items: MigratedPallet::ALL
+					.into_iter()
+					.map(|pallet| (pallet, ProcessedItems::<T>::get(pallet)))
+					.collect(),
+				moved: MovedBalance::<T>::get(),
+				last_error: LastError::<T>::get(),
+			}
+		}
+
+		/// Account for a batch of `pallet` items, of which `count_bad` failed.
+		pub(crate) fn note_processed(pallet: MigratedPallet, count_good: u32, count_bad: u32) {
+			ProcessedItems::<T>::mutate(pallet, |progress| {
+				progress.acknowledged.saturating_accrue(count_good);
+				progress.failed.saturating_accrue(count_bad);
+			});
+			Self::send_to_relay(RcMigratorCalls::ReceiveProcessed {
+				pallet,
+				count_good,
+				count_bad,
+			});
+		}
+
+		/// Send a call of the `RcMigrator` pallet to the Relay Chain.
+		///
+		/// The report only serves the progress tracking of the Relay Chain, so a failure to send
+		/// it is logged instead of failing the integration of the batch.
+		fn send_to_relay(call: RcMigratorCalls) {
+			let message = Xcm(vec![
+				Instruction::UnpaidExecution {
+					weight_limit: WeightLimit::Unlimited,
+					check_origin: None,
+				},
+				Instruction::Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most: T::RcReportWeight::get(),
+					call: RelayRuntimePallets::RcMigrator(call).encode().into(),
+				},
+			]);
+
+			if let Err(e) = send_xcm::<T::SendXcm>(Location::parent(), message) {
+				log::error!(target: LOG_TARGET, "Failed to send message to the Relay Chain: {:?}", e);
+			}
+		}
+
+		/// Translate a block number of the Relay Chain into the Asset Hub block number that is
+		/// expected at the same time.
+		pub fn rc_to_ah_block(rc_block: BlockNumber) -> BlockNumber {
+			let rc_now = T::RcBlockNumberProvider::current_block_number();
+			let ah_now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+			if rc_block >= rc_now {
+				ah_now.saturating_add((rc_block - rc_now) / RC_BLOCKS_PER_AH_BLOCK)
+			} else {
+				ah_now.saturating_sub((rc_now - rc_block) / RC_BLOCKS_PER_AH_BLOCK)
+			}
+		}
+
+		/// Translate a number of Relay Chain blocks into the number of Asset Hub blocks that take
+		/// the same time.
+		pub fn rc_to_ah_period(rc_period: BlockNumber) -> BlockNumber {
+			rc_period / RC_BLOCKS_PER_AH_BLOCK
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use codec::Encode;
+	use cumulus_primitives_core::ParaId;
+	use frame_benchmarking::v2::*;
+	use frame_support::{
+		storage::unhashed,
+		traits::{
+			fungible::{Inspect, Mutate},
+			schedule::DispatchTime,
+			Bounded, EnsureOrigin, ReservableCurrency,
+		},
+		BoundedVec,
+	};
+	use frame_system::RawOrigin;
+	use pallet_conviction_voting::{Tally, Voting};
+	use pallet_referenda::{Deposit, ReferendumInfo, ReferendumStatus};
+	use parachains_common::{AccountId, Balance};
+	use paseo_runtime_constants::{
+		ahm::{
+			MigrationStage, PortableDelegation, PortableGovernanceOrigin, PortableHoldReason,
+			PortableLock, PortableLockReasons, PortableNominations, PortablePaymentState,
+			PortablePoolStake, PortableProxyDefinition, PortableRewardDestination,
+			PortableScheduled, PortableSpendStatus, PortableStakingLedger, PortableVestingInfo,
+			RcAccount, RcClaimsMessage, RcConvictionVotingMessage, RcCrowdloanMessage,
+			RcDelegatedStakingMessage, RcIndex, RcMultisig, RcNomPoolsMessage, RcPreimageMessage,
+			RcProxyMessage, RcReferendum, RcScheduledTask, RcTreasuryMessage, RcVestingSchedule,
+			PREIMAGE_CHUNK_LEN,
+		},
+		proxy::ProxyType as RcProxyType,
+	};
+	use polkadot_runtime_common::{claims::EthereumAddress, impls::VersionedLocatableAsset};
+	use sp_core::H256;
+	use sp_runtime::traits::{Get, Hash as _};
+	use sp_std::{vec, vec::Vec};
+	use xcm::{
+		latest::{Junction, Location},
+		VersionedLocation,
+	};
+
+	type Balances<T> = pallet_balances::Pallet<T>;
+
+	/// The largest batch of accounts that the Relay Chain sends.
+	const MAX_ACCOUNTS: u32 = 100;
+	/// The largest batch of items of any other pallet that the Relay Chain sends.
+	const MAX_ITEMS: u32 = 50;
+
+	/// The origin of the calls that the Relay Chain sends, which is the parent of Asset Hub.
+	fn rc_origin() -> pallet_xcm::Origin {
+		pallet_xcm::Origin::Xcm(Location::parent())
+	}
+
+	/// `factor` times the existential deposit.
+	fn ed<T: Config>(factor: Balance) -> Balance {
+		<T as pallet_balances::Config>::ExistentialDeposit::get().saturating_mul(factor)
+	}
+
+	/// An account with plenty of free balance, of which `reserved` is reserved.
+	fn funded_account<T: Config>(name: &'static str, index: u32, reserved: Balance) -> AccountId {
+		let who: AccountId = account(name, index, 0);
+		<Balances<T> as Mutate<_>>::set_balance(&who, ed::<T>(1_000_000));
+		<Balances<T> as ReservableCurrency<_>>::reserve(&who, reserved)
+			.expect("the account has enough free balance");
+		who
+	}
+
+	#[benchmarks(where <T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>)]
+	mod benchmarks {
+		use super::*;
+
+		/// Accounts with a hold, a reserve and a lock.
+		#[benchmark]
+		fn receive_accounts(n: Linear<1, { MAX_ACCOUNTS }>) {
+			let accounts = (0..n)
+				.map(|i| RcAccount {
+					who: account("account", i, 0),
+					free: ed::<T>(100),
+					reserved: ed::<T>(2),
+					frozen: ed::<T>(1),
+					holds: vec![(PortableHoldReason::Preimage, ed::<T>(1))],
+					freezes: vec![],
+					locks: vec![PortableLock {
+						id: *b"benchmar",
+						amount: ed::<T>(1),
+						reasons: PortableLockReasons::All,
+					}],
+					unnamed_reserve: ed::<T>(1),
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), accounts);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Accounts).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn update_stage() {
+			let stage = MigrationStage::Ongoing(MigratedPallet::Accounts);
+
+			#[extrinsic_call]
+			_(rc_origin(), stage);
+
+			assert_eq!(AhMigrationStage::<T>::get(), stage);
+		}
+
+		/// Ongoing referenda whose proposal waits for its preimage.
+		#[benchmark]
+		fn receive_referenda(n: Linear<1, { MAX_ITEMS }>) {
+			let referenda = (0..n)
+				.map(|index| RcReferendum {
+					index,
+					info: ReferendumInfo::Ongoing(ReferendumStatus {
+						track: 0,
+						origin: PortableGovernanceOrigin::Root,
+						proposal: Bounded::Lookup { hash: H256::repeat_byte(1), len: 100 },
+						enactment: DispatchTime::After(10),
+						submitted: 0,
+						submission_deposit: Deposit {
+							who: account("submitter", index, 0),
+							amount: ed::<T>(1),
+						},
+						decision_deposit: None,
+						deciding: None,
+						tally: Tally::from_parts(0, 0, 0),
+						in_queue: false,
+						alarm: None,
+					}),
+					metadata: Some(H256::repeat_byte(2)),
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), referenda);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Referenda).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn receive_conviction_voting_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let messages = (0..n)
+				.map(|i| {
+					RcConvictionVotingMessage::VotingFor(
+						account("voter", i, 0),
+						0,
+						Voting::default(),
+					)
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::ConvictionVoting).acknowledged, n);
+		}
+
+		/// Spends of the native token of the Relay Chain, which are re-anchored to Asset Hub.
+		#[benchmark]
+		fn receive_treasury_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let messages = (0..n)
+				.map(|i| {
+					let beneficiary: AccountId = account("beneficiary", i, 0);
+					RcTreasuryMessage::Spends(
+						i,
+						PortableSpendStatus {
+							asset_kind: VersionedLocatableAsset::V4 {
+								location: Location::here(),
+								asset_id: Location::here().into(),
+							},
+							amount: ed::<T>(10),
+							beneficiary: VersionedLocation::V4(Location::new(
+								0,
+								[Junction::AccountId32 { network: None, id: beneficiary.into() }],
+							)),
+							valid_from: 0,
+							expire_at: 100,
+							status: PortablePaymentState::Pending,
+						},
+					)
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Treasury).acknowledged, n);
+		}
+
+		/// Stakes of pools that nominate, which are inserted into the voter list.
+		#[benchmark]
+		fn receive_nom_pools_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let target: AccountId = account("target", 0, 0);
+			let messages = (0..n)
+				.map(|i| {
+					RcNomPoolsMessage::PoolStake(PortablePoolStake {
+						ledger: PortableStakingLedger {
+							stash: account("pool", i, 0),
+							total: ed::<T>(100),
+							active: ed::<T>(100),
+							unlocking: vec![],
+							legacy_claimed_rewards: vec![],
+						},
+						payee: Some(PortableRewardDestination::Stash),
+						nominations: Some(PortableNominations {
+							targets: vec![target.clone()],
+							submitted_in: 0,
+							suppressed: false,
+						}),
+					})
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::NomPools).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn receive_delegated_staking_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let agent: AccountId = account("agent", 0, 0);
+			let messages = (0..n)
+				.map(|i| {
+					RcDelegatedStakingMessage::Delegators(
+						account("delegator", i, 0),
+						PortableDelegation { agent: agent.clone(), amount: ed::<T>(10) },
+					)
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::DelegatedStaking).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn receive_multisigs(n: Linear<1, { MAX_ITEMS }>) {
+			let multisigs = (0..n)
+				.map(|i| RcMultisig {
+					creator: funded_account::<T>("creator", i, ed::<T>(1)),
+					deposit: ed::<T>(1),
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), multisigs);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Multisig).acknowledged, n);
+		}
+
+		/// Proxies whose deposit is not covered by the migrated deposit.
+		#[benchmark]
+		fn receive_proxy_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let messages = (0..n)
+				.map(|i| RcProxyMessage::Proxies {
+					delegator: funded_account::<T>("delegator", i, 0),
+					deposit: 0,
+					proxies: vec![PortableProxyDefinition {
+						delegate: account("delegate", i, 0),
+						proxy_type: RcProxyType::Any,
+						delay: 0,
+					}],
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Proxy).acknowledged, n);
+		}
+
+		/// Schedules of accounts that already vest on Asset Hub, whose lock is recomputed.
+		#[benchmark]
+		fn receive_vesting_schedules(n: Linear<1, { MAX_ITEMS }>) {
+			let schedule = PortableVestingInfo {
+				locked: ed::<T>(10),
+				per_block: ed::<T>(1),
+				starting_block: 0,
+			};
+			let messages = (0..n)
+				.map(|i| {
+					let who = funded_account::<T>("vester", i, 0);
+					unhashed::put(
+						&pallet_vesting::Vesting::<T>::hashed_key_for(&who),
+						&vec![schedule.clone()],
+					);
+					RcVestingSchedule { who, schedules: vec![schedule.clone()] }
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Vesting).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn receive_claims_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let messages = (0..n)
+				.map(|i| {
+					let mut address = [0u8; 20];
+					address[..4].copy_from_slice(&i.to_le_bytes());
+					RcClaimsMessage::Claims(EthereumAddress(address), ed::<T>(10))
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Claims).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn switch_teleport_tracking() {
+			let rc_total_issuance =
+				<Balances<T> as Inspect<_>>::total_issuance().saturating_add(ed::<T>(1_000));
+
+			#[extrinsic_call]
+			_(rc_origin(), rc_total_issuance);
+
+			assert!(TeleportsTracked::<T>::get());
+		}
+
+		/// Preimages of the length of a chunk, which are complete and checked against their hash.
+		#[benchmark]
+		fn receive_preimage_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let messages = (0..n)
+				.map(|i| {
+					let mut data = vec![0u8; PREIMAGE_CHUNK_LEN as usize];
+					data[..4].copy_from_slice(&i.to_le_bytes());
+					RcPreimageMessage::Chunk {
+						hash: <T as frame_system::Config>::Hashing::hash(&data),
+						len: PREIMAGE_CHUNK_LEN,
+						offset: 0,
+						data,
+					}
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Preimage).acknowledged, n);
+		}
+
+		/// Named tasks with an inline call, which are translated and scheduled at distinct blocks.
+		#[benchmark]
+		fn receive_scheduled_tasks(n: Linear<1, { MAX_ITEMS }>) {
+			// `System::remark` of the Relay Chain.
+			let call =
+				Bounded::Inline(BoundedVec::truncate_from((0u8, 0u8, Vec::<u8>::new()).encode()));
+			let tasks = (0..n)
+				.map(|index| RcScheduledTask {
+					when: 10 + 2 * index,
+					index,
+					task: PortableScheduled {
+						maybe_id: Some(H256::from_low_u64_be(index.into()).0),
+						priority: 0,
+						call: call.clone(),
+						maybe_periodic: None,
+						origin: Some(PortableGovernanceOrigin::Root),
+					},
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), tasks);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Scheduler).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn receive_indices(n: Linear<1, { MAX_ITEMS }>) {
+			let indices = (0..n)
+				.map(|index| RcIndex {
+					index,
+					who: account("owner", index, 0),
+					deposit: ed::<T>(1),
+					frozen: false,
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), indices);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Indices).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn receive_crowdloan_messages(n: Linear<1, { MAX_ITEMS }>) {
+			let crowdloan_account: AccountId = account("crowdloan", 0, 0);
+			let messages = (0..n)
+				.map(|i| RcCrowdloanMessage::CrowdloanContribution {
+					withdraw_block: 0,
+					para_id: ParaId::from(2000),
+					contributor: account("contributor", i, 0),
+					crowdloan_account: crowdloan_account.clone(),
+					amount: ed::<T>(10),
+				})
+				.collect();
+
+			#[extrinsic_call]
+			_(rc_origin(), messages);
+
+			assert_eq!(ProcessedItems::<T>::get(MigratedPallet::Crowdloan).acknowledged, n);
+		}
+
+		#[benchmark]
+		fn unreserve_lease_deposit() {
+			let para_id = ParaId::from(2000);
+			let amount = ed::<T>(10);
+			let who = funded_account::<T>("leaser", 0, amount);
+			RcReserve::<T>::insert(&who, amount);
+			RcLeaseReserve::<T>::insert((0, para_id, &who), amount);
+			let caller: AccountId = whitelisted_caller();
+
+			#[extrinsic_call]
+			_(RawOrigin::Signed(caller), 0, para_id, who.clone());
+
+			assert!(!RcReserve::<T>::contains_key(&who));
+		}
+
+		/// A contribution to a crowdloan that still has its lease reserve.
+		#[benchmark]
+		fn withdraw_crowdloan_contribution() {
+			let para_id = ParaId::from(2000);
+			let amount = ed::<T>(10);
+			let crowdloan_account = funded_account::<T>("crowdloan", 0, amount);
+			RcReserve::<T>::insert(&crowdloan_account, amount);
+			RcLeaseReserve::<T>::insert((0, para_id, &crowdloan_account), amount);
+			let contributor: AccountId = account("contributor", 0, 0);
+			RcCrowdloanContribution::<T>::insert(
+				(0, para_id, &contributor),
+				(crowdloan_account, amount),
+			);
+			let caller: AccountId = whitelisted_caller();
+
+			#[extrinsic_call]
+			_(RawOrigin::Signed(caller), 0, para_id, contributor.clone());
+
+			assert_eq!(<Balances<T> as Inspect<_>>::balance(&contributor), amount);
+		}
+
+		#[benchmark]
+		fn unreserve_crowdloan_deposit() {
+			let para_id = ParaId::from(2000);
+			let amount = ed::<T>(10);
+			let depositor = funded_account::<T>("depositor", 0, amount);
+			RcReserve::<T>::insert(&depositor, amount);
+			RcCrowdloanDeposit::<T>::insert((0, para_id, &depositor), amount);
+			let caller: AccountId = whitelisted_caller();
+
+			#[extrinsic_call]
+			_(RawOrigin::Signed(caller), 0, para_id, depositor.clone());
+
+			assert!(!RcReserve::<T>::contains_key(&depositor));
+		}
+
+		#[benchmark]
+		fn restore_unintegrated_balance() -> Result<(), BenchmarkError> {
+			let origin = <T as Config>::ManagerOrigin::try_successful_origin()
+				.map_err(|_| BenchmarkError::Weightless)?;
+			let who: AccountId = account("unintegrated", 0, 0);
+			let dest: AccountId = account("dest", 0, 0);
+			UnintegratedBalance::<T>::insert(&who, ed::<T>(10));
+
+			#[extrinsic_call]
+			_(origin as <T as frame_system::Config>::RuntimeOrigin, who.clone(), dest);
+
+			assert!(!UnintegratedBalance::<T>::contains_key(&who));
+			Ok(())
+		}
+	}
+}
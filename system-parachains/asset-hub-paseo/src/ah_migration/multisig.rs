@@ -0,0 +1,73 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Refunds of the multisig deposits of the Relay Chain.
+//!
+//! The pending multisig operations are not migrated. The part of their deposits that was migrated
+//! together with the account of the depositor is reserved on Asset Hub and refunded here.
+
+use super::*;
+use frame_support::traits::ReservableCurrency;
+use paseo_runtime_constants::ahm::{MigratedPallet, RcMultisig};
+use sp_runtime::{DispatchError, Saturating};
+use sp_std::vec::Vec;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// Refund a batch of multisig deposits.
+	pub(crate) fn do_receive_multisigs(multisigs: Vec<RcMultisig>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for multisig in multisigs {
+			match Self::refund_multisig(multisig) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to refund multisig deposit: {:?}", e);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Multisig, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Multisig,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Unreserve the deposit of a single multisig operation and record the outcome.
+	///
+	/// Fails if not the whole deposit is reserved, the rest is refunded anyway.
+	pub(crate) fn refund_multisig(multisig: RcMultisig) -> Result<(), DispatchError> {
+		let missing =
+			<Balances<T> as ReservableCurrency<_>>::unreserve(&multisig.creator, multisig.deposit);
+		let refunded = multisig.deposit.saturating_sub(missing);
+
+		RcDepositOutcomes::<T>::mutate(MigratedPallet::Multisig, &multisig.creator, |outcome| {
+			outcome.refunded.saturating_accrue(refunded);
+			if missing > 0 {
+				outcome.failed.saturating_inc();
+			}
+		});
+
+		if missing > 0 {
+			return Err("multisig deposit not reserved".into())
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,210 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the nomination pools migrated from the Relay Chain.
+//!
+//! The bonded funds of the members and the rewards of the pools were already migrated together
+//! with the accounts. The block numbers of the commissions are translated to Asset Hub block
+//! numbers, everything else is integrated as is.
+//!
+//! The staking state of the bonded accounts of the pools is integrated into `pallet_staking`, and
+//! the pools that nominate are inserted into its voter list.
+
+use super::*;
+use frame_election_provider_support::SortedListProvider;
+use frame_support::BoundedVec;
+use pallet_nomination_pools::{
+	BalanceOf, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
+	GlobalMaxCommission, LastPoolId, MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata,
+	MinCreateBond, MinJoinBond, PoolMember, PoolMembers, ReversePoolIdLookup, RewardPool,
+	RewardPools, SubPools, SubPoolsStorage, TotalValueLocked,
+};
+use pallet_staking::{Nominations, RewardDestination, StakingLedger};
+use parachains_common::AccountId;
+#[cfg(feature = "try-runtime")]
+use paseo_runtime_constants::ahm::NomPoolsSummary;
+use paseo_runtime_constants::ahm::{
+	transcode, MigratedPallet, PortableCommission, PortableNomPoolsStorageValues,
+	PortablePoolStake, RcNomPoolsMessage,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of nomination pool items.
+	pub(crate) fn do_receive_nom_pools_messages(messages: Vec<RcNomPoolsMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_nom_pools_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to integrate nomination pools message: {:?}",
+						e
+					);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::NomPools, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::NomPools,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate a single item.
+	pub(crate) fn integrate_nom_pools_message(
+		message: RcNomPoolsMessage,
+	) -> Result<(), DispatchError> {
+		match message {
+			RcNomPoolsMessage::StorageValues(values) => Self::put_nom_pools_values(values)?,
+			RcNomPoolsMessage::PoolMembers(who, member) => PoolMembers::<T>::insert(
+				who,
+				transcode::<_, PoolMember<T>>(&member).map_err(|_| "invalid pool member")?,
+			),
+			RcNomPoolsMessage::BondedPools(id, mut pool) => {
+				pool.commission = Self::translate_commission(pool.commission);
+				BondedPools::<T>::insert(
+					id,
+					transcode::<_, BondedPoolInner<T>>(&pool).map_err(|_| "invalid bonded pool")?,
+				)
+			},
+			RcNomPoolsMessage::RewardPools(id, pool) => RewardPools::<T>::insert(
+				id,
+				transcode::<_, RewardPool<T>>(&pool).map_err(|_| "invalid reward pool")?,
+			),
+			RcNomPoolsMessage::SubPoolsStorage(id, pools) => SubPoolsStorage::<T>::insert(
+				id,
+				transcode::<_, SubPools<T>>(&pools).map_err(|_| "too many unbonding pools")?,
+			),
+			RcNomPoolsMessage::Metadata(id, metadata) => {
+				let metadata = BoundedVec::<u8, T::MaxMetadataLen>::try_from(metadata)
+					.map_err(|_| "metadata too long")?;
+				Metadata::<T>::insert(id, metadata)
+			},
+			RcNomPoolsMessage::ReversePoolIdLookup(who, id) =>
+				ReversePoolIdLookup::<T>::insert(who, id),
+			RcNomPoolsMessage::ClaimPermissions(who, permission) => ClaimPermissions::<T>::insert(
+				who,
+				transcode::<_, ClaimPermission>(&permission)
+					.map_err(|_| "invalid claim permission")?,
+			),
+			RcNomPoolsMessage::PoolStake(stake) => Self::integrate_pool_stake(stake)?,
+		}
+		Ok(())
+	}
+
+	/// Integrate the staking state of the bonded account of a pool.
+	///
+	/// The bonded account is a virtual staker, its stake is backed by the delegations that are
+	/// migrated with `pallet_delegated_staking`.
+	fn integrate_pool_stake(stake: PortablePoolStake) -> Result<(), DispatchError> {
+		let stash = stake.ledger.stash.clone();
+		let ledger = transcode::<_, StakingLedger<T>>(&stake.ledger)
+			.map_err(|_| "invalid staking ledger")?;
+		pallet_staking::Bonded::<T>::insert(&stash, &stash);
+		pallet_staking::Ledger::<T>::insert(&stash, ledger);
+		if let Some(payee) = stake.payee {
+			pallet_staking::Payee::<T>::insert(
+				&stash,
+				transcode::<_, RewardDestination<AccountId>>(&payee)
+					.map_err(|_| "invalid reward destination")?,
+			);
+		}
+		pallet_staking::VirtualStakers::<T>::insert(&stash, ());
+		if let Some(nominations) = stake.nominations {
+			pallet_staking::Nominators::<T>::insert(
+				&stash,
+				transcode::<_, Nominations<T>>(&nominations).map_err(|_| "too many nominations")?,
+			);
+			let score = pallet_staking::Pallet::<T>::weight_of(&stash);
+			<T as pallet_staking::Config>::VoterList::on_insert(stash, score)
+				.map_err(|_| "failed to insert the voter")?;
+		}
+		Ok(())
+	}
+
+	/// Set all storage values of the pallet.
+	fn put_nom_pools_values(values: PortableNomPoolsStorageValues) -> Result<(), DispatchError> {
+		let balance = |b| transcode::<_, BalanceOf<T>>(&b).map_err(|_| "invalid balance");
+		TotalValueLocked::<T>::put(balance(values.total_value_locked)?);
+		MinJoinBond::<T>::put(balance(values.min_join_bond)?);
+		MinCreateBond::<T>::put(balance(values.min_create_bond)?);
+		MaxPools::<T>::set(values.max_pools);
+		MaxPoolMembers::<T>::set(values.max_pool_members);
+		MaxPoolMembersPerPool::<T>::set(values.max_pool_members_per_pool);
+		GlobalMaxCommission::<T>::set(values.global_max_commission);
+		LastPoolId::<T>::put(values.last_pool_id);
+		Ok(())
+	}
+
+	/// Translate the Relay Chain block numbers of a commission to Asset Hub block numbers.
+	pub(crate) fn translate_commission(mut commission: PortableCommission) -> PortableCommission {
+		if let Some(ref mut change_rate) = commission.change_rate {
+			change_rate.min_delay = Self::rc_to_ah_period(change_rate.min_delay);
+		}
+		commission.throttle_from = commission.throttle_from.map(Self::rc_to_ah_block);
+		commission
+	}
+
+	/// Summary of the nomination pools and delegations that were integrated on Asset Hub.
+	#[cfg(feature = "try-runtime")]
+	pub fn nom_pools_summary() -> NomPoolsSummary {
+		let mut summary = NomPoolsSummary::default();
+		for (id, pool) in BondedPools::<T>::iter() {
+			summary.add_pool(&transcode(&pool).expect("bonded pools are portable"));
+			let stash = pallet_nomination_pools::Pallet::<T>::generate_bonded_account(id);
+			if let Some(ledger) = pallet_staking::Ledger::<T>::get(&stash) {
+				summary.add_ledger(
+					&transcode(&ledger).expect("staking ledgers are portable"),
+					pallet_staking::Nominators::<T>::contains_key(&stash),
+				);
+			}
+		}
+		for member in PoolMembers::<T>::iter_values() {
+			summary.add_member(&transcode(&member).expect("pool members are portable"));
+		}
+		for sub_pools in SubPoolsStorage::<T>::iter_values() {
+			summary.add_sub_pools(&transcode(&sub_pools).expect("sub pools are portable"));
+		}
+		for delegation in delegated_staking::alias::Delegators::<T>::iter_values() {
+			summary.add_delegation(&delegation);
+		}
+		summary
+	}
+
+	/// Check that the pools and delegations on Asset Hub match the summary that was taken on the
+	/// Relay Chain before the migration.
+	#[cfg(feature = "try-runtime")]
+	pub fn check_nom_pools(rc_before: NomPoolsSummary) -> Result<(), sp_runtime::TryRuntimeError> {
+		let ah_after = Self::nom_pools_summary();
+		if ah_after != rc_before {
+			log::error!(
+				target: LOG_TARGET,
+				"Nomination pools do not match, before: {:?}, after: {:?}",
+				rc_before,
+				ah_after
+			);
+			return Err("nomination pools do not match the Relay Chain".into())
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,180 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the preimages migrated from the Relay Chain.
+//!
+//! The preimages arrive in chunks and are checked against their hash once complete. Their deposits
+//! were migrated together with the accounts of the depositors and are kept, so the tickets of the
+//! request statuses stay valid. Preimages that are already known on Asset Hub keep their status on
+//! Asset Hub, the requests of the Relay Chain are added and the migrated deposit is refunded.
+
+use super::*;
+use frame_support::traits::{
+	fungible::MutateHold, tokens::Precision, QueryPreimage, ReservableCurrency,
+};
+use parachains_common::{AccountId, Balance, Hash};
+use paseo_runtime_constants::ahm::{
+	MigratedPallet, PortableHoldReason, PortableRequestStatus, RcPreimageMessage,
+};
+use sp_runtime::{
+	traits::{Convert, Hash as _},
+	DispatchError,
+};
+use sp_std::vec::Vec;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+type Preimage<T> = pallet_preimage::Pallet<T>;
+
+/// Storage items of `pallet_preimage` that are not public.
+pub(crate) mod alias {
+	use frame_support::{pallet_prelude::OptionQuery, storage_alias, Identity};
+	use parachains_common::Hash;
+	use paseo_runtime_constants::ahm::PortableRequestStatus;
+	use sp_std::vec::Vec;
+
+	#[storage_alias]
+	pub type StatusFor<T: pallet_preimage::Config> =
+		StorageMap<pallet_preimage::Pallet<T>, Identity, Hash, PortableRequestStatus, OptionQuery>;
+
+	#[storage_alias]
+	pub type RequestStatusFor<T: pallet_preimage::Config> =
+		StorageMap<pallet_preimage::Pallet<T>, Identity, Hash, PortableRequestStatus, OptionQuery>;
+
+	#[storage_alias]
+	pub type PreimageFor<T: pallet_preimage::Config> =
+		StorageMap<pallet_preimage::Pallet<T>, Identity, (Hash, u32), Vec<u8>, OptionQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of preimages and request statuses.
+	pub(crate) fn do_receive_preimage_messages(messages: Vec<RcPreimageMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_preimage_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to integrate preimage message: {:?}", e);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Preimage, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Preimage,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate a single preimage item.
+	pub(crate) fn integrate_preimage_message(
+		message: RcPreimageMessage,
+	) -> Result<(), DispatchError> {
+		match message {
+			RcPreimageMessage::RequestStatus { hash, status } =>
+				Self::integrate_request_status(hash, status, false),
+			RcPreimageMessage::LegacyStatus { hash, status } =>
+				Self::integrate_request_status(hash, status, true),
+			RcPreimageMessage::Chunk { hash, len, offset, data } =>
+				Self::integrate_preimage_chunk(hash, len, offset, data),
+		}
+	}
+
+	/// Insert the request status of the preimage `hash`.
+	///
+	/// The deposit of a `legacy` status is reserved instead of held.
+	fn integrate_request_status(
+		hash: Hash,
+		status: PortableRequestStatus,
+		legacy: bool,
+	) -> Result<(), DispatchError> {
+		if !alias::RequestStatusFor::<T>::contains_key(hash) &&
+			!alias::StatusFor::<T>::contains_key(hash)
+		{
+			if legacy {
+				alias::StatusFor::<T>::insert(hash, status);
+			} else {
+				alias::RequestStatusFor::<T>::insert(hash, status);
+			}
+			return Ok(())
+		}
+
+		// The preimage is already noted on Asset Hub, only the requests are kept.
+		if let Some((who, deposit)) = status.ticket() {
+			Self::refund_preimage_deposit(who, *deposit, legacy);
+		}
+		if let PortableRequestStatus::Requested { count, .. } = status {
+			for _ in 0..count {
+				<Preimage<T> as QueryPreimage>::request(&hash);
+			}
+		}
+		Ok(())
+	}
+
+	/// Append a chunk to the preimage `hash`.
+	///
+	/// Chunks of preimages that are already complete on Asset Hub are ignored.
+	fn integrate_preimage_chunk(
+		hash: Hash,
+		len: u32,
+		offset: u32,
+		data: Vec<u8>,
+	) -> Result<(), DispatchError> {
+		let existing = alias::PreimageFor::<T>::get((hash, len));
+		if let Some(preimage) = existing.as_ref().filter(|preimage| preimage.len() as u32 == len) {
+			Self::translate_pending_proposals(hash, preimage);
+			return Ok(())
+		}
+
+		let mut preimage = existing.unwrap_or_default();
+		if preimage.len() as u32 != offset {
+			return Err("preimage chunk out of order".into())
+		}
+		preimage.extend(data);
+
+		let complete = preimage.len() as u32 >= len;
+		if complete &&
+			(preimage.len() as u32 != len ||
+				<T as frame_system::Config>::Hashing::hash(&preimage) != hash)
+		{
+			alias::PreimageFor::<T>::remove((hash, len));
+			return Err("preimage does not match its hash".into())
+		}
+		if complete {
+			// The proposals of referenda that reference this preimage can be translated now.
+			Self::translate_pending_proposals(hash, &preimage);
+		}
+		alias::PreimageFor::<T>::insert((hash, len), preimage);
+		Ok(())
+	}
+
+	/// Refund a preimage deposit that was migrated together with the account of `who`.
+	fn refund_preimage_deposit(who: &AccountId, deposit: Balance, legacy: bool) {
+		if legacy {
+			<Balances<T> as ReservableCurrency<_>>::unreserve(who, deposit);
+		} else {
+			let reason = T::PortableHoldReason::convert(PortableHoldReason::Preimage);
+			let _ = <Balances<T> as MutateHold<_>>::release(
+				&reason,
+				who,
+				deposit,
+				Precision::BestEffort,
+			);
+		}
+	}
+}
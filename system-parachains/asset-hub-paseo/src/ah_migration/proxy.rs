@@ -0,0 +1,190 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the proxies and announcements migrated from the Relay Chain.
+//!
+//! The part of the deposits that was migrated together with the accounts is reserved on Asset Hub.
+//! The deposits are recomputed with the deposit parameters of Asset Hub, the difference is
+//! unreserved or, if the migrated part does not suffice, reserved in addition. Proxies and
+//! announcements of accounts that already have some on Asset Hub are merged.
+
+use super::*;
+use frame_support::{storage::unhashed, traits::ReservableCurrency};
+use pallet_proxy::{Announcements, Proxies, ProxyDefinition};
+use parachains_common::{AccountId, Balance, BlockNumber};
+use paseo_runtime_constants::ahm::{
+	MigratedPallet, PortableAnnouncement, PortableProxyDefinition, RcProxyMessage,
+};
+use sp_core::Get;
+use sp_runtime::{
+	traits::{Convert, SaturatedConversion},
+	DispatchError, Saturating,
+};
+use sp_std::vec::Vec;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+/// The proxies of an account, encoded like the values of `pallet_proxy::Proxies`.
+type ProxiesOf<T> =
+	(Vec<ProxyDefinition<AccountId, <T as pallet_proxy::Config>::ProxyType, BlockNumber>>, Balance);
+
+/// The announcements of an account, encoded like the values of `pallet_proxy::Announcements`.
+type AnnouncementsOf = (Vec<PortableAnnouncement>, Balance);
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of proxies and announcements.
+	pub(crate) fn do_receive_proxy_messages(messages: Vec<RcProxyMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_proxy_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to integrate proxy message: {:?}", e);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Proxy, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Proxy,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate the proxies or announcements of a single account.
+	pub(crate) fn integrate_proxy_message(message: RcProxyMessage) -> Result<(), DispatchError> {
+		match message {
+			RcProxyMessage::Proxies { delegator, deposit, proxies } =>
+				Self::integrate_proxies(delegator, deposit, proxies),
+			RcProxyMessage::Announcements { delegate, deposit, announcements } =>
+				Self::integrate_announcements(delegate, deposit, announcements),
+		}
+	}
+
+	/// Add the proxies of `delegator` and adjust their deposit.
+	///
+	/// Proxies with a proxy type that does not exist on Asset Hub are dropped.
+	fn integrate_proxies(
+		delegator: AccountId,
+		deposit: Balance,
+		proxies: Vec<PortableProxyDefinition>,
+	) -> Result<(), DispatchError> {
+		let key = Proxies::<T>::hashed_key_for(&delegator);
+		let (mut all, old_deposit) = unhashed::get::<ProxiesOf<T>>(&key).unwrap_or_default();
+
+		for proxy in proxies {
+			let Some(proxy_type) = T::RcProxyType::convert(proxy.proxy_type) else {
+				log::warn!(
+					target: LOG_TARGET,
+					"Dropping proxy of {:?} with unknown proxy type {:?}",
+					delegator,
+					proxy.proxy_type
+				);
+				continue
+			};
+			all.push(ProxyDefinition {
+				delegate: proxy.delegate,
+				proxy_type,
+				delay: Self::rc_to_ah_period(proxy.delay),
+			});
+		}
+		// The pallet keeps the proxies sorted and free of duplicates.
+		all.sort();
+		all.dedup();
+
+		if all.len() > <T as pallet_proxy::Config>::MaxProxies::get() as usize {
+			Self::refund_proxy_deposit(&delegator, deposit);
+			return Err("too many proxies".into())
+		}
+
+		let required =
+			pallet_proxy::Pallet::<T>::deposit(all.len() as u32).saturated_into::<Balance>();
+		Self::settle_proxy_deposit(&delegator, old_deposit, deposit, required)?;
+		unhashed::put::<ProxiesOf<T>>(&key, &(all, required));
+		Ok(())
+	}
+
+	/// Add the announcements of `delegate` and adjust their deposit.
+	fn integrate_announcements(
+		delegate: AccountId,
+		deposit: Balance,
+		announcements: Vec<PortableAnnouncement>,
+	) -> Result<(), DispatchError> {
+		let key = Announcements::<T>::hashed_key_for(&delegate);
+		let (mut all, old_deposit) = unhashed::get::<AnnouncementsOf>(&key).unwrap_or_default();
+
+		all.extend(announcements.into_iter().map(|announcement| PortableAnnouncement {
+			height: Self::rc_to_ah_block(announcement.height),
+			..announcement
+		}));
+
+		if all.len() > <T as pallet_proxy::Config>::MaxPending::get() as usize {
+			Self::refund_proxy_deposit(&delegate, deposit);
+			return Err("too many announcements".into())
+		}
+
+		let base: Balance =
+			<T as pallet_proxy::Config>::AnnouncementDepositBase::get().saturated_into();
+		let factor: Balance =
+			<T as pallet_proxy::Config>::AnnouncementDepositFactor::get().saturated_into();
+		let required = base.saturating_add(factor.saturating_mul(all.len() as Balance));
+		Self::settle_proxy_deposit(&delegate, old_deposit, deposit, required)?;
+		unhashed::put::<AnnouncementsOf>(&key, &(all, required));
+		Ok(())
+	}
+
+	/// Adjust the reserved deposit of `who` to `required`.
+	///
+	/// `old` is the deposit that was already reserved on Asset Hub and `migrated` the part of the
+	/// Relay Chain deposit that was migrated with the account. If the missing part can not be
+	/// reserved, the migrated part is refunded and nothing is integrated.
+	fn settle_proxy_deposit(
+		who: &AccountId,
+		old: Balance,
+		migrated: Balance,
+		required: Balance,
+	) -> Result<(), DispatchError> {
+		let held = old.saturating_add(migrated);
+		let refunded = if held >= required {
+			let excess = held - required;
+			excess.saturating_sub(<Balances<T> as ReservableCurrency<_>>::unreserve(who, excess))
+		} else {
+			if let Err(e) = <Balances<T> as ReservableCurrency<_>>::reserve(who, required - held) {
+				Self::refund_proxy_deposit(who, migrated);
+				return Err(e)
+			}
+			0
+		};
+
+		RcDepositOutcomes::<T>::mutate(MigratedPallet::Proxy, who, |outcome| {
+			outcome.reserved.saturating_accrue(required.saturating_sub(old));
+			outcome.refunded.saturating_accrue(refunded);
+		});
+		Ok(())
+	}
+
+	/// Refund the migrated deposit of items that could not be integrated.
+	fn refund_proxy_deposit(who: &AccountId, migrated: Balance) {
+		let missing = <Balances<T> as ReservableCurrency<_>>::unreserve(who, migrated);
+		RcDepositOutcomes::<T>::mutate(MigratedPallet::Proxy, who, |outcome| {
+			outcome.refunded.saturating_accrue(migrated.saturating_sub(missing));
+			outcome.failed.saturating_inc();
+		});
+	}
+}
@@ -0,0 +1,239 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the referenda migrated from the Relay Chain.
+//!
+//! The block numbers of the referenda are translated to Asset Hub block numbers. The alarms of
+//! ongoing referenda pointed to the scheduler of the Relay Chain, so they are dropped and the
+//! referenda are nudged, which re-computes the queues and schedules new alarms.
+//!
+//! The proposals of ongoing referenda are Relay Chain calls and are translated with
+//! [`Config::RcCallIndex`]. Inline proposals are translated right away, the others once their
+//! preimage is migrated. Referenda whose proposal can not be translated, or whose preimage is
+//! still missing once all preimages are migrated, are cancelled.
+
+use super::*;
+use frame_support::{
+	traits::{schedule::DispatchTime, Bounded, StorePreimage},
+	weights::Weight,
+};
+use frame_system::RawOrigin;
+use pallet_referenda::{BoundedCallOf, DecidingStatus, ReferendumInfo};
+use parachains_common::Hash;
+use paseo_runtime_constants::ahm::{MigratedPallet, PortableReferendumInfo, RcReferendum};
+use sp_core::Get;
+use sp_runtime::{
+	traits::{BlakeTwo256, Convert},
+	DispatchError, Saturating,
+};
+use sp_std::vec::Vec;
+
+type ReferendaCallOf<T> = <T as pallet_referenda::Config>::RuntimeCall;
+type ReferendaPreimages<T> = <T as pallet_referenda::Config>::Preimages;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of referenda.
+	pub(crate) fn do_receive_referenda(referenda: Vec<RcReferendum>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for RcReferendum { index, info, metadata } in referenda {
+			let rc_proposal = match info {
+				ReferendumInfo::Ongoing(ref status) => Some(status.proposal.clone()),
+				_ => None,
+			};
+			let mut info = T::PortableReferendumInfo::convert(Self::translate_referendum(info));
+			// The referendum is cancelled once it is integrated if its proposal can not be
+			// translated.
+			let mut untranslatable = None;
+			let ongoing = match (&mut info, rc_proposal) {
+				(ReferendumInfo::Ongoing(status), Some(rc_proposal)) => {
+					match Self::translate_rc_proposal(index, &rc_proposal) {
+						Ok(Some(proposal)) => status.proposal = proposal,
+						Ok(None) => {},
+						Err(error) => untranslatable = Some(error),
+					}
+					if status.deciding.is_some() {
+						pallet_referenda::DecidingCount::<T>::mutate(status.track, |count| {
+							count.saturating_inc()
+						});
+					}
+					true
+				},
+				_ => false,
+			};
+
+			pallet_referenda::ReferendumInfoFor::<T>::insert(index, info);
+			if let Some(hash) = metadata {
+				pallet_referenda::MetadataOf::<T>::insert(index, hash);
+			}
+			pallet_referenda::ReferendumCount::<T>::mutate(|count| {
+				*count = (*count).max(index.saturating_add(1))
+			});
+
+			let mut failed = false;
+			if ongoing {
+				if let Err(e) =
+					pallet_referenda::Pallet::<T>::nudge_referendum(RawOrigin::Root.into(), index)
+				{
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to nudge referendum {}: {:?}",
+						index,
+						e
+					);
+					LastError::<T>::put(e);
+					failed = true;
+				}
+			}
+			if let Some(error) = untranslatable {
+				Self::reject_rc_referendum(index, error);
+				failed = true;
+			}
+			if failed {
+				count_bad += 1;
+			} else {
+				count_good += 1;
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Referenda, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Referenda,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Translate the Relay Chain block numbers of a referendum to Asset Hub block numbers.
+	pub(crate) fn translate_referendum(info: PortableReferendumInfo) -> PortableReferendumInfo {
+		let block = Self::rc_to_ah_block;
+		match info {
+			ReferendumInfo::Ongoing(mut status) => {
+				status.enactment = match status.enactment {
+					DispatchTime::At(at) => DispatchTime::At(block(at)),
+					DispatchTime::After(after) => DispatchTime::After(Self::rc_to_ah_period(after)),
+				};
+				status.submitted = block(status.submitted);
+				status.deciding = status.deciding.map(|deciding| DecidingStatus {
+					since: block(deciding.since),
+					confirming: deciding.confirming.map(block),
+				});
+				// The alarm is re-scheduled when the referendum is nudged, which also puts it back
+				// into the queue of its track if needed.
+				status.alarm = None;
+				status.in_queue = false;
+				ReferendumInfo::Ongoing(status)
+			},
+			ReferendumInfo::Approved(moment, submission, decision) =>
+				ReferendumInfo::Approved(block(moment), submission, decision),
+			ReferendumInfo::Rejected(moment, submission, decision) =>
+				ReferendumInfo::Rejected(block(moment), submission, decision),
+			ReferendumInfo::Cancelled(moment, submission, decision) =>
+				ReferendumInfo::Cancelled(block(moment), submission, decision),
+			ReferendumInfo::TimedOut(moment, submission, decision) =>
+				ReferendumInfo::TimedOut(block(moment), submission, decision),
+			ReferendumInfo::Killed(moment) => ReferendumInfo::Killed(block(moment)),
+		}
+	}
+
+	/// Translate the proposal of an ongoing Relay Chain referendum.
+	///
+	/// Returns `None` for proposals that are not inline, they are noted in [`RcPendingProposals`]
+	/// until their preimage is migrated.
+	fn translate_rc_proposal(
+		index: u32,
+		proposal: &Bounded<(), BlakeTwo256>,
+	) -> Result<Option<BoundedCallOf<T, ()>>, DispatchError> {
+		match proposal {
+			Bounded::Inline(encoded) => Self::bound_rc_proposal(encoded).map(Some),
+			Bounded::Legacy { hash, .. } | Bounded::Lookup { hash, .. } => {
+				RcPendingProposals::<T>::insert(hash, index, ());
+				Ok(None)
+			},
+		}
+	}
+
+	/// Translate the encoded Relay Chain call of a proposal and bound it on Asset Hub.
+	///
+	/// Calls that do not fit inline are noted as preimages.
+	fn bound_rc_proposal(encoded: &[u8]) -> Result<BoundedCallOf<T, ()>, DispatchError> {
+		let call = Self::translate_rc_call_into::<ReferendaCallOf<T>>(encoded)
+			.ok_or("call can not be translated")?;
+		ReferendaPreimages::<T>::bound(call)
+	}
+
+	/// Translate the proposals of the referenda that wait for the preimage `hash`, whose encoded
+	/// call is `encoded`.
+	pub(crate) fn translate_pending_proposals(hash: Hash, encoded: &[u8]) {
+		for (index, ()) in RcPendingProposals::<T>::drain_prefix(hash) {
+			let Some(ReferendumInfo::Ongoing(mut status)) =
+				pallet_referenda::ReferendumInfoFor::<T>::get(index)
+			else {
+				continue
+			};
+			match Self::bound_rc_proposal(encoded) {
+				Ok(proposal) => {
+					status.proposal = proposal;
+					pallet_referenda::ReferendumInfoFor::<T>::insert(
+						index,
+						ReferendumInfo::Ongoing(status),
+					);
+				},
+				Err(error) => Self::reject_rc_referendum(index, error),
+			}
+		}
+	}
+
+	/// Cancel up to `limit` referenda whose proposal still waits for its preimage.
+	///
+	/// Called once all preimages are migrated, returns the consumed weight.
+	pub(crate) fn reject_pending_proposals(limit: u32) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		for (_, index, ()) in RcPendingProposals::<T>::drain().take(limit as usize) {
+			weight.saturating_accrue(Self::referendum_weight());
+			if let Some(ReferendumInfo::Ongoing(_)) =
+				pallet_referenda::ReferendumInfoFor::<T>::get(index)
+			{
+				Self::reject_rc_referendum(index, Error::<T>::PreimageNotMigrated.into());
+			}
+		}
+		weight
+	}
+
+	/// Cancel the referendum `index` whose proposal could not be translated.
+	///
+	/// The deposits of cancelled referenda can be refunded.
+	fn reject_rc_referendum(index: u32, error: DispatchError) {
+		log::warn!(
+			target: LOG_TARGET,
+			"Cancelling referendum {} whose proposal can not be translated: {:?}",
+			index,
+			error
+		);
+		if let Err(e) = pallet_referenda::Pallet::<T>::cancel(RawOrigin::Root.into(), index) {
+			log::error!(target: LOG_TARGET, "Failed to cancel referendum {}: {:?}", index, e);
+		}
+		Self::deposit_event(Event::ReferendumProposalRejected { index, error });
+		LastError::<T>::put(error);
+	}
+
+	/// Upper bound of the weight to integrate a single referendum.
+	pub(crate) fn referendum_weight() -> Weight {
+		// The referendum, its metadata, the referendum count and the deciding count are written,
+		// nudging the referendum services its track queue and schedules an alarm. The proposal is
+		// noted as pending or as a preimage, or the referendum is cancelled.
+		T::DbWeight::get().reads_writes(14, 14)
+	}
+}
@@ -0,0 +1,170 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the scheduled tasks migrated from the Relay Chain.
+//!
+//! The calls of the tasks are encoded for the Relay Chain. Calls of pallets that exist on both
+//! chains are translated with [`Config::RcCallIndex`] and scheduled again at the Asset Hub block
+//! that is expected at the same time. All other tasks are dropped with a
+//! [`Event::ScheduledTaskDropped`] event, so that governance can submit them again. The preimage of
+//! the Relay Chain call is released either way, its deposit is refunded once it is not requested
+//! anymore.
+
+use super::*;
+use codec::DecodeAll;
+use frame_support::traits::{
+	schedule::{
+		v3::{Anon, Named},
+		DispatchTime,
+	},
+	Bounded, QueryPreimage, StorePreimage,
+};
+use frame_system::RawOrigin;
+use parachains_common::BlockNumber;
+use paseo_runtime_constants::ahm::{MigratedPallet, RcScheduledTask};
+use sp_runtime::{
+	traits::{BlakeTwo256, Convert, SaturatedConversion},
+	DispatchError, Saturating,
+};
+use sp_std::vec::Vec;
+
+type Preimages<T> = <T as pallet_scheduler::Config>::Preimages;
+type Scheduler<T> = pallet_scheduler::Pallet<T>;
+
+/// The call of a scheduled task on Asset Hub.
+type CallOf<T> = <T as pallet_scheduler::Config>::RuntimeCall;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of scheduled tasks.
+	pub(crate) fn do_receive_scheduled_tasks(tasks: Vec<RcScheduledTask>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for task in tasks {
+			let (when, index) = (task.when, task.index);
+			let (maybe_id, call_hash) = (task.task.maybe_id, task.task.call.hash());
+			match Self::integrate_scheduled_task(task) {
+				Ok(()) => count_good += 1,
+				Err(error) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Dropping task {:?} of Relay Chain block {:?}: {:?}",
+						index,
+						when,
+						error
+					);
+					Self::deposit_event(Event::ScheduledTaskDropped {
+						when,
+						index,
+						maybe_id,
+						call_hash,
+						error,
+					});
+					LastError::<T>::put(error);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Scheduler, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Scheduler,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Translate the call of a single task and schedule it.
+	pub(crate) fn integrate_scheduled_task(task: RcScheduledTask) -> Result<(), DispatchError> {
+		let RcScheduledTask { when, task, .. } = task;
+
+		let encoded = Self::rc_call_bytes(&task.call);
+		// The Relay Chain call is not needed anymore, whether it can be translated or not.
+		Self::release_rc_call(&task.call);
+
+		let origin = task.origin.ok_or("origin does not exist on Asset Hub")?;
+		let call = Self::translate_rc_call(&encoded?).ok_or("call can not be translated")?;
+		let call = <Preimages<T> as StorePreimage>::bound(call)?;
+
+		let now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+		let when = Self::rc_to_ah_block(when).max(now.saturating_add(1));
+		let when = DispatchTime::At(when.saturated_into());
+		let maybe_periodic = task
+			.maybe_periodic
+			.map(|(period, count)| (Self::rc_to_ah_period(period).max(1).saturated_into(), count));
+		let origin = T::PortableSchedulerOrigin::convert(origin);
+
+		let result = match task.maybe_id {
+			Some(id) => <Scheduler<T> as Named<_, _, _>>::schedule_named(
+				id,
+				when,
+				maybe_periodic,
+				task.priority,
+				origin,
+				call.clone(),
+			),
+			None => <Scheduler<T> as Anon<_, _, _>>::schedule(
+				when,
+				maybe_periodic,
+				task.priority,
+				origin,
+				call.clone(),
+			),
+		};
+		if result.is_err() {
+			// The translated call is not referenced by any task.
+			<Preimages<T> as QueryPreimage>::drop(&call);
+		}
+		result.map(|_| ())
+	}
+
+	/// The encoded Relay Chain call of a task.
+	///
+	/// Calls that are not inlined are read from the migrated preimages.
+	fn rc_call_bytes(call: &Bounded<(), BlakeTwo256>) -> Result<Vec<u8>, DispatchError> {
+		match call {
+			Bounded::Inline(encoded) => Ok(encoded.to_vec()),
+			Bounded::Legacy { hash, .. } =>
+				Ok(<Preimages<T> as QueryPreimage>::fetch(hash, None)?.into_owned()),
+			Bounded::Lookup { hash, len } =>
+				Ok(<Preimages<T> as QueryPreimage>::fetch(hash, Some(*len))?.into_owned()),
+		}
+	}
+
+	/// Release the request of the Relay Chain scheduler for the preimage of `call`.
+	///
+	/// Preimages that are not requested anymore are removed and their deposit is refunded.
+	fn release_rc_call(call: &Bounded<(), BlakeTwo256>) {
+		let Some(hash) = call.lookup_hash() else { return };
+		<Preimages<T> as QueryPreimage>::unrequest(&hash);
+		if !<Preimages<T> as QueryPreimage>::is_requested(&hash) {
+			let _ = pallet_preimage::Pallet::<T>::unnote_preimage(RawOrigin::Root.into(), hash);
+		}
+	}
+
+	/// Translate an encoded Relay Chain call into a call of Asset Hub.
+	///
+	/// Only the pallet and call indices are mapped, the arguments have to decode unchanged.
+	pub(crate) fn translate_rc_call(encoded: &[u8]) -> Option<CallOf<T>> {
+		Self::translate_rc_call_into(encoded)
+	}
+
+	/// Translate an encoded Relay Chain call into the call type `C` of Asset Hub.
+	pub(crate) fn translate_rc_call_into<C: DecodeAll>(encoded: &[u8]) -> Option<C> {
+		let ([pallet, call], args) = encoded.split_first_chunk::<2>()?;
+		let (pallet, call) = T::RcCallIndex::convert((*pallet, *call))?;
+		let translated: Vec<u8> = [pallet, call].into_iter().chain(args.iter().copied()).collect();
+		C::decode_all(&mut &translated[..]).ok()
+	}
+}
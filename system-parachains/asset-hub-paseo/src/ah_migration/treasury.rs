@@ -0,0 +1,265 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the treasury, bounties and child bounties migrated from the Relay Chain.
+//!
+//! The funds and the deposits were already migrated together with the accounts. The block numbers
+//! of spends and bounties are translated to Asset Hub block numbers and the assets of spends are
+//! re-anchored, so that they are paid locally from the treasury account.
+//!
+//! The assets of spends of assets of Asset Hub are moved from the account of the Relay Chain
+//! treasury to the treasury account. Spends of assets on other chains can not be paid from Asset
+//! Hub and are refused.
+
+use super::*;
+use frame_support::{
+	storage::unhashed,
+	traits::{fungibles::Mutate, tokens::Preservation},
+	BoundedVec,
+};
+use paseo_runtime_constants::{
+	ahm::{
+		transcode, MigratedPallet, PortableBountyStatus, PortableChildBountyStatus,
+		PortablePaymentState, PortableSpendStatus, RcTreasuryMessage,
+	},
+	system_parachain::ASSET_HUB_ID,
+};
+use polkadot_runtime_common::impls::{
+	LocatableAssetConverter, VersionedLocatableAsset, VersionedLocationConverter,
+};
+use sp_core::Get;
+use sp_runtime::{traits::TryConvert, DispatchError};
+use sp_std::vec::Vec;
+use xcm::{
+	latest::{
+		Junction::{AccountId32, Parachain},
+		Location, Parent,
+	},
+	VersionedLocation,
+};
+
+/// Storage items of `pallet_treasury` that are not public.
+mod alias {
+	use frame_support::{pallet_prelude::ValueQuery, storage_alias};
+
+	#[storage_alias]
+	pub type ProposalCount<T: pallet_treasury::Config> =
+		StorageValue<pallet_treasury::Pallet<T>, u32, ValueQuery>;
+
+	#[storage_alias]
+	pub type SpendCount<T: pallet_treasury::Config> =
+		StorageValue<pallet_treasury::Pallet<T>, u32, ValueQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Integrate a batch of treasury, bounty and child bounty items.
+	pub(crate) fn do_receive_treasury_messages(messages: Vec<RcTreasuryMessage>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_treasury_message(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Failed to integrate treasury message: {:?}", e);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Treasury, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Treasury,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate a single item.
+	///
+	/// Items with private fields are written with their portable representation, which has the
+	/// same encoding.
+	pub(crate) fn integrate_treasury_message(
+		message: RcTreasuryMessage,
+	) -> Result<(), DispatchError> {
+		match message {
+			RcTreasuryMessage::ProposalCount(count) => alias::ProposalCount::<T>::put(count),
+			RcTreasuryMessage::Proposals(id, proposal) =>
+				unhashed::put(&pallet_treasury::Proposals::<T>::hashed_key_for(id), &proposal),
+			RcTreasuryMessage::Approvals(approvals) => pallet_treasury::Approvals::<T>::set(
+				transcode(&approvals).map_err(|_| "too many approvals")?,
+			),
+			RcTreasuryMessage::SpendCount(count) => alias::SpendCount::<T>::put(count),
+			RcTreasuryMessage::Spends(id, spend) => {
+				let spend = Self::translate_spend(spend)?;
+				Self::fund_spend(&spend)?;
+				unhashed::put(&pallet_treasury::Spends::<T>::hashed_key_for(id), &spend)
+			},
+			RcTreasuryMessage::BountyCount(count) => pallet_bounties::BountyCount::<T>::put(count),
+			RcTreasuryMessage::Bounties(id, mut bounty) => {
+				bounty.status = Self::translate_bounty_status(bounty.status);
+				unhashed::put(&pallet_bounties::Bounties::<T>::hashed_key_for(id), &bounty)
+			},
+			RcTreasuryMessage::BountyDescriptions(id, description) => {
+				let description = BoundedVec::<u8, T::MaximumReasonLength>::try_from(description)
+					.map_err(|_| "description too long")?;
+				pallet_bounties::BountyDescriptions::<T>::insert(id, description)
+			},
+			RcTreasuryMessage::BountyApprovals(approvals) =>
+				pallet_bounties::BountyApprovals::<T>::set(
+					transcode(&approvals).map_err(|_| "too many bounty approvals")?,
+				),
+			RcTreasuryMessage::ChildBountyCount(count) =>
+				pallet_child_bounties::ChildBountyCount::<T>::put(count),
+			RcTreasuryMessage::ParentChildBounties(parent, count) =>
+				pallet_child_bounties::ParentChildBounties::<T>::insert(parent, count),
+			RcTreasuryMessage::ChildBounties(parent, id, mut child_bounty) => {
+				child_bounty.status = Self::translate_child_bounty_status(child_bounty.status);
+				unhashed::put(
+					&pallet_child_bounties::ChildBounties::<T>::hashed_key_for(parent, id),
+					&child_bounty,
+				)
+			},
+			RcTreasuryMessage::ChildBountyDescriptions(id, description) => {
+				let description = BoundedVec::<u8, T::MaximumReasonLength>::try_from(description)
+					.map_err(|_| "description too long")?;
+				pallet_child_bounties::ChildBountyDescriptions::<T>::insert(id, description)
+			},
+			RcTreasuryMessage::ChildrenCuratorFees(id, fee) =>
+				pallet_child_bounties::ChildrenCuratorFees::<T>::insert(
+					id,
+					transcode::<_, pallet_treasury::BalanceOf<T>>(&fee)
+						.map_err(|_| "invalid curator fee")?,
+				),
+		}
+		Ok(())
+	}
+
+	/// Translate the block numbers of a spend and re-anchor its asset and beneficiary.
+	pub(crate) fn translate_spend(
+		mut spend: PortableSpendStatus,
+	) -> Result<PortableSpendStatus, DispatchError> {
+		let (asset_kind, beneficiary) =
+			Self::translate_spend_asset(spend.asset_kind, &spend.beneficiary)?;
+		spend.asset_kind = asset_kind;
+		spend.beneficiary = beneficiary;
+		spend.valid_from = Self::rc_to_ah_block(spend.valid_from);
+		spend.expire_at = Self::rc_to_ah_block(spend.expire_at);
+		Ok(spend)
+	}
+
+	/// Move the assets of a translated spend that is still to be paid to the treasury account.
+	///
+	/// The native token was migrated together with the treasury account. Spends of assets on
+	/// other chains are refused, the Relay Chain treasury keeps their assets.
+	pub(crate) fn fund_spend(spend: &PortableSpendStatus) -> Result<(), DispatchError> {
+		if matches!(spend.status, PortablePaymentState::Attempted { .. }) {
+			return Ok(())
+		}
+		let asset = LocatableAssetConverter::try_convert(spend.asset_kind.clone())
+			.map_err(|_| DispatchError::Other("unsupported asset kind"))?;
+		if asset.location != Location::here() {
+			return Err(DispatchError::Other("spend of an asset on another chain"))
+		}
+		if asset.asset_id.0 == Location::parent() {
+			return Ok(())
+		}
+		T::TreasuryAssets::transfer(
+			asset.asset_id.0,
+			&T::RcTreasuryAccount::get(),
+			&pallet_treasury::Pallet::<T>::account_id(),
+			spend.amount,
+			Preservation::Expendable,
+		)?;
+		Ok(())
+	}
+
+	/// Re-anchor the asset kind and the beneficiary of a spend from the Relay Chain to Asset Hub.
+	///
+	/// The asset kind of the Relay Chain is located relative to the Relay Chain and the
+	/// beneficiary relative to the location of the asset:
+	/// - DOT on the Relay Chain becomes DOT on Asset Hub, beneficiaries other than a plain account
+	///   are re-anchored to Asset Hub.
+	/// - Assets on Asset Hub keep their asset id and beneficiary.
+	/// - Assets on other chains are re-anchored to Asset Hub and keep their asset id and
+	///   beneficiary. They can not be paid locally and are refused by [`Self::fund_spend`].
+	pub(crate) fn translate_spend_asset(
+		asset_kind: VersionedLocatableAsset,
+		beneficiary: &VersionedLocation,
+	) -> Result<(VersionedLocatableAsset, VersionedLocation), DispatchError> {
+		let asset = LocatableAssetConverter::try_convert(asset_kind)
+			.map_err(|_| DispatchError::Other("unsupported asset kind"))?;
+		let beneficiary = VersionedLocationConverter::try_convert(beneficiary)
+			.map_err(|_| DispatchError::Other("unsupported beneficiary"))?;
+		let reanchor = |location: Location| -> Result<Location, DispatchError> {
+			let mut location = location;
+			location
+				.prepend_with(Parent)
+				.map_err(|_| DispatchError::Other("location can not be re-anchored"))?;
+			Ok(location)
+		};
+
+		let (location, asset_id, beneficiary) = match asset.location.unpack() {
+			(0, []) => {
+				let beneficiary = match beneficiary.unpack() {
+					(0, [AccountId32 { .. }]) => beneficiary,
+					_ => reanchor(beneficiary)?,
+				};
+				(Location::here(), reanchor(asset.asset_id.0)?, beneficiary)
+			},
+			(0, [Parachain(id)]) if *id == ASSET_HUB_ID =>
+				(Location::here(), asset.asset_id.0, beneficiary),
+			_ => (reanchor(asset.location)?, asset.asset_id.0, beneficiary),
+		};
+
+		Ok((
+			VersionedLocatableAsset::V4 { location, asset_id: asset_id.into() },
+			VersionedLocation::V4(beneficiary),
+		))
+	}
+
+	/// Translate the Relay Chain block numbers of a bounty status to Asset Hub block numbers.
+	pub(crate) fn translate_bounty_status(status: PortableBountyStatus) -> PortableBountyStatus {
+		match status {
+			PortableBountyStatus::Active { curator, update_due } => PortableBountyStatus::Active {
+				curator,
+				update_due: Self::rc_to_ah_block(update_due),
+			},
+			PortableBountyStatus::PendingPayout { curator, beneficiary, unlock_at } =>
+				PortableBountyStatus::PendingPayout {
+					curator,
+					beneficiary,
+					unlock_at: Self::rc_to_ah_block(unlock_at),
+				},
+			status => status,
+		}
+	}
+
+	/// Translate the Relay Chain block numbers of a child bounty status to Asset Hub block
+	/// numbers.
+	pub(crate) fn translate_child_bounty_status(
+		status: PortableChildBountyStatus,
+	) -> PortableChildBountyStatus {
+		match status {
+			PortableChildBountyStatus::PendingPayout { curator, beneficiary, unlock_at } =>
+				PortableChildBountyStatus::PendingPayout {
+					curator,
+					beneficiary,
+					unlock_at: Self::rc_to_ah_block(unlock_at),
+				},
+			status => status,
+		}
+	}
+}
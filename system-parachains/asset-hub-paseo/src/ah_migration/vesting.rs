@@ -0,0 +1,161 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration of the vesting schedules migrated from the Relay Chain.
+//!
+//! `pallet_vesting` is driven by the block number of the Relay Chain on Asset Hub, the schedules
+//! keep their starting blocks and unlock at the same time as before. The vesting locks were
+//! already migrated together with the accounts. Schedules of accounts that already vest on Asset
+//! Hub are merged, their lock is recomputed afterwards.
+
+use super::*;
+use frame_support::storage::unhashed;
+use pallet_vesting::Vesting;
+use parachains_common::{Balance, BlockNumber};
+#[cfg(feature = "try-runtime")]
+use paseo_runtime_constants::ahm::VestingSummary;
+use paseo_runtime_constants::ahm::{MigratedPallet, PortableVestingInfo, RcVestingSchedule};
+use sp_runtime::{
+	traits::{BlockNumberProvider, SaturatedConversion},
+	DispatchError,
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Integrate the vesting schedules of a batch of accounts.
+	pub(crate) fn do_receive_vesting_schedules(messages: Vec<RcVestingSchedule>) {
+		let (mut count_good, mut count_bad) = (0, 0);
+
+		for message in messages {
+			match Self::integrate_vesting_schedule(message) {
+				Ok(()) => count_good += 1,
+				Err(e) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to integrate vesting schedules: {:?}",
+						e
+					);
+					LastError::<T>::put(e);
+					count_bad += 1;
+				},
+			}
+		}
+
+		Self::note_processed(MigratedPallet::Vesting, count_good, count_bad);
+		Self::deposit_event(Event::BatchProcessed {
+			pallet: MigratedPallet::Vesting,
+			count_good,
+			count_bad,
+		});
+	}
+
+	/// Integrate the vesting schedules of a single account.
+	///
+	/// The schedules are written with their portable representation, which has the same encoding.
+	pub(crate) fn integrate_vesting_schedule(
+		message: RcVestingSchedule,
+	) -> Result<(), DispatchError> {
+		let key = Vesting::<T>::hashed_key_for(&message.who);
+		let existing = unhashed::get::<Vec<PortableVestingInfo>>(&key);
+		let mut recompute_lock = existing.is_some();
+
+		let mut schedules = existing.unwrap_or_default();
+		schedules.extend(message.schedules);
+		let max = <T as pallet_vesting::Config>::MAX_VESTING_SCHEDULES as usize;
+		if schedules.len() > max {
+			let now = <T as pallet_vesting::Config>::BlockNumberProvider::current_block_number()
+				.saturated_into();
+			let excess = schedules.split_off(max - 1);
+			schedules.push(Self::merge_vesting_schedules(&excess, now));
+			recompute_lock = true;
+			Self::deposit_event(Event::VestingSchedulesMerged {
+				who: message.who.clone(),
+				merged: excess.len() as u32,
+			});
+		}
+		unhashed::put(&key, &schedules);
+
+		// The migrated lock only covers the schedules of the Relay Chain as they were.
+		if recompute_lock {
+			pallet_vesting::Pallet::<T>::vest(frame_system::RawOrigin::Signed(message.who).into())
+				.map_err(|e| e.error)?;
+		}
+		Ok(())
+	}
+
+	/// Merge `schedules` into a single schedule that does not unlock anything earlier than they
+	/// would.
+	///
+	/// What is still locked at `now` stays locked until the latest start and is then unlocked
+	/// linearly until the latest end.
+	pub(crate) fn merge_vesting_schedules(
+		schedules: &[PortableVestingInfo],
+		now: BlockNumber,
+	) -> PortableVestingInfo {
+		let locked = schedules.iter().map(|s| s.locked_at(now)).fold(0, Balance::saturating_add);
+		let starting_block =
+			schedules.iter().map(|s| s.starting_block).max().unwrap_or(now).max(now);
+		let ending_block = schedules.iter().map(|s| s.ending_block()).max().unwrap_or_default();
+		let duration = ending_block.saturating_sub(starting_block as Balance).max(1);
+		PortableVestingInfo { locked, per_block: locked.div_ceil(duration).max(1), starting_block }
+	}
+
+	/// Summary of the vesting schedules that were integrated on Asset Hub.
+	///
+	/// The locked amounts are sampled at the Relay Chain block numbers of `rc_before`.
+	#[cfg(feature = "try-runtime")]
+	pub fn vesting_summary(rc_before: &VestingSummary) -> VestingSummary {
+		let mut summary = VestingSummary::new(rc_before.locked_at.iter().map(|(n, _)| *n));
+		for who in Vesting::<T>::iter_keys() {
+			let schedules =
+				unhashed::get::<Vec<PortableVestingInfo>>(&Vesting::<T>::hashed_key_for(&who))
+					.expect("vesting schedules are portable");
+			summary.add_schedules(&schedules);
+		}
+		summary
+	}
+
+	/// Check that the vesting schedules on Asset Hub lock the same amounts as the schedules that
+	/// were taken on the Relay Chain before the migration.
+	///
+	/// Merged schedules are fewer and may lock more, but never less after the first sampled height.
+	/// They lock what was still locked when they were merged, which is after that height.
+	#[cfg(feature = "try-runtime")]
+	pub fn check_vesting(rc_before: VestingSummary) -> Result<(), sp_runtime::TryRuntimeError> {
+		let ah_after = Self::vesting_summary(&rc_before);
+		let locks_less = ah_after
+			.locked_at
+			.iter()
+			.zip(rc_before.locked_at.iter())
+			.skip(1)
+			.any(|((_, after), (_, before))| after < before);
+		// Without merged schedules the amounts match exactly.
+		let merged = ah_after.schedules < rc_before.schedules;
+		if ah_after.accounts != rc_before.accounts ||
+			ah_after.schedules > rc_before.schedules ||
+			locks_less ||
+			(!merged && ah_after != rc_before)
+		{
+			log::error!(
+				target: LOG_TARGET,
+				"Vesting schedules do not match, before: {:?}, after: {:?}",
+				rc_before,
+				ah_after
+			);
+			return Err("vesting schedules do not match the Relay Chain".into())
+		}
+		Ok(())
+	}
+}
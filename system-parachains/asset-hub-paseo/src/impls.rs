@@ -122,3 +122,110 @@ pub mod tx_payment {
 		}
 	}
 }
+
+pub mod treasury {
+	use super::*;
+	use core::marker::PhantomData;
+	use frame_support::traits::tokens::{Pay, PaymentStatus, Preservation};
+	use polkadot_runtime_common::impls::{
+		LocatableAssetConverter, VersionedLocatableAsset, VersionedLocationConverter,
+	};
+	use sp_core::Get;
+	use sp_runtime::{traits::TryConvert, DispatchError};
+	use xcm::latest::Location;
+	use xcm_executor::traits::ConvertLocation;
+
+	/// Implements [`Pay`] for [`pallet_treasury`] by transferring assets of Asset Hub from the
+	/// account returned by `A`.
+	///
+	/// The asset kind must be located on Asset Hub itself, the asset is transferred with the
+	/// `fungibles` implementation `F` (eg. [`crate::NativeAndAssets`]). The beneficiary is
+	/// converted into an account with `C`.
+	///
+	/// Payments are executed immediately, so every payment that was attempted succeeded. Spends
+	/// of the Relay Chain are only migrated as attempted if their XCM payment was confirmed, the
+	/// others are migrated as failed and are paid again.
+	pub struct LocalPay<F, A, C>(PhantomData<(F, A, C)>);
+
+	impl<F, A, C> LocalPay<F, A, C>
+	where
+		C: ConvertLocation<AccountId>,
+	{
+		/// The id of an asset of Asset Hub, or an error if the asset is located elsewhere.
+		fn local_asset_id(asset_kind: VersionedLocatableAsset) -> Result<Location, DispatchError> {
+			let asset = LocatableAssetConverter::try_convert(asset_kind)
+				.map_err(|_| DispatchError::Other("unsupported asset kind"))?;
+			if asset.location != Location::here() {
+				return Err(DispatchError::Other("asset is not located on Asset Hub"))
+			}
+			Ok(asset.asset_id.0)
+		}
+
+		/// The account of a beneficiary.
+		fn beneficiary_account(who: &VersionedLocation) -> Result<AccountId, DispatchError> {
+			let location = VersionedLocationConverter::try_convert(who)
+				.map_err(|_| DispatchError::Other("unsupported beneficiary"))?;
+			C::convert_location(&location)
+				.ok_or(DispatchError::Other("beneficiary without an account"))
+		}
+	}
+
+	impl<F, A, C> Pay for LocalPay<F, A, C>
+	where
+		F: fungibles::Mutate<AccountId, AssetId = Location, Balance = Balance>,
+		A: Get<AccountId>,
+		C: ConvertLocation<AccountId>,
+	{
+		type Balance = Balance;
+		type Beneficiary = VersionedLocation;
+		type AssetKind = VersionedLocatableAsset;
+		type Id = u64;
+		type Error = DispatchError;
+
+		fn pay(
+			who: &Self::Beneficiary,
+			asset_kind: Self::AssetKind,
+			amount: Self::Balance,
+		) -> Result<Self::Id, Self::Error> {
+			let who = Self::beneficiary_account(who)?;
+			let asset_id = Self::local_asset_id(asset_kind)?;
+			F::transfer(asset_id, &A::get(), &who, amount, Preservation::Expendable)?;
+			Ok(0)
+		}
+
+		fn check_payment(_: Self::Id) -> PaymentStatus {
+			PaymentStatus::Success
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn ensure_successful(_: &Self::Beneficiary, asset_kind: Self::AssetKind, amount: Balance) {
+			let asset_id = Self::local_asset_id(asset_kind).expect("asset kind is local");
+			let _ = F::mint_into(asset_id, &A::get(), amount);
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn ensure_concluded(_: Self::Id) {}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub mod benchmarks {
+		use super::*;
+		use pallet_treasury::ArgumentsFactory;
+		use xcm::latest::Junction::AccountId32;
+
+		/// Creates spends of DOT on Asset Hub to plain accounts.
+		pub struct LocalTreasuryArguments;
+		impl ArgumentsFactory<VersionedLocatableAsset, VersionedLocation> for LocalTreasuryArguments {
+			fn create_asset_kind(_seed: u32) -> VersionedLocatableAsset {
+				VersionedLocatableAsset::V4 {
+					location: Location::here(),
+					asset_id: Location::parent().into(),
+				}
+			}
+
+			fn create_beneficiary(seed: [u8; 32]) -> VersionedLocation {
+				VersionedLocation::V4(Location::new(0, [AccountId32 { network: None, id: seed }]))
+			}
+		}
+	}
+}
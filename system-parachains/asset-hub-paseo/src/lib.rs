@@ -59,16 +59,24 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+// Asset Hub Migration.
+pub mod ah_migration;
+
+use ah_migration::pallet_ah_migrator;
 // Genesis preset configurations.
 pub mod genesis_config_presets;
+pub mod governance;
 mod impls;
+pub mod staking;
+use staking::rc_client::pallet_rc_client;
+pub mod treasury;
 mod weights;
 pub mod xcm_config;
 
 use assets_common::{
 	foreign_creators::ForeignCreators,
 	local_and_foreign_assets::{LocalFromLeft, TargetFromLeft},
-	matching::{FromNetwork, FromSiblingParachain},
+	matching::{FromNetwork, FromSiblingParachain, ParentLocation},
 	AssetIdForTrustBackedAssetsConvert,
 };
 use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
@@ -77,7 +85,7 @@ use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, ConstU128, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, Verify},
+	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertInto, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, Perbill, Permill,
 };
@@ -87,6 +95,7 @@ use xcm_runtime_apis::{
 	fees::Error as XcmPaymentApiError,
 };
 
+use core::cmp::Ordering;
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
@@ -97,11 +106,15 @@ use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
 	genesis_builder_helper::{build_state, get_preset},
+	migrations::{FreezeChainOnFailedMigration, MigrationStatusHandler, MultiStepMigrator},
 	ord_parameter_types, parameter_types,
 	traits::{
-		fungible, fungibles, tokens::imbalance::ResolveAssetTo, AsEnsureOriginWithArg, ConstBool,
-		ConstU32, ConstU64, ConstU8, Contains, EitherOfDiverse, Equals, EverythingBut,
-		InstanceFilter, NeverEnsureOrigin, TransformOrigin, WithdrawReasons,
+		fungible::{self, HoldConsideration},
+		fungibles,
+		tokens::imbalance::ResolveAssetTo,
+		AsEnsureOriginWithArg, Bounded, ConstBool, ConstU32, ConstU64, ConstU8, Contains, EitherOf,
+		EitherOfDiverse, Equals, EverythingBut, InstanceFilter, LinearStoragePrice,
+		NeverEnsureOrigin, PrivilegeCmp, TransformOrigin, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, Weight, WeightToFee as _},
 	PalletId,
@@ -111,16 +124,24 @@ use frame_system::{
 	EnsureRoot, EnsureSigned, EnsureSignedBy,
 };
 use pallet_nfts::PalletFeatures;
+use pallet_remote_proxy::ProxyDefinition;
 use parachains_common::{
 	message_queue::*, AccountId, AuraId, AssetIdForTrustBackedAssets,
 	Balance, BlockNumber, Hash, Header, Nonce, Signature,
 };
+use paseo_runtime_constants::{
+	ahm::{
+		PortableFreezeReason, PortableGovernanceOrigin, PortableHoldReason, PortableReferendumInfo,
+	},
+	proxy::{ProxyType as RcProxyType, RemoteProxyInterface},
+};
+use polkadot_core_primitives::AccountIndex;
 
 use sp_runtime::RuntimeDebug;
 pub use system_parachains_constants::SLOT_DURATION;
 use system_parachains_constants::{
 	paseo::{consensus::*, currency::*, fee::WeightToFee},
-	AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO,
+	AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, MINUTES, NORMAL_DISPATCH_RATIO,
 };
 use xcm::{
 	latest::prelude::{AssetId, BodyId},
@@ -136,8 +157,9 @@ use xcm_config::{
 pub use sp_runtime::BuildStorage;
 
 // Polkadot imports
+use governance::{pallet_custom_origins, AuctionAdmin, Treasurer};
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
-use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
+use polkadot_runtime_common::{claims, prod_or_fast, BlockHashCount, SlowAdjustingFeeUpdate};
 
 use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
 
@@ -158,7 +180,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_004_003,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 15,
+	transaction_version: 17,
 	state_version: 1,
 };
 
@@ -200,14 +222,98 @@ impl Contains<RuntimeCall> for VestedTransferCalls {
 	}
 }
 
+/// Calls that are paused while the Relay Chain state is migrated to Asset Hub.
+///
+/// Balance transfers, staking, governance and proxy calls are paused until the migration is done.
+pub struct MigrationPausedCalls;
+impl Contains<RuntimeCall> for MigrationPausedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		AhMigrator::is_ongoing() &&
+			matches!(
+				call,
+				RuntimeCall::Balances(..) |
+					RuntimeCall::Indices(..) |
+					RuntimeCall::Vesting(..) |
+					RuntimeCall::Claims(..) |
+					RuntimeCall::PolkadotXcm(
+						pallet_xcm::Call::teleport_assets { .. } |
+							pallet_xcm::Call::limited_teleport_assets { .. } |
+							pallet_xcm::Call::reserve_transfer_assets { .. } |
+							pallet_xcm::Call::limited_reserve_transfer_assets { .. } |
+							pallet_xcm::Call::transfer_assets { .. } |
+							pallet_xcm::Call::transfer_assets_using_type_and_then { .. } |
+							pallet_xcm::Call::execute { .. }
+					) | RuntimeCall::Assets(..) |
+					RuntimeCall::ForeignAssets(..) |
+					RuntimeCall::PoolAssets(..) |
+					RuntimeCall::AssetConversion(..) |
+					RuntimeCall::Multisig(..) |
+					RuntimeCall::Proxy(..) |
+					RuntimeCall::RemoteProxyRelayChain(..) |
+					RuntimeCall::ConvictionVoting(..) |
+					RuntimeCall::Referenda(..) |
+					RuntimeCall::Whitelist(..) |
+					RuntimeCall::Preimage(..) |
+					RuntimeCall::Scheduler(..) |
+					RuntimeCall::Treasury(..) |
+					RuntimeCall::Bounties(..) |
+					RuntimeCall::ChildBounties(..) |
+					RuntimeCall::Staking(..) |
+					RuntimeCall::NominationPools(..) |
+					RuntimeCall::FastUnstake(..) |
+					RuntimeCall::VoterList(..)
+			)
+	}
+}
+
+/// Calls that are paused while a multi-block migration runs.
+///
+/// Blocks only include inherents while a migration runs, this also pauses the calls that are
+/// dispatched through XCM or the scheduler. Only the calls that are needed to build blocks, to
+/// control the migration with sudo and to keep the Asset Hub Migration and the staking messages
+/// between the chains going stay available.
+pub struct MbmPausedCalls;
+impl Contains<RuntimeCall> for MbmPausedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		MultiBlockMigrations::ongoing() &&
+			!matches!(
+				call,
+				RuntimeCall::System(..) |
+					RuntimeCall::ParachainSystem(..) |
+					RuntimeCall::Timestamp(..) |
+					RuntimeCall::MultiBlockMigrations(..) |
+					RuntimeCall::Sudo(..) |
+					// Messages of the Relay Chain that keep the Asset Hub Migration and staking
+					// going.
+					RuntimeCall::AhMigrator(..) |
+					RuntimeCall::RcClient(..)
+			)
+	}
+}
+
+/// Logs the start and the end of multi-block migrations.
+///
+/// Calls are paused by [`MbmPausedCalls`] for as long as a migration runs.
+pub struct MbmStatusHandler;
+impl MigrationStatusHandler for MbmStatusHandler {
+	fn started() {
+		log::info!(target: "runtime::mbm", "Multi-block migrations started, pausing calls");
+	}
+
+	fn completed() {
+		log::info!(target: "runtime::mbm", "Multi-block migrations completed, resuming calls");
+	}
+}
+
 // Configure FRAME pallets to include in runtime.
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = EverythingBut<VestedTransferCalls>;
+	type BaseCallFilter =
+		EverythingBut<(VestedTransferCalls, MigrationPausedCalls, MbmPausedCalls)>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type AccountId = AccountId;
 	type RuntimeCall = RuntimeCall;
-	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Lookup = (AccountIdLookup<AccountId, AccountIndex>, Indices);
 	type Nonce = Nonce;
 	type Hash = Hash;
 	type Hashing = BlakeTwo256;
@@ -227,12 +333,31 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<64>;
 	type SingleBlockMigrations = ();
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
 	type PostTransactions = ();
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = MbmMigrations;
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = MbmStatusHandler;
+	type FailedMigrationHandler = FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
+}
+
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
 	type Moment = u64;
@@ -246,6 +371,19 @@ impl pallet_authorship::Config for Runtime {
 	type EventHandler = (CollatorSelection,);
 }
 
+parameter_types! {
+	// 1/100th of the deposit of the Relay Chain, like all other deposits of system parachains.
+	pub const IndexDeposit: Balance = 10 * DOLLARS / 100;
+}
+
+impl pallet_indices::Config for Runtime {
+	type AccountIndex = AccountIndex;
+	type Currency = Balances;
+	type Deposit = IndexDeposit;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// This comes from system_parachains_constants::polkadot::currency and is the ED for all system
 	// parachains. For Asset Hub in particular, we set it to 1/10th of the amount.
@@ -266,8 +404,8 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
-	type FreezeIdentifier = ();
-	type MaxFreezes = ConstU32<0>;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<8>;
 }
 
 parameter_types! {
@@ -290,6 +428,24 @@ impl pallet_vesting::Config for Runtime {
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
+parameter_types! {
+	/// Must be the same as on the Relay Chain, the signatures of the claimers commit to it.
+	pub Prefix: &'static [u8] = b"Pay PASs to the Paseo account:";
+}
+
+/// The pre-sale claims, migrated from the Relay Chain.
+///
+/// The vesting schedules of the claims start at Relay Chain block numbers, which is also what
+/// `pallet_vesting` uses on Asset Hub.
+impl claims::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type VestingSchedule = Vesting;
+	type Prefix = Prefix;
+	/// Only Root can move a claim.
+	type MoveClaimOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::polkadot_runtime_common_claims::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	/// Relay Chain `TransactionByteFee` / 10
 	pub const TransactionByteFee: Balance = system_parachains_constants::paseo::fee::TRANSACTION_BYTE_FEE;
@@ -455,19 +611,48 @@ parameter_types! {
 )]
 pub enum ProxyType {
 	/// Fully permissioned proxy. Can execute any call on behalf of _proxied_.
+	#[codec(index = 0)]
 	Any,
 	/// Can execute any call that does not transfer funds or assets.
+	#[codec(index = 1)]
 	NonTransfer,
 	/// Proxy with the ability to reject time-delay proxy announcements.
+	#[codec(index = 2)]
 	CancelProxy,
 	/// Assets proxy. Can execute any call from `assets`, **including asset transfers**.
+	#[codec(index = 3)]
 	Assets,
 	/// Owner proxy. Can execute calls related to asset ownership.
+	#[codec(index = 4)]
 	AssetOwner,
 	/// Asset manager. Can execute calls related to asset management.
+	#[codec(index = 5)]
 	AssetManager,
 	/// Collator selection proxy. Can execute calls related to collator selection mechanism.
+	#[codec(index = 6)]
 	Collator,
+	// New variants introduced by the Asset Hub Migration from the Relay Chain. Their indices
+	// start at 100, so that no index of the Relay Chain decodes to one of them.
+	/// Governance proxy. Can execute calls related to governance and the treasury.
+	#[codec(index = 100)]
+	Governance,
+	/// Staking proxy. Can execute calls related to staking and nomination pools.
+	#[codec(index = 101)]
+	Staking,
+	/// Nomination pools proxy. Can execute calls of nomination pools.
+	#[codec(index = 102)]
+	NominationPools,
+	/// Auction proxy of the Relay Chain.
+	///
+	/// There are no auctions on Asset Hub, the proxy can not execute any call.
+	#[codec(index = 103)]
+	Auction,
+	/// Para registration proxy of the Relay Chain.
+	///
+	/// There is no para registration on Asset Hub, the proxy can only batch calls and remove
+	/// proxies.
+	#[codec(index = 104)]
+	ParaRegistration,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -485,6 +670,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Assets { .. } |
 					RuntimeCall::Nfts { .. } |
 					RuntimeCall::Uniques { .. } |
+					RuntimeCall::Indices(pallet_indices::Call::transfer { .. }) |
 					// We allow calling `vest` and merging vesting schedules, but obviously not
 					// vested transfers.
 					RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { .. })
@@ -575,6 +761,34 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
 			),
+			ProxyType::Governance => matches!(
+				c,
+				RuntimeCall::Treasury(..) |
+					RuntimeCall::Bounties(..) |
+					RuntimeCall::Utility(..) |
+					RuntimeCall::ChildBounties(..) |
+					RuntimeCall::ConvictionVoting(..) |
+					RuntimeCall::Referenda(..) |
+					RuntimeCall::Whitelist(..)
+			),
+			ProxyType::Staking => matches!(
+				c,
+				RuntimeCall::Staking(..) |
+					RuntimeCall::Utility(..) |
+					RuntimeCall::FastUnstake(..) |
+					RuntimeCall::VoterList(..) |
+					RuntimeCall::NominationPools(..)
+			),
+			ProxyType::NominationPools =>
+				matches!(c, RuntimeCall::NominationPools(..) | RuntimeCall::Utility(..)),
+			ProxyType::Auction => false,
+			ProxyType::ParaRegistration => matches!(
+				c,
+				RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
+					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
+			),
 		}
 	}
 
@@ -586,6 +800,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Assets, ProxyType::AssetOwner) => true,
 			(ProxyType::Assets, ProxyType::AssetManager) => true,
 			(ProxyType::NonTransfer, ProxyType::Collator) => true,
+			(ProxyType::NonTransfer, ProxyType::Governance) => true,
+			(ProxyType::NonTransfer, ProxyType::Staking) => true,
+			(ProxyType::NonTransfer, ProxyType::NominationPools) => true,
+			(ProxyType::Staking, ProxyType::NominationPools) => true,
 			_ => false,
 		}
 	}
@@ -606,6 +824,46 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
+/// Converts the proxy types of the Relay Chain into the proxy types of Asset Hub.
+pub struct RcToAhProxyType;
+impl Convert<RcProxyType, Option<ProxyType>> for RcToAhProxyType {
+	fn convert(proxy_type: RcProxyType) -> Option<ProxyType> {
+		Some(match proxy_type {
+			RcProxyType::Any => ProxyType::Any,
+			RcProxyType::NonTransfer => ProxyType::NonTransfer,
+			RcProxyType::Governance => ProxyType::Governance,
+			RcProxyType::Staking => ProxyType::Staking,
+			RcProxyType::CancelProxy => ProxyType::CancelProxy,
+			RcProxyType::Auction => ProxyType::Auction,
+			RcProxyType::NominationPools => ProxyType::NominationPools,
+			RcProxyType::ParaRegistration => ProxyType::ParaRegistration,
+		})
+	}
+}
+
+/// Converts the proxy definitions of the Relay Chain into proxy definitions of Asset Hub.
+pub struct ProxyDefinitionConverter;
+impl
+	Convert<
+		ProxyDefinition<AccountId, RcProxyType, BlockNumber>,
+		Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>>,
+	> for ProxyDefinitionConverter
+{
+	fn convert(
+		definition: ProxyDefinition<AccountId, RcProxyType, BlockNumber>,
+	) -> Option<ProxyDefinition<AccountId, ProxyType, BlockNumber>> {
+		let proxy_type = RcToAhProxyType::convert(definition.proxy_type)?;
+		Some(ProxyDefinition { delegate: definition.delegate, proxy_type, delay: definition.delay })
+	}
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	// Proofs against relay blocks of the last two hours can be used.
+	type MaxStorageRootsToKeep = ConstU32<{ 2 * HOURS }>;
+	type RemoteProxy = RemoteProxyInterface<ProxyType, ProxyDefinitionConverter>;
+	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
@@ -614,7 +872,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RemoteProxyRelayChain;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
@@ -732,6 +990,14 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 }
 
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
 impl pallet_aura::Config for Runtime {
 	type AuthorityId = AuraId;
 	type DisabledValidators = ();
@@ -964,12 +1230,226 @@ impl pallet_asset_conversion::Config for Runtime {
 	>;
 }
 
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		RuntimeBlockWeights::get().max_block;
+	pub const MaxScheduledPerBlock: u32 = 50;
+}
+
+/// Compares the privilege of origins inside the scheduler.
+pub struct OriginPrivilegeCmp;
+
+impl PrivilegeCmp<OriginCaller> for OriginPrivilegeCmp {
+	fn cmp_privilege(left: &OriginCaller, right: &OriginCaller) -> Option<Ordering> {
+		if left == right {
+			return Some(Ordering::Equal);
+		}
+
+		match (left, right) {
+			// Root is greater than anything.
+			(OriginCaller::system(frame_system::RawOrigin::Root), _) => Some(Ordering::Greater),
+			// For every other origin we don't care, as they are not used for `ScheduleOrigin`.
+			_ => None,
+		}
+	}
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	// The governance tracks that administer auctions and the treasury on the Relay Chain kept
+	// their origins on Asset Hub, so that their scheduled tasks can be migrated. The Treasurer
+	// also schedules recurring payments.
+	type ScheduleOrigin = EitherOf<EitherOf<EnsureRoot<AccountId>, AuctionAdmin>, Treasurer>;
+	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
+	type OriginPrivilegeCmp = OriginPrivilegeCmp;
+	type Preimages = Preimage;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = system_para_deposit(2, 64);
+	pub const PreimageByteDeposit: Balance = system_para_deposit(0, 1);
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+	type WeightInfo = weights::pallet_preimage::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+/// Maps the portable hold reasons of the Relay Chain to the hold reasons of this runtime.
+pub struct PortableToAhHoldReason;
+impl Convert<PortableHoldReason, RuntimeHoldReason> for PortableToAhHoldReason {
+	fn convert(reason: PortableHoldReason) -> RuntimeHoldReason {
+		match reason {
+			PortableHoldReason::Preimage =>
+				RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage),
+			PortableHoldReason::StateTrieMigration => RuntimeHoldReason::StateTrieMigration(
+				pallet_state_trie_migration::HoldReason::SlashForMigrate,
+			),
+			PortableHoldReason::DelegatedStaking => RuntimeHoldReason::DelegatedStaking(
+				pallet_delegated_staking::HoldReason::StakingDelegation,
+			),
+		}
+	}
+}
+
+/// Maps the portable freeze reasons of the Relay Chain to the freeze reasons of this runtime.
+pub struct PortableToAhFreezeReason;
+impl Convert<PortableFreezeReason, RuntimeFreezeReason> for PortableToAhFreezeReason {
+	fn convert(reason: PortableFreezeReason) -> RuntimeFreezeReason {
+		match reason {
+			PortableFreezeReason::NominationPools => RuntimeFreezeReason::NominationPools(
+				pallet_nomination_pools::FreezeReason::PoolMinBalance,
+			),
+		}
+	}
+}
+
+/// Maps the portable origins of Relay Chain referenda to the origins of this runtime.
+pub struct PortableToAhGovernanceOrigin;
+impl Convert<PortableGovernanceOrigin, OriginCaller> for PortableToAhGovernanceOrigin {
+	fn convert(origin: PortableGovernanceOrigin) -> OriginCaller {
+		use pallet_custom_origins::Origin;
+		match origin {
+			PortableGovernanceOrigin::Root => frame_system::RawOrigin::Root.into(),
+			PortableGovernanceOrigin::StakingAdmin => Origin::StakingAdmin.into(),
+			PortableGovernanceOrigin::Treasurer => Origin::Treasurer.into(),
+			PortableGovernanceOrigin::FellowshipAdmin => Origin::FellowshipAdmin.into(),
+			PortableGovernanceOrigin::GeneralAdmin => Origin::GeneralAdmin.into(),
+			PortableGovernanceOrigin::AuctionAdmin => Origin::AuctionAdmin.into(),
+			PortableGovernanceOrigin::LeaseAdmin => Origin::LeaseAdmin.into(),
+			PortableGovernanceOrigin::ReferendumCanceller => Origin::ReferendumCanceller.into(),
+			PortableGovernanceOrigin::ReferendumKiller => Origin::ReferendumKiller.into(),
+			PortableGovernanceOrigin::SmallTipper => Origin::SmallTipper.into(),
+			PortableGovernanceOrigin::BigTipper => Origin::BigTipper.into(),
+			PortableGovernanceOrigin::SmallSpender => Origin::SmallSpender.into(),
+			PortableGovernanceOrigin::MediumSpender => Origin::MediumSpender.into(),
+			PortableGovernanceOrigin::BigSpender => Origin::BigSpender.into(),
+			PortableGovernanceOrigin::WhitelistedCaller => Origin::WhitelistedCaller.into(),
+			PortableGovernanceOrigin::WishForChange => Origin::WishForChange.into(),
+		}
+	}
+}
+
+/// Maps the portable referenda of the Relay Chain to the referenda of this runtime.
+pub struct PortableToAhReferendumInfo;
+impl Convert<PortableReferendumInfo, pallet_referenda::ReferendumInfoOf<Runtime, ()>>
+	for PortableToAhReferendumInfo
+{
+	fn convert(info: PortableReferendumInfo) -> pallet_referenda::ReferendumInfoOf<Runtime, ()> {
+		use pallet_conviction_voting::Tally;
+		use pallet_referenda::{ReferendumInfo, ReferendumStatus};
+		match info {
+			ReferendumInfo::Ongoing(status) => ReferendumInfo::Ongoing(ReferendumStatus {
+				track: status.track,
+				origin: PortableToAhGovernanceOrigin::convert(status.origin),
+				// The proposal is still the Relay Chain call, the migrator translates it.
+				proposal: match status.proposal {
+					Bounded::Legacy { hash, .. } =>
+						Bounded::Legacy { hash, dummy: Default::default() },
+					Bounded::Inline(call) => Bounded::Inline(call),
+					Bounded::Lookup { hash, len } => Bounded::Lookup { hash, len },
+				},
+				enactment: status.enactment,
+				submitted: status.submitted,
+				submission_deposit: status.submission_deposit,
+				decision_deposit: status.decision_deposit,
+				deciding: status.deciding,
+				tally: Tally::from_parts(
+					status.tally.ayes,
+					status.tally.nays,
+					status.tally.support,
+				),
+				in_queue: status.in_queue,
+				alarm: None,
+			}),
+			ReferendumInfo::Approved(moment, submission, decision) =>
+				ReferendumInfo::Approved(moment, submission, decision),
+			ReferendumInfo::Rejected(moment, submission, decision) =>
+				ReferendumInfo::Rejected(moment, submission, decision),
+			ReferendumInfo::Cancelled(moment, submission, decision) =>
+				ReferendumInfo::Cancelled(moment, submission, decision),
+			ReferendumInfo::TimedOut(moment, submission, decision) =>
+				ReferendumInfo::TimedOut(moment, submission, decision),
+			ReferendumInfo::Killed(moment) => ReferendumInfo::Killed(moment),
+		}
+	}
+}
+
+/// Maps the pallet and call indices of calls of the Relay Chain to the indices of the same calls
+/// on Asset Hub.
+///
+/// Only calls whose arguments have the same encoding and meaning on both chains are mapped. Calls
+/// that contain other calls, origins or locations relative to the Relay Chain are not.
+pub struct RcToAhCallIndex;
+impl Convert<(u8, u8), Option<(u8, u8)>> for RcToAhCallIndex {
+	fn convert((pallet, call): (u8, u8)) -> Option<(u8, u8)> {
+		use frame_support::traits::PalletInfoAccess;
+		let pallet = match (pallet, call) {
+			// `remark` and `remark_with_event`.
+			(0, 0 | 7) => System::index(),
+			// Transfers and `force_unreserve`.
+			(5, 0 | 2..=5) => Balances::index(),
+			// All calls except `spend`, whose asset kind is relative to the Relay Chain.
+			(19, 3 | 4 | 6..=8) => Treasury::index(),
+			// `cancel` and `kill`.
+			(21, 3 | 4) => Referenda::index(),
+			// `whitelist_call` and `remove_whitelisted_call`. The dispatches execute a call of the
+			// Relay Chain from its preimage.
+			(23, 0 | 1) => Whitelist::index(),
+			(34, 0..=8) => Bounties::index(),
+			(38, 0..=6) => ChildBounties::index(),
+			_ => return None,
+		};
+		Some((pallet as u8, call))
+	}
+}
+
+parameter_types! {
+	/// Upper bound of the weight of `RcMigrator::receive_processed` on the Relay Chain, which
+	/// reads one and writes two storage items.
+	pub const RcReportWeight: Weight = Weight::from_parts(250_000_000, 10_000);
+}
+
+impl pallet_ah_migrator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RcOrigin = EnsureXcm<Equals<ParentLocation>>;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type PortableHoldReason = PortableToAhHoldReason;
+	type PortableFreezeReason = PortableToAhFreezeReason;
+	type PortableReferendumInfo = PortableToAhReferendumInfo;
+	type RcProxyType = RcToAhProxyType;
+	type PortableSchedulerOrigin = PortableToAhGovernanceOrigin;
+	type RcCallIndex = RcToAhCallIndex;
+	type RcBlockNumberProvider = cumulus_pallet_parachain_system::RelaychainDataProvider<Runtime>;
+	type CheckingAccount = xcm_config::CheckingAccount;
+	type RcTreasuryAccount = xcm_config::RelayTreasuryPalletAccount;
+	type TreasuryAssets = NativeAndAssets;
+	type SendXcm = xcm_config::XcmRouter;
+	type RcReportWeight = RcReportWeight;
+	type WeightInfo = weights::pallet_ah_migrator::WeightInfo<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime
@@ -980,12 +1460,17 @@ construct_runtime!(
 		// RandomnessCollectiveFlip = 2 removed
 		Timestamp: pallet_timestamp = 3,
 		ParachainInfo: parachain_info = 4,
+		Preimage: pallet_preimage = 5,
+		Scheduler: pallet_scheduler = 6,
+		MultiBlockMigrations: pallet_migrations = 7,
 
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
 		TransactionPayment: pallet_transaction_payment = 11,
+		Indices: pallet_indices = 12,
 		AssetTxPayment: pallet_asset_conversion_tx_payment = 13,
 		Vesting: pallet_vesting = 14,
+		Claims: claims = 15,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -1006,6 +1491,7 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		RemoteProxyRelayChain: pallet_remote_proxy = 43,
 
 		// The main stage.
 		Assets: pallet_assets::<Instance1> = 50,
@@ -1015,16 +1501,40 @@ construct_runtime!(
 		PoolAssets: pallet_assets::<Instance3> = 54,
 		AssetConversion: pallet_asset_conversion = 55,
 
+		// Treasury stuff.
+		Treasury: pallet_treasury = 60,
+		Bounties: pallet_bounties = 61,
+		ChildBounties: pallet_child_bounties = 62,
+		AssetRate: pallet_asset_rate = 63,
+
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 70,
 
+		// OpenGov stuff.
+		ConvictionVoting: pallet_conviction_voting = 80,
+		Referenda: pallet_referenda = 81,
+		Origins: pallet_custom_origins = 82,
+		Whitelist: pallet_whitelist = 83,
+
+		// Staking stuff.
+		Staking: pallet_staking = 90,
+		NominationPools: pallet_nomination_pools = 91,
+		FastUnstake: pallet_fast_unstake = 92,
+		VoterList: pallet_bags_list::<Instance1> = 93,
+		DelegatedStaking: pallet_delegated_staking = 94,
+		ElectionProviderMultiPhase: pallet_election_provider_multi_phase = 95,
+		RcClient: pallet_rc_client = 96,
+
+		// Asset Hub Migration.
+		AhMigrator: pallet_ah_migrator = 254,
+
 		// Sudo.
 		Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>, Config<T>} = 255,
 	}
 );
 
 /// The address format for describing accounts.
-pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
+pub type Address = sp_runtime::MultiAddress<AccountId, AccountIndex>;
 /// Block type as expected by this runtime.
 pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 /// A Block signed with a Justification
@@ -1041,6 +1551,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+	claims::PrevalidateAttests<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1053,6 +1564,12 @@ pub type Migrations = (
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
 
+/// Multi-block migrations, run by `pallet_migrations` in the blocks after a runtime upgrade.
+///
+/// Each migration implements `SteppedMigration` and stores its cursor between the steps, so that
+/// storage moves that do not fit into one block are spread over as many blocks as needed.
+pub type MbmMigrations = ();
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1069,19 +1586,34 @@ mod benches {
 
 	frame_benchmarking::define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
+		[pallet_ah_migrator, AhMigrator]
 		[pallet_assets, Local]
 		[pallet_assets, Foreign]
 		[pallet_assets, Pool]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_balances, Balances]
+		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nfts, Nfts]
 		[pallet_proxy, Proxy]
+		[pallet_rc_client, RcClient]
+		[pallet_remote_proxy, RemoteProxyRelayChain]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_uniques, Uniques]
 		[pallet_utility, Utility]
 		[pallet_vesting, Vesting]
+		[polkadot_runtime_common::claims, Claims]
+		[pallet_conviction_voting, ConvictionVoting]
+		[pallet_preimage, Preimage]
+		[pallet_referenda, Referenda]
+		[pallet_scheduler, Scheduler]
+		[pallet_whitelist, Whitelist]
+		[pallet_treasury, Treasury]
+		[pallet_bounties, Bounties]
+		[pallet_child_bounties, ChildBounties]
+		[pallet_asset_rate, AssetRate]
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_parachain_system, ParachainSystem]
@@ -1414,6 +1946,22 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl paseo_runtime_constants::ahm::AhMigrationApi<Block> for Runtime {
+		fn translated_account(who: AccountId) -> AccountId {
+			paseo_runtime_constants::ahm::translate_account(who)
+		}
+
+		fn para_sovereign_account(para_id: u32) -> AccountId {
+			paseo_runtime_constants::ahm::sibling_sovereign_account(para_id.into())
+		}
+	}
+
+	impl paseo_runtime_constants::ahm::AhMigrationProgressApi<Block> for Runtime {
+		fn migration_progress() -> paseo_runtime_constants::ahm::MigrationProgress {
+			AhMigrator::progress()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -1870,6 +2418,848 @@ mod tests {
 		assert_eq!(fee, CENTS, "20kb maps to CENT");
 	}
 
+	/// Relay Chain block numbers are mapped to the Asset Hub blocks expected at the same time.
+	#[test]
+	fn relay_block_numbers_are_translated() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			cumulus_pallet_parachain_system::LastRelayChainBlockNumber::<Runtime>::put(1_000);
+			System::set_block_number(100);
+
+			assert_eq!(AhMigrator::rc_to_ah_block(1_000), 100);
+			assert_eq!(AhMigrator::rc_to_ah_block(1_200), 200);
+			assert_eq!(AhMigrator::rc_to_ah_block(900), 50);
+			assert_eq!(AhMigrator::rc_to_ah_block(0), 0);
+			assert_eq!(AhMigrator::rc_to_ah_period(28 * 14_400), 28 * DAYS);
+		});
+	}
+
+	/// Integrated and failed accounts are reported by the progress of the migration.
+	#[test]
+	fn migration_progress_counts_integrated_accounts() {
+		use frame_support::{assert_noop, assert_ok};
+		use paseo_runtime_constants::ahm::{MigratedPallet, PalletProgress, RcAccount};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let account = |who: [u8; 32], free: Balance| RcAccount {
+				who: who.into(),
+				free,
+				reserved: 0,
+				frozen: 0,
+				holds: vec![],
+				freezes: vec![],
+				locks: vec![],
+				unnamed_reserve: 0,
+			};
+			// The second account is below the existential deposit and can not be minted.
+			AhMigrator::do_receive_accounts(vec![account([1; 32], UNITS), account([2; 32], 1)]);
+
+			let progress = AhMigrator::progress();
+			assert_eq!(progress.moved, UNITS);
+			assert_eq!(
+				progress.items[0],
+				(MigratedPallet::Accounts, PalletProgress { sent: 0, acknowledged: 1, failed: 1 })
+			);
+			assert!(progress.last_error.is_some());
+
+			// The balance of the failed account can be restored by governance.
+			let (who, dest) = (AccountId::from([2; 32]), AccountId::from([3; 32]));
+			assert_eq!(pallet_ah_migrator::UnintegratedBalance::<Runtime>::get(&who), 1);
+			assert_noop!(
+				AhMigrator::restore_unintegrated_balance(
+					RuntimeOrigin::signed(dest.clone()),
+					who.clone(),
+					dest.clone()
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			Balances::set_balance(&dest, UNITS);
+			assert_ok!(AhMigrator::restore_unintegrated_balance(
+				RuntimeOrigin::root(),
+				who.clone(),
+				dest.clone()
+			));
+			assert_eq!(Balances::free_balance(&dest), UNITS + 1);
+			assert_eq!(AhMigrator::progress().moved, UNITS + 1);
+			assert!(!pallet_ah_migrator::UnintegratedBalance::<Runtime>::contains_key(&who));
+		});
+	}
+
+	/// Accounts whose reserves or freezes are not re-created as on the Relay Chain fail.
+	#[test]
+	fn integrated_accounts_match_the_relay_balances() {
+		use frame_support::assert_ok;
+		use paseo_runtime_constants::ahm::{PortableLock, PortableLockReasons, RcAccount};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let account = |who: [u8; 32], reserved: Balance, frozen: Balance| RcAccount {
+				who: who.into(),
+				free: 10 * UNITS,
+				reserved,
+				frozen,
+				holds: vec![],
+				freezes: vec![],
+				locks: vec![PortableLock {
+					id: *b"vesting ",
+					amount: UNITS,
+					reasons: PortableLockReasons::All,
+				}],
+				unnamed_reserve: 2 * UNITS,
+			};
+
+			assert_ok!(AhMigrator::integrate_account(account([1; 32], 2 * UNITS, UNITS)));
+			assert_eq!(
+				AhMigrator::integrate_account(account([2; 32], 3 * UNITS, UNITS)),
+				Err(pallet_ah_migrator::Error::<Runtime>::ReservedMismatch.into())
+			);
+			assert_eq!(
+				AhMigrator::integrate_account(account([3; 32], 2 * UNITS, 2 * UNITS)),
+				Err(pallet_ah_migrator::Error::<Runtime>::FrozenMismatch.into())
+			);
+		});
+	}
+
+	/// Relay Chain proxies keep their delegate, type and delay on Asset Hub.
+	#[test]
+	fn relay_proxy_definitions_are_converted() {
+		let definition = |proxy_type| ProxyDefinition {
+			delegate: AccountId::from([1; 32]),
+			proxy_type,
+			delay: 10,
+		};
+
+		for (rc, ah) in [
+			(RcProxyType::Any, ProxyType::Any),
+			(RcProxyType::NonTransfer, ProxyType::NonTransfer),
+			(RcProxyType::Governance, ProxyType::Governance),
+			(RcProxyType::Staking, ProxyType::Staking),
+			(RcProxyType::CancelProxy, ProxyType::CancelProxy),
+			(RcProxyType::Auction, ProxyType::Auction),
+			(RcProxyType::NominationPools, ProxyType::NominationPools),
+			(RcProxyType::ParaRegistration, ProxyType::ParaRegistration),
+		] {
+			assert_eq!(ProxyDefinitionConverter::convert(definition(rc)), Some(definition(ah)));
+		}
+	}
+
+	/// Scheduled calls of the Relay Chain are translated by their pallet and call indices.
+	#[test]
+	fn relay_calls_are_translated() {
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: AccountId::from([1; 32]).into(),
+			value: UNITS,
+		});
+		let mut encoded = call.encode();
+
+		// `Balances` of the Relay Chain.
+		encoded[0] = 5;
+		assert_eq!(AhMigrator::translate_rc_call(&encoded), Some(call));
+		// `Utility` of the Relay Chain, whose calls contain other calls.
+		encoded[0] = 26;
+		assert_eq!(AhMigrator::translate_rc_call(&encoded), None);
+	}
+
+	/// Inline proposals of ongoing Relay Chain referenda are translated and can be enacted, the
+	/// referenda of untranslatable proposals and of missing preimages are cancelled.
+	#[test]
+	fn migrated_inline_proposals_are_translated_and_enacted() {
+		use frame_support::traits::{Bounded, Hooks, QueryPreimage};
+		use pallet_referenda::{Deposit, ReferendumInfo, ReferendumStatus};
+		use paseo_runtime_constants::ahm::{
+			MigratedPallet, MigrationStage, PortableGovernanceOrigin, RcReferendum,
+		};
+		use sp_runtime::traits::Dispatchable;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			System::set_block_number(1);
+			let (alice, bob) = (AccountId::from([1; 32]), AccountId::from([2; 32]));
+			Balances::set_balance(&alice, 10 * UNITS);
+			let call = RuntimeCall::Balances(pallet_balances::Call::force_transfer {
+				source: alice.clone().into(),
+				dest: bob.clone().into(),
+				value: UNITS,
+			});
+			let rc_call = |pallet: u8| {
+				let mut encoded = call.encode();
+				encoded[0] = pallet;
+				Bounded::Inline(encoded.try_into().unwrap())
+			};
+			let referendum = |index, proposal| RcReferendum {
+				index,
+				info: ReferendumInfo::Ongoing(ReferendumStatus {
+					track: 0,
+					origin: PortableGovernanceOrigin::Root,
+					proposal,
+					enactment: frame_support::traits::schedule::DispatchTime::After(10),
+					submitted: 0,
+					submission_deposit: Deposit { who: alice.clone(), amount: 0 },
+					decision_deposit: None,
+					deciding: None,
+					tally: pallet_conviction_voting::Tally::from_parts(0, 0, 0),
+					in_queue: false,
+					alarm: None,
+				}),
+				metadata: None,
+			};
+
+			// `Balances` and `Utility` of the Relay Chain.
+			AhMigrator::do_receive_referenda(vec![
+				referendum(0, rc_call(5)),
+				referendum(1, rc_call(26)),
+				referendum(2, Bounded::Lookup { hash: Hash::repeat_byte(9), len: 10 }),
+			]);
+
+			let Some(ReferendumInfo::Ongoing(status)) =
+				pallet_referenda::ReferendumInfoFor::<Runtime>::get(0)
+			else {
+				panic!("the referendum is ongoing")
+			};
+			let (proposal, _) = <Preimage as QueryPreimage>::realize(&status.proposal).unwrap();
+			assert_eq!(proposal, call);
+			assert!(proposal.dispatch(RuntimeOrigin::root()).is_ok());
+			assert_eq!(Balances::free_balance(&bob), UNITS);
+
+			assert!(matches!(
+				pallet_referenda::ReferendumInfoFor::<Runtime>::get(1),
+				Some(ReferendumInfo::Cancelled(..))
+			));
+
+			// The preimage of the third proposal is never migrated.
+			pallet_ah_migrator::AhMigrationStage::<Runtime>::put(MigrationStage::Ongoing(
+				MigratedPallet::Preimage,
+			));
+			AhMigrator::on_initialize(1);
+			assert!(matches!(
+				pallet_referenda::ReferendumInfoFor::<Runtime>::get(2),
+				Some(ReferendumInfo::Ongoing(..))
+			));
+			pallet_ah_migrator::AhMigrationStage::<Runtime>::put(MigrationStage::Ongoing(
+				MigratedPallet::Scheduler,
+			));
+			AhMigrator::on_initialize(1);
+			assert!(matches!(
+				pallet_referenda::ReferendumInfoFor::<Runtime>::get(2),
+				Some(ReferendumInfo::Cancelled(..))
+			));
+			assert_eq!(pallet_ah_migrator::RcPendingProposals::<Runtime>::iter().count(), 0);
+		});
+	}
+
+	/// The bonded accounts of migrated pools keep staking and nominating on Asset Hub.
+	#[test]
+	fn pool_stake_is_integrated() {
+		use frame_election_provider_support::SortedListProvider;
+		use frame_support::assert_ok;
+		use paseo_runtime_constants::ahm::{
+			PortableNominations, PortablePoolStake, PortableRewardDestination,
+			PortableStakingLedger, RcNomPoolsMessage,
+		};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let stash = pallet_nomination_pools::Pallet::<Runtime>::generate_bonded_account(1);
+			let stake = PortablePoolStake {
+				ledger: PortableStakingLedger {
+					stash: stash.clone(),
+					total: 100 * UNITS,
+					active: 100 * UNITS,
+					unlocking: vec![],
+					legacy_claimed_rewards: vec![],
+				},
+				payee: Some(PortableRewardDestination::Account(stash.clone())),
+				nominations: Some(PortableNominations {
+					targets: vec![AccountId::from([1; 32])],
+					submitted_in: 0,
+					suppressed: false,
+				}),
+			};
+
+			assert_ok!(AhMigrator::integrate_nom_pools_message(RcNomPoolsMessage::PoolStake(
+				stake
+			)));
+
+			assert_eq!(pallet_staking::Bonded::<Runtime>::get(&stash), Some(stash.clone()));
+			assert_eq!(
+				pallet_staking::Ledger::<Runtime>::get(&stash).map(|ledger| ledger.active),
+				Some(100 * UNITS)
+			);
+			assert!(pallet_staking::VirtualStakers::<Runtime>::contains_key(&stash));
+			assert!(pallet_staking::Nominators::<Runtime>::contains_key(&stash));
+			assert!(<Runtime as pallet_staking::Config>::VoterList::contains(&stash));
+		});
+	}
+
+	/// Migrated indices resolve to their Relay Chain owner, unless claimed on Asset Hub.
+	#[test]
+	fn migrated_indices_keep_their_owner() {
+		use frame_support::{
+			assert_ok,
+			traits::{Currency, ReservableCurrency},
+		};
+		use paseo_runtime_constants::ahm::RcIndex;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (alice, bob) = (AccountId::from([1; 32]), AccountId::from([2; 32]));
+			for who in [&alice, &bob] {
+				Balances::make_free_balance_be(who, UNITS);
+				assert_ok!(Balances::reserve(who, 10 * CENTS));
+			}
+			pallet_indices::Accounts::<Runtime>::insert(7, (bob.clone(), 10 * CENTS, false));
+
+			let index = |index, who: &AccountId| RcIndex {
+				index,
+				who: who.clone(),
+				deposit: 10 * CENTS,
+				frozen: true,
+			};
+			AhMigrator::do_receive_indices(vec![index(42, &alice), index(7, &alice)]);
+
+			assert_eq!(Indices::lookup_index(42), Some(alice.clone()));
+			assert_eq!(pallet_indices::Accounts::<Runtime>::get(42).map(|(_, _, f)| f), Some(true));
+			// The index was claimed on Asset Hub, the deposit of the Relay Chain owner is refunded.
+			assert_eq!(Indices::lookup_index(7), Some(bob));
+			assert_eq!(Balances::reserved_balance(&alice), 0);
+		});
+	}
+
+	/// Calls can address accounts by their migrated index.
+	#[test]
+	fn migrated_indices_resolve_in_calls() {
+		use frame_support::{
+			assert_noop, assert_ok,
+			traits::{Currency, ReservableCurrency},
+		};
+		use paseo_runtime_constants::ahm::RcIndex;
+		use sp_runtime::{DispatchError, MultiAddress};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (alice, bob) = (AccountId::from([1; 32]), AccountId::from([2; 32]));
+			Balances::make_free_balance_be(&alice, UNITS);
+			assert_ok!(Balances::reserve(&alice, 10 * CENTS));
+			Balances::make_free_balance_be(&bob, UNITS);
+			AhMigrator::do_receive_indices(vec![RcIndex {
+				index: 42,
+				who: alice.clone(),
+				deposit: 10 * CENTS,
+				frozen: false,
+			}]);
+
+			assert_ok!(Balances::transfer_keep_alive(
+				RuntimeOrigin::signed(bob.clone()),
+				MultiAddress::Index(42),
+				10 * CENTS
+			));
+			assert_eq!(Balances::free_balance(&alice), UNITS);
+			assert_ok!(Balances::transfer_keep_alive(
+				RuntimeOrigin::signed(bob.clone()),
+				MultiAddress::Id(alice.clone()),
+				10 * CENTS
+			));
+			assert_eq!(Balances::free_balance(&alice), UNITS + 10 * CENTS);
+			assert_noop!(
+				Balances::transfer_keep_alive(
+					RuntimeOrigin::signed(bob),
+					MultiAddress::Index(7),
+					10 * CENTS
+				),
+				DispatchError::CannotLookup
+			);
+		});
+	}
+
+	/// Crowdloan contributions are paid back from the crowdloan once its lease ended.
+	#[test]
+	fn crowdloan_reserves_are_unwound() {
+		use frame_support::{
+			assert_noop, assert_ok,
+			traits::{Currency, ReservableCurrency},
+		};
+		use paseo_runtime_constants::ahm::RcCrowdloanMessage;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let [crowdloan, contributor, depositor, holder, unmigrated] =
+				[1u8, 2, 3, 4, 5].map(|i| AccountId::from([i; 32]));
+			for (who, reserved) in
+				[(&crowdloan, 100 * UNITS), (&depositor, UNITS), (&holder, UNITS)]
+			{
+				Balances::make_free_balance_be(who, reserved + UNITS);
+				assert_ok!(Balances::reserve(who, reserved));
+				pallet_ah_migrator::RcReserve::<Runtime>::insert(who, reserved);
+			}
+			// The account of this lease holder failed to migrate, its reserve is from Asset Hub.
+			Balances::make_free_balance_be(&unmigrated, 2 * UNITS);
+			assert_ok!(Balances::reserve(&unmigrated, UNITS));
+			let para_id = ParaId::from(2000);
+
+			for message in [
+				RcCrowdloanMessage::LeaseReserve {
+					unreserve_block: 0,
+					para_id,
+					who: crowdloan.clone(),
+					amount: 100 * UNITS,
+				},
+				RcCrowdloanMessage::CrowdloanContribution {
+					withdraw_block: 0,
+					para_id,
+					contributor: contributor.clone(),
+					crowdloan_account: crowdloan.clone(),
+					amount: 100 * UNITS,
+				},
+				RcCrowdloanMessage::CrowdloanDeposit {
+					unreserve_block: 0,
+					para_id,
+					depositor: depositor.clone(),
+					amount: UNITS,
+				},
+				// The lease of another parachain did not end yet.
+				RcCrowdloanMessage::LeaseReserve {
+					unreserve_block: 1,
+					para_id: ParaId::from(2001),
+					who: holder.clone(),
+					amount: UNITS,
+				},
+				RcCrowdloanMessage::LeaseReserve {
+					unreserve_block: 0,
+					para_id: ParaId::from(2002),
+					who: unmigrated.clone(),
+					amount: UNITS,
+				},
+			] {
+				AhMigrator::integrate_crowdloan_message(message);
+			}
+
+			assert_noop!(
+				AhMigrator::do_unreserve_lease_deposit(1, ParaId::from(2001), holder.clone()),
+				pallet_ah_migrator::Error::<Runtime>::NotYetReleased
+			);
+			assert_noop!(
+				AhMigrator::do_unreserve_crowdloan_deposit(0, para_id, depositor.clone()),
+				pallet_ah_migrator::Error::<Runtime>::ContributionsRemaining
+			);
+
+			assert_ok!(AhMigrator::do_withdraw_crowdloan_contribution(
+				0,
+				para_id,
+				contributor.clone()
+			));
+			assert_eq!(Balances::free_balance(&contributor), 100 * UNITS);
+			assert_eq!(Balances::total_balance(&crowdloan), UNITS);
+
+			assert_ok!(AhMigrator::do_unreserve_crowdloan_deposit(0, para_id, depositor.clone()));
+			assert_eq!(Balances::reserved_balance(&depositor), 0);
+			assert_eq!(Balances::reserved_balance(&holder), UNITS);
+			assert_eq!(pallet_ah_migrator::RcReserve::<Runtime>::get(&crowdloan), 0);
+
+			assert_ok!(AhMigrator::do_unreserve_lease_deposit(
+				0,
+				ParaId::from(2002),
+				unmigrated.clone()
+			));
+			assert_eq!(Balances::reserved_balance(&unmigrated), UNITS);
+		});
+	}
+
+	#[test]
+	fn rc_client_accepts_new_session_reports_of_the_relay_chain() {
+		use frame_support::{assert_noop, assert_ok};
+		use paseo_runtime_constants::staking::SessionReport;
+
+		let report = |end_index| SessionReport { end_index, validator_points: vec![] };
+		// The Relay Chain calls the client at this index.
+		let call = RuntimeCall::RcClient(pallet_rc_client::Call::receive_session_report {
+			report: report(3),
+		});
+		assert_eq!(call.encode()[..2], [96, 0]);
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let relay_chain: RuntimeOrigin = pallet_xcm::Origin::Xcm(ParentLocation::get()).into();
+			assert_noop!(
+				RcClient::receive_session_report(RuntimeOrigin::root(), report(3)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(RcClient::receive_session_report(relay_chain.clone(), report(3)));
+			assert_eq!(pallet_rc_client::LastSessionReport::<Runtime>::get(), Some((3, 0)));
+			assert_noop!(
+				RcClient::receive_session_report(relay_chain, report(3)),
+				pallet_rc_client::Error::<Runtime>::StaleSessionReport
+			);
+		});
+	}
+
+	#[test]
+	fn rc_client_reports_truncated_validator_sets() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			System::set_block_number(1);
+			let max = staking::MaxActiveValidators::get();
+			let validators = |count: u32| -> Vec<AccountId> {
+				(0..count)
+					.map(|i| {
+						let mut raw = [0u8; 32];
+						raw[..4].copy_from_slice(&i.to_le_bytes());
+						AccountId::from(raw)
+					})
+					.collect()
+			};
+
+			RcClient::queue_validator_set(7, validators(max + 2));
+			System::assert_has_event(
+				pallet_rc_client::Event::ValidatorSetTruncated { planned_session: 7, dropped: 2 }
+					.into(),
+			);
+			let (planned_session, queued) =
+				pallet_rc_client::PendingValidatorSet::<Runtime>::get().unwrap();
+			assert_eq!((planned_session, queued.len() as u32), (7, max));
+
+			// A newer set replaces the one that was not sent yet.
+			RcClient::queue_validator_set(8, validators(3));
+			assert_eq!(
+				pallet_rc_client::PendingValidatorSet::<Runtime>::get()
+					.map(|(session, set)| (session, set.into_inner())),
+				Some((8, validators(3)))
+			);
+		});
+	}
+
+	#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+	pub enum OldProxyType {
+		Any,
+		NonTransfer,
+		CancelProxy,
+		Assets,
+		AssetOwner,
+		AssetManager,
+		Collator,
+	}
+
+	#[test]
+	fn proxy_type_decodes_correctly() {
+		for (i, j) in vec![
+			(OldProxyType::Any, ProxyType::Any),
+			(OldProxyType::NonTransfer, ProxyType::NonTransfer),
+			(OldProxyType::CancelProxy, ProxyType::CancelProxy),
+			(OldProxyType::Assets, ProxyType::Assets),
+			(OldProxyType::AssetOwner, ProxyType::AssetOwner),
+			(OldProxyType::AssetManager, ProxyType::AssetManager),
+			(OldProxyType::Collator, ProxyType::Collator),
+		]
+		.into_iter()
+		{
+			assert_eq!(i.encode(), j.encode());
+		}
+
+		for (index, proxy_type) in [
+			(100u8, ProxyType::Governance),
+			(101, ProxyType::Staking),
+			(102, ProxyType::NominationPools),
+			(103, ProxyType::Auction),
+			(104, ProxyType::ParaRegistration),
+		] {
+			assert_eq!(proxy_type.encode(), vec![index]);
+		}
+		assert!(ProxyType::decode(&mut &[7u8][..]).is_err());
+		assert!(ProxyType::decode(&mut &[105u8][..]).is_err());
+
+		// No index of the Relay Chain decodes to one of the new variants.
+		for rc_proxy_type in [
+			RcProxyType::Any,
+			RcProxyType::NonTransfer,
+			RcProxyType::Governance,
+			RcProxyType::Staking,
+			RcProxyType::CancelProxy,
+			RcProxyType::Auction,
+			RcProxyType::NominationPools,
+			RcProxyType::ParaRegistration,
+		] {
+			let encoded = rc_proxy_type.encode();
+			assert_eq!(
+				ProxyType::decode(&mut &encoded[..]).ok().map(|p| p.encode()),
+				OldProxyType::decode(&mut &encoded[..]).ok().map(|p| p.encode()),
+				"{rc_proxy_type:?} decodes to a new variant"
+			);
+		}
+	}
+
+	/// Spends of the Relay Chain treasury are re-anchored to be paid on Asset Hub.
+	#[test]
+	fn relay_spend_assets_are_reanchored() {
+		use polkadot_runtime_common::impls::VersionedLocatableAsset;
+		use xcm::latest::prelude::*;
+
+		let account = Location::new(0, [AccountId32 { network: None, id: [1; 32] }]);
+		let sibling = Location::new(0, [Parachain(2000)]);
+		let usdt = Location::new(0, [PalletInstance(50), GeneralIndex(1984)]);
+		let spend = |location: Location, asset_id: Location, beneficiary: &Location| {
+			AhMigrator::translate_spend_asset(
+				VersionedLocatableAsset::V4 { location, asset_id: asset_id.into() },
+				&VersionedLocation::V4(beneficiary.clone()),
+			)
+			.unwrap()
+		};
+		let expected = |location: Location, asset_id: Location, beneficiary: Location| {
+			(
+				VersionedLocatableAsset::V4 { location, asset_id: asset_id.into() },
+				VersionedLocation::V4(beneficiary),
+			)
+		};
+
+		// DOT on the Relay Chain.
+		assert_eq!(
+			spend(Location::here(), Location::here(), &account),
+			expected(Location::here(), Location::parent(), account.clone())
+		);
+		assert_eq!(
+			spend(Location::here(), Location::here(), &sibling),
+			expected(Location::here(), Location::parent(), Location::new(1, [Parachain(2000)]))
+		);
+		// An asset on Asset Hub.
+		assert_eq!(
+			spend(Location::new(0, [Parachain(1000)]), usdt.clone(), &account),
+			expected(Location::here(), usdt.clone(), account.clone())
+		);
+		// An asset on another chain.
+		assert_eq!(
+			spend(sibling.clone(), usdt.clone(), &account),
+			expected(Location::new(1, [Parachain(2000)]), usdt, account)
+		);
+	}
+
+	/// Spends of assets of Asset Hub are funded from the Relay Chain treasury, spends of assets on
+	/// other chains are refused.
+	#[test]
+	fn relay_spends_are_funded_or_refused() {
+		use frame_support::{assert_ok, traits::fungibles::Mutate};
+		use paseo_runtime_constants::ahm::{PortablePaymentState, PortableSpendStatus};
+		use polkadot_runtime_common::impls::VersionedLocatableAsset;
+		use xcm::latest::prelude::*;
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let rc_treasury = xcm_config::RelayTreasuryPalletAccount::get();
+			let usdt = Location::new(0, [PalletInstance(50), GeneralIndex(1984)]);
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				1984.into(),
+				rc_treasury.clone().into(),
+				true,
+				1
+			));
+			assert_ok!(NativeAndAssets::mint_into(usdt.clone(), &rc_treasury, 100));
+			let spend = |location: Location, status| PortableSpendStatus {
+				asset_kind: VersionedLocatableAsset::V4 { location, asset_id: usdt.clone().into() },
+				amount: 60,
+				beneficiary: VersionedLocation::V4(Location::new(
+					0,
+					[AccountId32 { network: None, id: [1; 32] }],
+				)),
+				valid_from: 0,
+				expire_at: 100,
+				status,
+			};
+
+			let translated =
+				|location, status| AhMigrator::translate_spend(spend(location, status)).unwrap();
+
+			// A spend of an asset of Asset Hub whose payment failed on the Relay Chain.
+			let on_asset_hub = Location::new(0, [Parachain(1000)]);
+			assert_ok!(AhMigrator::fund_spend(&translated(
+				on_asset_hub,
+				PortablePaymentState::Failed
+			)));
+			assert_eq!(Assets::balance(1984, Treasury::account_id()), 60);
+			assert_eq!(Assets::balance(1984, &rc_treasury), 40);
+			// A spend of an asset on another chain.
+			let elsewhere = Location::new(0, [Parachain(2000)]);
+			assert!(AhMigrator::fund_spend(&translated(elsewhere, PortablePaymentState::Pending))
+				.is_err());
+		});
+	}
+
+	#[test]
+	fn multisig_and_proxy_deposits_are_settled() {
+		use frame_support::{
+			assert_err, assert_ok,
+			traits::{fungible::Mutate, ReservableCurrency},
+		};
+		use pallet_ah_migrator::{DepositOutcome, RcDepositOutcomes};
+		use paseo_runtime_constants::{
+			ahm::{MigratedPallet, PortableProxyDefinition, RcMultisig, RcProxyMessage},
+			proxy::ProxyType as RcProxyType,
+		};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let (alice, bob) = (AccountId::from([1u8; 32]), AccountId::from([2u8; 32]));
+			let deposit = 10 * ExistentialDeposit::get();
+			let outcome = |pallet, who: &AccountId| RcDepositOutcomes::<Runtime>::get(pallet, who);
+
+			// The whole migrated deposit of a multisig is reserved and refunded.
+			Balances::set_balance(&alice, 100 * deposit);
+			assert_ok!(Balances::reserve(&alice, deposit));
+			assert_ok!(AhMigrator::refund_multisig(RcMultisig { creator: alice.clone(), deposit }));
+			assert_eq!(Balances::reserved_balance(&alice), 0);
+			assert_eq!(
+				outcome(MigratedPallet::Multisig, &alice),
+				DepositOutcome { reserved: 0, refunded: deposit, failed: 0 }
+			);
+
+			// Only half of it is reserved: that half is refunded and the failure recorded.
+			Balances::set_balance(&bob, 100 * deposit);
+			assert_ok!(Balances::reserve(&bob, deposit / 2));
+			assert_err!(
+				AhMigrator::refund_multisig(RcMultisig { creator: bob.clone(), deposit }),
+				sp_runtime::DispatchError::Other("multisig deposit not reserved")
+			);
+			assert_eq!(Balances::reserved_balance(&bob), 0);
+			assert_eq!(
+				outcome(MigratedPallet::Multisig, &bob),
+				DepositOutcome { reserved: 0, refunded: deposit / 2, failed: 1 }
+			);
+
+			let proxies = |who: &AccountId, deposit| RcProxyMessage::Proxies {
+				delegator: who.clone(),
+				deposit,
+				proxies: vec![PortableProxyDefinition {
+					delegate: AccountId::from([3u8; 32]),
+					proxy_type: RcProxyType::Any,
+					delay: 0,
+				}],
+			};
+			let required = pallet_proxy::Pallet::<Runtime>::deposit(1);
+			assert!(required < deposit);
+
+			// The migrated proxy deposit exceeds the one of Asset Hub: the excess is refunded.
+			assert_ok!(Balances::reserve(&alice, deposit));
+			assert_ok!(AhMigrator::integrate_proxy_message(proxies(&alice, deposit)));
+			assert_eq!(pallet_proxy::Proxies::<Runtime>::get(&alice).0.len(), 1);
+			assert_eq!(Balances::reserved_balance(&alice), required);
+			assert_eq!(
+				outcome(MigratedPallet::Proxy, &alice),
+				DepositOutcome { reserved: required, refunded: deposit - required, failed: 0 }
+			);
+
+			// The deposit of Asset Hub can not be reserved: nothing is integrated and the
+			// migrated part is refunded.
+			let poor = AccountId::from([4u8; 32]);
+			Balances::set_balance(&poor, ExistentialDeposit::get() + required / 2);
+			assert_ok!(Balances::reserve(&poor, required / 2));
+			assert!(AhMigrator::integrate_proxy_message(proxies(&poor, required / 2)).is_err());
+			assert!(pallet_proxy::Proxies::<Runtime>::get(&poor).0.is_empty());
+			assert_eq!(Balances::reserved_balance(&poor), 0);
+			assert_eq!(
+				outcome(MigratedPallet::Proxy, &poor),
+				DepositOutcome { reserved: 0, refunded: required / 2, failed: 1 }
+			);
+		});
+	}
+
+	#[test]
+	fn excess_vesting_schedules_are_merged() {
+		use frame_support::{assert_ok, traits::fungible::Mutate};
+		use paseo_runtime_constants::ahm::{PortableVestingInfo, RcVestingSchedule};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let who = AccountId::from([1u8; 32]);
+			Balances::set_balance(&who, 1_000 * ExistentialDeposit::get());
+			let max = <Runtime as pallet_vesting::Config>::MAX_VESTING_SCHEDULES;
+			let schedules: Vec<_> = (0..max + 2)
+				.map(|i| PortableVestingInfo {
+					locked: 10 * ExistentialDeposit::get(),
+					per_block: ExistentialDeposit::get(),
+					starting_block: 10 * i,
+				})
+				.collect();
+
+			assert_ok!(AhMigrator::integrate_vesting_schedule(RcVestingSchedule {
+				who: who.clone(),
+				schedules: schedules.clone(),
+			}));
+			assert_eq!(pallet_vesting::Vesting::<Runtime>::get(&who).unwrap().len(), max as usize);
+
+			// The merged schedule never unlocks earlier than the schedules it replaces.
+			let merged = AhMigrator::merge_vesting_schedules(&schedules[max as usize - 1..], 0);
+			for n in (0..400).step_by(5) {
+				let separate: Balance =
+					schedules[max as usize - 1..].iter().map(|s| s.locked_at(n)).sum();
+				assert!(merged.locked_at(n) >= separate, "unlocked early at {}", n);
+			}
+			assert_eq!(merged.locked_at(merged.ending_block() as BlockNumber), 0);
+		});
+	}
+
+	#[test]
+	fn migrated_claims_validate_unsigned() {
+		use claims::{EcdsaSignature, EthereumAddress};
+		use frame_support::{assert_ok, pallet_prelude::*};
+		use paseo_runtime_constants::ahm::RcClaimsMessage;
+		use sp_core::{ecdsa, keccak_256, Pair};
+
+		// The hash an Ethereum wallet signs, see `claims::Pallet::ethereum_signable_message`.
+		let message = |dest: &AccountId| {
+			let what: Vec<u8> =
+				dest.encode().iter().flat_map(|b| format!("{:02x}", b).into_bytes()).collect();
+			let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+			message.extend((Prefix::get().len() + what.len()).to_string().into_bytes());
+			message.extend_from_slice(Prefix::get());
+			message.extend(what);
+			keccak_256(&message)
+		};
+		let sign = |pair: &ecdsa::Pair, dest: &AccountId| {
+			EcdsaSignature(pair.sign_prehashed(&message(dest)).0)
+		};
+		let address = |pair: &ecdsa::Pair| {
+			let dest = AccountId::from([0u8; 32]);
+			let public =
+				sp_io::crypto::secp256k1_ecdsa_recover(&sign(pair, &dest).0, &message(&dest))
+					.unwrap();
+			let mut address = EthereumAddress::default();
+			address.0.copy_from_slice(&keccak_256(&public)[12..]);
+			address
+		};
+
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let claimer = ecdsa::Pair::from_seed(&[1u8; 32]);
+			let dest = AccountId::from([2u8; 32]);
+			assert_ok!(AhMigrator::integrate_claims_message(RcClaimsMessage::Claims(
+				address(&claimer),
+				100 * ExistentialDeposit::get()
+			)));
+
+			let claim = |signature| claims::Call::claim {
+				dest: dest.clone(),
+				ethereum_signature: signature,
+			};
+			assert_ok!(Claims::validate_unsigned(
+				TransactionSource::External,
+				&claim(sign(&claimer, &dest))
+			));
+			// A signature for another destination recovers another address without a claim.
+			assert_eq!(
+				Claims::validate_unsigned(
+					TransactionSource::External,
+					&claim(sign(&claimer, &AccountId::from([3u8; 32])))
+				),
+				Err(InvalidTransaction::Custom(claims::ValidityError::SignerHasNoClaim.into())
+					.into())
+			);
+			// Only migrated claims can be claimed.
+			let stranger = ecdsa::Pair::from_seed(&[4u8; 32]);
+			assert!(Claims::validate_unsigned(
+				TransactionSource::External,
+				&claim(sign(&stranger, &dest))
+			)
+			.is_err());
+		});
+	}
+
+	#[test]
+	fn scheduled_whitelist_dispatches_are_not_mapped() {
+		use frame_support::traits::PalletInfoAccess;
+
+		let whitelist = Whitelist::index() as u8;
+		assert_eq!(RcToAhCallIndex::convert((23, 0)), Some((whitelist, 0)));
+		assert_eq!(RcToAhCallIndex::convert((23, 1)), Some((whitelist, 1)));
+		// The whitelisted calls are calls of the Relay Chain.
+		assert_eq!(RcToAhCallIndex::convert((23, 2)), None);
+		assert_eq!(RcToAhCallIndex::convert((23, 3)), None);
+	}
+
 	/// Filling up a block by proof size is at most 30 times more expensive than ref time.
 	///
 	/// This is just a sanity check.
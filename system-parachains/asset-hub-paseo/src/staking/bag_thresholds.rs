@@ -0,0 +1,234 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Paseo.
+
+// Paseo is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Paseo is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Paseo.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated bag thresholds.
+//!
+//! Generated on 2021-10-14T08:36:33.156699497+00:00
+//! for the polkadot runtime.
+
+/// Existential weight for this runtime.
+#[cfg(any(test, feature = "std"))]
+#[allow(unused)]
+pub const EXISTENTIAL_WEIGHT: u64 = 10_000_000_000;
+
+/// Constant ratio between bags for this runtime.
+#[cfg(any(test, feature = "std"))]
+#[allow(unused)]
+pub const CONSTANT_RATIO: f64 = 1.1131723507077667;
+
+/// Upper thresholds delimiting the bag list.
+pub const THRESHOLDS: [u64; 200] = [
+	10_000_000_000,
+	11_131_723_507,
+	12_391_526_824,
+	13_793_905_044,
+	15_354_993_703,
+	17_092_754_435,
+	19_027_181_634,
+	21_180_532_507,
+	23_577_583_160,
+	26_245_913_670,
+	29_216_225_417,
+	32_522_694_326,
+	36_203_364_094,
+	40_300_583_912,
+	44_861_495_728,
+	49_938_576_656,
+	55_590_242_767,
+	61_881_521_217,
+	68_884_798_439,
+	76_680_653_006,
+	85_358_782_760,
+	95_019_036_859,
+	105_772_564_622,
+	117_743_094_401,
+	131_068_357_174,
+	145_901_671_259,
+	162_413_706_368,
+	180_794_447_305,
+	201_255_379_901,
+	224_031_924_337,
+	249_386_143_848,
+	277_609_759_981,
+	309_027_509_097,
+	344_000_878_735,
+	382_932_266_827,
+	426_269_611_626,
+	474_511_545_609,
+	528_213_132_664,
+	587_992_254_562,
+	654_536_720_209,
+	728_612_179_460,
+	811_070_932_564,
+	902_861_736_593,
+	1_005_040_721_687,
+	1_118_783_542_717,
+	1_245_398_906_179,
+	1_386_343_627_960,
+	1_543_239_395_225,
+	1_717_891_425_287,
+	1_912_309_236_147,
+	2_128_729_767_682,
+	2_369_643_119_512,
+	2_637_821_201_686,
+	2_936_349_627_828,
+	3_268_663_217_709,
+	3_638_585_517_729,
+	4_050_372_794_022,
+	4_508_763_004_364,
+	5_019_030_312_352,
+	5_587_045_771_074,
+	6_219_344_874_498,
+	6_923_202_753_807,
+	7_706_717_883_882,
+	8_578_905_263_043,
+	9_549_800_138_161,
+	10_630_573_468_586,
+	11_833_660_457_397,
+	13_172_903_628_838,
+	14_663_712_098_160,
+	16_323_238_866_411,
+	18_170_578_180_087,
+	20_226_985_226_447,
+	22_516_120_692_255,
+	25_064_322_999_817,
+	27_900_911_352_605,
+	31_058_523_077_268,
+	34_573_489_143_434,
+	38_486_252_181_966,
+	42_841_831_811_331,
+	47_690_342_626_046,
+	53_087_570_807_094,
+	59_095_615_988_698,
+	65_783_605_766_662,
+	73_228_491_069_308,
+	81_515_931_542_404,
+	90_741_281_135_191,
+	101_010_685_227_495,
+	112_442_301_921_293,
+	125_167_661_548_718,
+	139_333_180_038_781,
+	155_101_843_555_358,
+	172_655_083_789_626,
+	192_194_865_483_744,
+	213_946_010_204_502,
+	238_158_783_103_893,
+	265_111_772_429_462,
+	295_115_094_915_607,
+	328_513_963_936_552,
+	365_692_661_475_578,
+	407_078_959_611_349,
+	453_149_042_394_237,
+	504_432_984_742_966,
+	561_520_851_400_862,
+	625_069_486_125_324,
+	695_810_069_225_823,
+	774_556_530_406_243,
+	862_214_913_708_369,
+	959_793_802_308_039,
+	1_068_415_923_109_985,
+	1_189_331_064_661_951,
+	1_323_930_457_019_515,
+	1_473_762_779_014_021,
+	1_640_551_977_100_649,
+	1_826_217_100_807_404,
+	2_032_894_383_008_501,
+	2_262_961_819_074_188,
+	2_519_066_527_700_738,
+	2_804_155_208_229_882,
+	3_121_508_044_894_685,
+	3_474_776_448_088_622,
+	3_868_025_066_902_796,
+	4_305_778_556_320_752,
+	4_793_073_637_166_665,
+	5_335_517_047_800_242,
+	5_939_350_054_341_159,
+	6_611_520_261_667_250,
+	7_359_761_551_432_161,
+	8_192_683_066_856_378,
+	9_119_868_268_136_230,
+	10_151_985_198_186_376,
+	11_300_909_227_415_580,
+	12_579_859_689_817_292,
+	14_003_551_982_487_792,
+	15_588_366_878_604_342,
+	17_352_539_001_951_086,
+	19_316_366_631_550_092,
+	21_502_445_250_375_680,
+	23_935_927_525_325_748,
+	26_644_812_709_737_600,
+	29_660_268_798_266_784,
+	33_016_991_140_790_860,
+	36_753_601_641_491_664,
+	40_913_093_136_236_104,
+	45_543_324_061_189_736,
+	50_697_569_104_240_168,
+	56_435_132_174_936_472,
+	62_822_028_745_677_552,
+	69_931_745_415_056_768,
+	77_846_085_432_775_824,
+	86_656_109_914_600_688,
+	96_463_185_576_826_656,
+	107_380_151_045_315_664,
+	119_532_615_158_469_088,
+	133_060_402_202_199_856,
+	148_119_160_705_543_712,
+	164_882_154_307_451_552,
+	183_542_255_300_186_560,
+	204_314_163_786_713_728,
+	227_436_877_985_347_776,
+	253_176_444_104_585_088,
+	281_829_017_427_734_464,
+	313_724_269_827_691_328,
+	349_229_182_918_168_832,
+	388_752_270_484_770_624,
+	432_748_278_778_513_664,
+	481_723_418_752_617_984,
+	536_241_190_443_833_600,
+	596_928_866_512_693_376,
+	664_484_709_541_257_600,
+	739_686_006_129_409_280,
+	823_398_010_228_713_984,
+	916_583_898_614_395_264,
+	1_020_315_853_041_475_584,
+	1_135_787_396_594_579_584,
+	1_264_327_126_171_442_688,
+	1_407_413_999_103_859_968,
+	1_566_694_349_801_462_272,
+	1_744_000_832_209_069_824,
+	1_941_373_506_026_471_680,
+	2_161_083_309_305_266_176,
+	2_405_658_187_494_662_656,
+	2_677_912_179_572_818_944,
+	2_980_977_795_924_034_048,
+	3_318_342_060_496_414_208,
+	3_693_886_631_935_247_360,
+	4_111_932_465_319_354_368,
+	4_577_289_528_371_127_808,
+	5_095_312_144_166_932_480,
+	5_671_960_597_112_134_656,
+	6_313_869_711_009_142_784,
+	7_028_425_188_266_614_784,
+	7_823_848_588_596_424_704,
+	8_709_291_924_949_524_480,
+	9_694_942_965_096_232_960,
+	10_792_142_450_433_898_496,
+	12_013_514_580_722_579_456,
+	13_373_112_266_084_982_784,
+	14_886_578_817_516_689_408,
+	16_571_327_936_291_497_984,
+	18_446_744_073_709_551_615,
+];
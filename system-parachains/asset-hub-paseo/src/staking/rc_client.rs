@@ -0,0 +1,379 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset Hub side of staking on Asset Hub.
+//!
+//! The `RcClient` pallet drives `pallet_staking` with the sessions of the Relay Chain, which are
+//! reported by the `SessionClient` pallet of the Relay Chain. Every report rotates the sessions of
+//! `pallet_staking` like `pallet_session` would, and a newly elected validator set is sent back to
+//! the Relay Chain. A set that cannot be sent stays pending and is sent again in the next blocks.
+//! The offences reported by the Relay Chain are slashed here.
+
+pub use pallet_rc_client::*;
+
+use codec::{Decode, Encode};
+use paseo_runtime_constants::staking::ValidatorSetReport;
+
+/// The weights of the calls of [`Pallet`].
+pub trait WeightInfo {
+	fn receive_offence_report(o: u32) -> frame_support::weights::Weight;
+}
+
+/// The calls of the `SessionClient` pallet on the Relay Chain.
+///
+/// The codec indices must correspond to the call indices of `pallet_session_client`.
+#[derive(Encode, Decode)]
+pub(crate) enum SessionClientCalls {
+	#[codec(index = 1)]
+	ReceiveValidatorSet(ValidatorSetReport),
+}
+
+/// The pallets of the Relay Chain runtime that are called by the client.
+///
+/// The codec index must correspond to the index of `SessionClient` in the `construct_runtime` of
+/// the Relay Chain.
+#[derive(Encode, Decode)]
+pub(crate) enum RelayRuntimePallets {
+	#[codec(index = 42)]
+	SessionClient(SessionClientCalls),
+}
+
+#[frame_support::pallet]
+pub mod pallet_rc_client {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::EstimateNextNewSession};
+	use frame_system::pallet_prelude::*;
+	use pallet_session::SessionManager;
+	use pallet_staking::{Exposure, ExposureOf};
+	use parachains_common::{AccountId, Balance};
+	use paseo_runtime_constants::staking::{OffenceReport, SessionReport};
+	use sp_runtime::traits::{Convert, Saturating};
+	use sp_staking::{
+		offence::{OffenceDetails, OnOffenceHandler},
+		SessionIndex,
+	};
+	use sp_std::{vec, vec::Vec};
+	use xcm::prelude::*;
+
+	use super::WeightInfo;
+
+	/// The log target of this pallet.
+	pub const LOG_TARGET: &str = "runtime::rc-client";
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<AccountId = AccountId>
+		+ pallet_staking::Config<CurrencyBalance = Balance>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin of the Relay Chain.
+		type RcOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// The router used to send the validator sets to the Relay Chain.
+		type SendXcm: SendXcm;
+		/// Slashes the offenders reported by the Relay Chain.
+		type OffenceHandler: OnOffenceHandler<
+			AccountId,
+			(AccountId, Exposure<AccountId, Balance>),
+			Weight,
+		>;
+		/// The maximum number of validators in a set.
+		#[pallet::constant]
+		type MaxValidators: Get<u32>;
+		/// The expected length of a session of the Relay Chain, in local blocks.
+		#[pallet::constant]
+		type SessionLength: Get<BlockNumberFor<Self>>;
+		/// The weight required to process a session report, which may plan a new era.
+		#[pallet::constant]
+		type SessionReportWeight: Get<Weight>;
+		/// The weight required on the Relay Chain to receive a validator set.
+		#[pallet::constant]
+		type RcValidatorSetWeight: Get<Weight>;
+		/// Weight information for the calls of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The last session that ended on the Relay Chain and the local block in which it was
+	/// reported.
+	#[pallet::storage]
+	pub type LastSessionReport<T: Config> =
+		StorageValue<_, (SessionIndex, BlockNumberFor<T>), OptionQuery>;
+
+	/// The last validator set that was sent to the Relay Chain.
+	#[pallet::storage]
+	pub type ValidatorSet<T: Config> =
+		StorageValue<_, BoundedVec<AccountId, T::MaxValidators>, ValueQuery>;
+
+	/// The validator set that was elected but not yet sent to the Relay Chain, with the session
+	/// it was planned for.
+	#[pallet::storage]
+	pub type PendingValidatorSet<T: Config> =
+		StorageValue<_, (SessionIndex, BoundedVec<AccountId, T::MaxValidators>), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The end of a session of the Relay Chain was processed.
+		SessionReportReceived { end_index: SessionIndex, rewarded: u32 },
+		/// A new validator set was sent to the Relay Chain.
+		ValidatorSetSent { planned_session: SessionIndex, validators: u32 },
+		/// Offences of the Relay Chain were handed to staking.
+		OffenceReportReceived { session: SessionIndex, offences: u32 },
+		/// More than `MaxValidators` validators were elected, the last `dropped` ones are not
+		/// sent to the Relay Chain.
+		ValidatorSetTruncated { planned_session: SessionIndex, dropped: u32 },
+		/// The validator set could not be sent to the Relay Chain, it is sent again later.
+		ValidatorSetSendFailed { planned_session: SessionIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The validator set could not be sent to the Relay Chain.
+		XcmSendError,
+		/// The session was already reported.
+		StaleSessionReport,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			match PendingValidatorSet::<T>::get() {
+				Some(_) => {
+					Self::send_pending_validator_set();
+					T::DbWeight::get().reads_writes(2, 3)
+				},
+				None => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Receive the end of a session of the Relay Chain.
+		///
+		/// The reward points are given to the validators and the sessions of `pallet_staking`
+		/// are rotated. If a new era is planned, its validators are sent to the Relay Chain.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::SessionReportWeight::get())]
+		pub fn receive_session_report(
+			origin: OriginFor<T>,
+			report: SessionReport,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			let SessionReport { end_index, validator_points } = report;
+			if let Some((last, _)) = LastSessionReport::<T>::get() {
+				ensure!(end_index > last, Error::<T>::StaleSessionReport);
+			}
+
+			let rewarded = validator_points.len() as u32;
+			pallet_staking::Pallet::<T>::reward_by_ids(validator_points);
+
+			// The same order as in `pallet_session::Pallet::rotate_session`.
+			<pallet_staking::Pallet<T> as SessionManager<AccountId>>::end_session(end_index);
+			<pallet_staking::Pallet<T> as SessionManager<AccountId>>::start_session(
+				end_index.saturating_add(1),
+			);
+			let planned_session = end_index.saturating_add(2);
+			if let Some(validators) =
+				<pallet_staking::Pallet<T> as SessionManager<AccountId>>::new_session(
+					planned_session,
+				) {
+				Self::queue_validator_set(planned_session, validators);
+				Self::send_pending_validator_set();
+			}
+
+			LastSessionReport::<T>::put((end_index, frame_system::Pallet::<T>::block_number()));
+			Self::deposit_event(Event::SessionReportReceived { end_index, rewarded });
+			Ok(())
+		}
+
+		/// Receive offences of validators of the Relay Chain.
+		///
+		/// The offenders are slashed with their current exposure.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::receive_offence_report(
+			report.offences.len() as u32
+		))]
+		pub fn receive_offence_report(
+			origin: OriginFor<T>,
+			report: OffenceReport,
+		) -> DispatchResult {
+			T::RcOrigin::ensure_origin(origin)?;
+			let OffenceReport { session, offences } = report;
+			let count = offences.len() as u32;
+
+			let (offenders, slash_fraction): (Vec<_>, Vec<_>) = offences
+				.into_iter()
+				.map(|(who, fraction)| {
+					let exposure = ExposureOf::<T>::convert(who.clone()).unwrap_or_default();
+					(OffenceDetails { offender: (who, exposure), reporters: vec![] }, fraction)
+				})
+				.unzip();
+			T::OffenceHandler::on_offence(&offenders, &slash_fraction, session);
+
+			Self::deposit_event(Event::OffenceReportReceived { session, offences: count });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Queue the `validators` planned for `planned_session` to be sent to the Relay Chain.
+		///
+		/// A set that is still pending is replaced, the Relay Chain only needs the latest one.
+		pub(crate) fn queue_validator_set(
+			planned_session: SessionIndex,
+			mut validators: Vec<AccountId>,
+		) {
+			let max = T::MaxValidators::get() as usize;
+			if validators.len() > max {
+				let dropped = validators.split_off(max).len() as u32;
+				log::error!(
+					target: LOG_TARGET,
+					"Dropping {} validators of session {} above the limit",
+					dropped,
+					planned_session
+				);
+				Self::deposit_event(Event::ValidatorSetTruncated { planned_session, dropped });
+			}
+			let validators = BoundedVec::truncate_from(validators);
+			PendingValidatorSet::<T>::put((planned_session, validators));
+		}
+
+		/// Send the pending validator set to the Relay Chain.
+		///
+		/// The set stays pending if it cannot be sent.
+		pub(crate) fn send_pending_validator_set() {
+			let Some((planned_session, validators)) = PendingValidatorSet::<T>::get() else {
+				return
+			};
+			if Self::send_validator_set(planned_session, validators).is_err() {
+				Self::deposit_event(Event::ValidatorSetSendFailed { planned_session });
+				return
+			}
+			PendingValidatorSet::<T>::kill();
+		}
+
+		/// Send the `validators` planned for `planned_session` to the Relay Chain.
+		fn send_validator_set(
+			planned_session: SessionIndex,
+			validators: BoundedVec<AccountId, T::MaxValidators>,
+		) -> Result<(), Error<T>> {
+			let report =
+				ValidatorSetReport { planned_session, validators: validators.clone().into_inner() };
+			let message = Xcm(vec![
+				Instruction::UnpaidExecution {
+					weight_limit: WeightLimit::Unlimited,
+					check_origin: None,
+				},
+				Instruction::Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most: T::RcValidatorSetWeight::get(),
+					call: RelayRuntimePallets::SessionClient(
+						SessionClientCalls::ReceiveValidatorSet(report),
+					)
+					.encode()
+					.into(),
+				},
+			]);
+
+			send_xcm::<T::SendXcm>(Location::parent(), message).map_err(|e| {
+				log::error!(target: LOG_TARGET, "Failed to send validator set: {:?}", e);
+				Error::<T>::XcmSendError
+			})?;
+
+			Self::deposit_event(Event::ValidatorSetSent {
+				planned_session,
+				validators: validators.len() as u32,
+			});
+			ValidatorSet::<T>::put(validators);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> pallet_staking::SessionInterface<AccountId> for Pallet<T> {
+		fn disable_validator(_validator_index: u32) -> bool {
+			// The Relay Chain disables the offenders when it reports them.
+			false
+		}
+
+		fn validators() -> Vec<AccountId> {
+			ValidatorSet::<T>::get().into_inner()
+		}
+
+		fn prune_historical_up_to(_up_to: SessionIndex) {}
+	}
+
+	impl<T: Config> EstimateNextNewSession<BlockNumberFor<T>> for Pallet<T> {
+		fn average_session_length() -> BlockNumberFor<T> {
+			T::SessionLength::get()
+		}
+
+		fn estimate_next_new_session(
+			_now: BlockNumberFor<T>,
+		) -> (Option<BlockNumberFor<T>>, Weight) {
+			let next = LastSessionReport::<T>::get()
+				.map(|(_, reported_at)| reported_at.saturating_add(T::SessionLength::get()));
+			(next, T::DbWeight::get().reads(1))
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::*;
+	use frame_benchmarking::v2::*;
+	use paseo_runtime_constants::staking::OffenceReport;
+	use sp_runtime::{traits::Get, Perbill};
+	use sp_std::vec::Vec;
+	use xcm::prelude::Location;
+
+	#[benchmarks(where <T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>)]
+	mod benchmarks {
+		use super::*;
+
+		/// Offences of bonded validators in the active era, whose slashes are deferred.
+		#[benchmark]
+		fn receive_offence_report(
+			o: Linear<1, { T::MaxValidators::get() }>,
+		) -> Result<(), BenchmarkError> {
+			pallet_staking::ActiveEra::<T>::put(pallet_staking::ActiveEraInfo {
+				index: 1,
+				start: None,
+			});
+			pallet_staking::ErasStartSessionIndex::<T>::insert(1, 1);
+			let offences = (0..o)
+				.map(|i| {
+					let (stash, _) = pallet_staking::testing_utils::create_stash_controller::<T>(
+						i,
+						100,
+						pallet_staking::RewardDestination::Staked,
+					)?;
+					Ok((stash, Perbill::from_percent(10)))
+				})
+				.collect::<Result<Vec<_>, BenchmarkError>>()?;
+			let report = OffenceReport { session: 1, offences };
+			// The Relay Chain is the parent of Asset Hub.
+			let origin = pallet_xcm::Origin::Xcm(Location::parent());
+
+			#[extrinsic_call]
+			_(origin, report);
+
+			Ok(())
+		}
+	}
+}
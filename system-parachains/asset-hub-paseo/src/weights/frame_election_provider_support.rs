@@ -0,0 +1,69 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `frame_election_provider_support`
+//!
+//! COPIED FROM THE RELAY CHAIN, NOT YET BENCHMARKED ON ASSET HUB. The pallet accesses the same
+//! storage on both chains, but the numbers were measured with the Relay Chain runtime. Replace
+//! this file with the output of:
+//!
+//! ./target/production/polkadot-parachain benchmark pallet
+//!   --chain=./asset-hub-paseo-chain-spec.json --steps=50 --repeat=20 --extrinsic=*
+//!   --wasm-execution=compiled --heap-pages=4096 --pallet=frame_election_provider_support
+//!   --output=./asset-hub-paseo-weights/ --header=./file_header.txt
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `frame_election_provider_support`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> frame_election_provider_support::WeightInfo for WeightInfo<T> {
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn phragmen(v: u32, _t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_718_481_000 picoseconds.
+		Weight::from_parts(8_744_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 188_720
+			.saturating_add(Weight::from_parts(8_105_048, 0).saturating_mul(v.into()))
+			// Standard Error: 19_294_093
+			.saturating_add(Weight::from_parts(2_002_437_201, 0).saturating_mul(d.into()))
+	}
+	/// The range of component `v` is `[1000, 2000]`.
+	/// The range of component `t` is `[500, 1000]`.
+	/// The range of component `d` is `[5, 16]`.
+	fn phragmms(v: u32, _t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_180_796_000 picoseconds.
+		Weight::from_parts(6_192_455_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 157_434
+			.saturating_add(Weight::from_parts(6_515_455, 0).saturating_mul(v.into()))
+			// Standard Error: 16_095_537
+			.saturating_add(Weight::from_parts(1_799_572_171, 0).saturating_mul(d.into()))
+	}
+}
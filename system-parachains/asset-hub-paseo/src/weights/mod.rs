@@ -18,25 +18,46 @@ pub mod block_weights;
 pub mod cumulus_pallet_parachain_system;
 pub mod cumulus_pallet_xcmp_queue;
 pub mod extrinsic_weights;
+pub mod frame_election_provider_support;
 pub mod frame_system;
+pub mod pallet_ah_migrator;
 pub mod pallet_asset_conversion;
+pub mod pallet_asset_rate;
 pub mod pallet_assets_foreign;
 pub mod pallet_assets_local;
 pub mod pallet_assets_pool;
+pub mod pallet_bags_list;
 pub mod pallet_balances;
+pub mod pallet_bounties;
+pub mod pallet_child_bounties;
 pub mod pallet_collator_selection;
+pub mod pallet_conviction_voting;
+pub mod pallet_election_provider_multi_phase;
+pub mod pallet_fast_unstake;
+pub mod pallet_indices;
 pub mod pallet_message_queue;
+pub mod pallet_migrations;
 pub mod pallet_multisig;
 pub mod pallet_nfts;
+pub mod pallet_nomination_pools;
+pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_rc_client;
+pub mod pallet_referenda;
+pub mod pallet_remote_proxy;
+pub mod pallet_scheduler;
 pub mod pallet_session;
+pub mod pallet_staking;
 pub mod pallet_timestamp;
+pub mod pallet_treasury;
 pub mod pallet_uniques;
 pub mod pallet_utility;
 pub mod pallet_vesting;
+pub mod pallet_whitelist;
 pub mod pallet_xcm;
 pub mod pallet_xcm_bridge_hub_router;
 pub mod paritydb_weights;
+pub mod polkadot_runtime_common_claims;
 pub mod rocksdb_weights;
 pub mod xcm;
 
@@ -0,0 +1,376 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_ah_migrator`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: per item, the execution time and
+//! proof size cover the integration of the largest item of its pallet and every batch covers the
+//! report to the Relay Chain. The Relay Chain sends the batches with a fixed weight per item, keep
+//! it above these. Replace this file with the output of:
+//!
+//! ./target/production/polkadot-parachain benchmark pallet
+//!   --chain=./asset-hub-paseo-chain-spec.json --steps=50 --repeat=20
+//!   --pallet=pallet_ah_migrator --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./asset-hub-paseo-weights/ --header=./file_header.txt
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_ah_migrator`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::ah_migration::WeightInfo for WeightInfo<T> {
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `AhMigrator::RcReserve` (r:1 w:1)
+	/// Storage: `AhMigrator::MovedBalance` (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn receive_accounts(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(100_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::AhMigrationStage` (r:1 w:1)
+	fn update_stage() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2_000))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Storage: `Referenda::ReferendumCount` (r:1 w:1)
+	/// Storage: `Referenda::TrackQueue` (r:1 w:1)
+	/// Storage: `Referenda::MetadataOf` (r:0 w:1)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Storage: `AhMigrator::RcPendingProposals` (r:1 w:1)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_referenda(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(100_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `ConvictionVoting::VotingFor` (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_conviction_voting_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Treasury::Spends` (r:1 w:1)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:3)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_treasury_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(50_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:1 w:1)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Payee` (r:1 w:1)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::VirtualStakers` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	/// Storage: `VoterList::ListNodes` (r:2 w:2)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_nom_pools_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(100_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 10_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:1)
+	/// Storage: `DelegatedStaking::CounterForDelegators` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_delegated_staking_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `AhMigrator::RcDepositOutcomes` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_multisigs(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 4_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `AhMigrator::RcDepositOutcomes` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_proxy_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(50_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:2)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_vesting_schedules(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(80_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Claims::Claims` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_claims_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::TeleportsTracked` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn switch_teleport_tracking() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Preimage::PreimageFor` (r:1 w:1)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Storage: `AhMigrator::RcPendingProposals` (r:2 w:1)
+	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_preimage_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(200_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 20_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Storage: `Preimage::RequestStatusFor` (r:2 w:2)
+	/// Storage: `Preimage::PreimageFor` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_scheduled_tasks(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(100_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 5_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `Indices::Accounts` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `AhMigrator::RcDepositOutcomes` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_indices(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::ProcessedItems` (r:1 w:1)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Storage: `AhMigrator::RcCrowdloanContribution` (r:0 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn receive_crowdloan_messages(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AhMigrator::RcLeaseReserve` (r:1 w:1)
+	/// Storage: `AhMigrator::RcReserve` (r:1 w:1)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn unreserve_lease_deposit() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8_000))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AhMigrator::RcCrowdloanContribution` (r:1 w:1)
+	/// Storage: `AhMigrator::RcLeaseReserve` (r:1 w:1)
+	/// Storage: `AhMigrator::RcReserve` (r:1 w:1)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Storage: `System::Account` (r:3 w:3)
+	fn withdraw_crowdloan_contribution() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10_000))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `AhMigrator::RcCrowdloanContribution` (r:1 w:0)
+	/// Storage: `AhMigrator::RcCrowdloanDeposit` (r:1 w:1)
+	/// Storage: `AhMigrator::RcReserve` (r:1 w:1)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn unreserve_crowdloan_deposit() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8_000))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AhMigrator::UnintegratedBalance` (r:1 w:1)
+	/// Storage: `AhMigrator::MovedBalance` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn restore_unintegrated_balance() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,75 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_asset_rate`
+//!
+//! COPIED FROM THE RELAY CHAIN, NOT YET BENCHMARKED ON ASSET HUB. The pallet accesses the same
+//! storage on both chains, but the numbers were measured with the Relay Chain runtime. Replace
+//! this file with the output of:
+//!
+//! ./target/production/polkadot-parachain benchmark pallet
+//!   --chain=./asset-hub-paseo-chain-spec.json --steps=50 --repeat=20 --extrinsic=*
+//!   --wasm-execution=compiled --heap-pages=4096 --pallet=pallet_asset_rate
+//!   --output=./asset-hub-paseo-weights/ --header=./file_header.txt
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_asset_rate`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_asset_rate::WeightInfo for WeightInfo<T> {
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(1238), added: 3713, mode: `MaxEncodedLen`)
+	fn create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4703`
+		// Minimum execution time: 15_640_000 picoseconds.
+		Weight::from_parts(15_930_000, 0)
+			.saturating_add(Weight::from_parts(0, 4703))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(1238), added: 3713, mode: `MaxEncodedLen`)
+	fn update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `144`
+		//  Estimated: `4703`
+		// Minimum execution time: 16_320_000 picoseconds.
+		Weight::from_parts(16_650_000, 0)
+			.saturating_add(Weight::from_parts(0, 4703))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(1238), added: 3713, mode: `MaxEncodedLen`)
+	fn remove() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `144`
+		//  Estimated: `4703`
+		// Minimum execution time: 17_491_000 picoseconds.
+		Weight::from_parts(17_750_000, 0)
+			.saturating_add(Weight::from_parts(0, 4703))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,62 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_rc_client`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: per offence, the execution time
+//! covers the deferred slash of a validator by `pallet_staking` several times over and the proof
+//! size covers its ledger, exposure and slashing spans. The Relay Chain sends the reports with a
+//! fixed weight, keep it above these. Replace this file with the output of:
+//!
+//! ./target/production/polkadot-parachain benchmark pallet
+//!   --chain=./asset-hub-paseo-chain-spec.json --steps=50 --repeat=20
+//!   --pallet=pallet_rc_client --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./asset-hub-paseo-weights/ --header=./file_header.txt
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_rc_client`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::staking::rc_client::WeightInfo for WeightInfo<T> {
+	/// Storage: `Staking::ActiveEra` (r:1 w:0)
+	/// Storage: `Staking::ErasStartSessionIndex` (r:1 w:0)
+	/// Storage: `Staking::BondedEras` (r:1 w:0)
+	/// Storage: `Staking::ErasStakersOverview` (r:1200 w:0)
+	/// Storage: `Staking::Invulnerables` (r:1 w:0)
+	/// Storage: `Staking::ValidatorSlashInEra` (r:1200 w:1200)
+	/// Storage: `Staking::SlashingSpans` (r:1200 w:1200)
+	/// Storage: `Staking::SpanSlash` (r:1200 w:1200)
+	/// Storage: `Staking::Validators` (r:1200 w:1200)
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:1)
+	/// The range of component `o` is `[1, 1200]`.
+	fn receive_offence_report(o: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_parts(150_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(0, 12_000).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(o.into())))
+	}
+}
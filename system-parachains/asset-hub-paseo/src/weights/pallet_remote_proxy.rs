@@ -0,0 +1,57 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_remote_proxy`
+//!
+//! NOT YET BENCHMARKED ON THIS RUNTIME. These are upper bounds: the proof size covers the storage
+//! roots of the last `MaxStorageRootsToKeep` (2 hours of) relay blocks and the execution time
+//! covers the verification of a relay storage proof. Replace this file with the output of:
+//!
+//! ./target/production/polkadot-parachain benchmark pallet
+//!   --chain=./asset-hub-paseo-chain-spec.json --steps=50 --repeat=20
+//!   --pallet=pallet_remote_proxy --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//!   --output=./asset-hub-paseo-weights/ --header=./file_header.txt
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_remote_proxy`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_remote_proxy::WeightInfo for WeightInfo<T> {
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	fn remote_proxy() -> Weight {
+		Weight::from_parts(250_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 45_000))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	fn register_remote_proxy_proof() -> Weight {
+		Weight::from_parts(250_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 45_000))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	fn remote_proxy_with_registered_proof() -> Weight {
+		Weight::from_parts(250_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 45_000))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+}
@@ -0,0 +1,51 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Core assignments that are chunked on their way to the Relay chain.
+//!
+//! The Relay chain accepts at most `MAX_ASSIGNMENTS_PER_MESSAGE` assignments of a core per
+//! message, so larger assignments are sent in chunks to its `CoretimeAssignments` pallet. This
+//! pallet only reports the chunking, so that it can be followed from the Coretime chain.
+
+pub use pallet_assignment_chunks::*;
+
+#[frame_support::pallet]
+pub mod pallet_assignment_chunks {
+	use cumulus_primitives_core::relay_chain::BlockNumber as RelayBlockNumber;
+	use frame_support::pallet_prelude::*;
+	use pallet_broker::CoreIndex;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The assignment of a core was sent to the Relay chain in `chunks` messages.
+		AssignmentChunked {
+			core: CoreIndex,
+			begin: RelayBlockNumber,
+			assignments: u32,
+			chunks: u32,
+		},
+	}
+}
@@ -19,30 +19,43 @@ use codec::{Decode, Encode};
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
 use cumulus_primitives_core::relay_chain;
 use frame_support::{
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	parameter_types,
 	traits::{
 		fungible::{Balanced, Credit, Inspect},
 		tokens::{Fortitude, Preservation},
-		DefensiveResult, OnUnbalanced,
+		AsEnsureOriginWithArg, DefensiveResult, Get, OnRuntimeUpgrade, OnUnbalanced,
+	},
+	weights::{
+		constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
+		RuntimeDbWeight,
 	},
-	weights::constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
 };
 use frame_system::Pallet as System;
 use pallet_broker::{
-	CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, RCBlockNumberOf, TaskId,
+	AdaptPrice, AdaptedPrices, CenterTargetPrice, CompletionStatus, CoreAssignment, CoreIndex,
+	CoretimeInterface, Leases, PartsOf57600, PotentialRenewals, RCBlockNumberOf, SaleInfo,
+	SalePerformance, ScheduleItem, TaskId,
 };
 use parachains_common::{AccountId, Balance};
 use paseo_runtime_constants::{system_parachain::coretime, time::DAYS as RELAY_DAYS};
-use sp_runtime::traits::{AccountIdConversion, MaybeConvert};
+use sp_runtime::{
+	traits::{AccountIdConversion, MaybeConvert},
+	FixedU64, PerThing,
+};
 use xcm::latest::prelude::*;
-use xcm_config::LocationToAccountId;
+use xcm_config::{AssetHubLocation, LocationToAccountId};
 use xcm_executor::traits::{ConvertLocation, TransactAsset};
 
-/// A type containing the encoding of the coretime pallet in the Relay chain runtime. Used to
-/// construct any remote calls. The codec index must correspond to the index of `Coretime` in the
-/// `construct_runtime` of the Relay chain.
+/// A type containing the encoding of the coretime pallets in the Relay chain runtime. Used to
+/// construct any remote calls. The codec indices must correspond to the indices of `Coretime`,
+/// `OnDemandCredits` and `CoretimeAssignments` in the `construct_runtime` of the Relay chain.
 #[derive(Encode, Decode)]
 enum RelayRuntimePallets {
+	#[codec(index = 66)]
+	OnDemandCredits(OnDemandCreditsCalls),
+	#[codec(index = 67)]
+	CoretimeAssignments(CoretimeAssignmentsCalls),
 	#[codec(index = 74)]
 	Coretime(CoretimeProviderCalls),
 }
@@ -54,8 +67,6 @@ enum CoretimeProviderCalls {
 	RequestCoreCount(CoreIndex),
 	#[codec(index = 2)]
 	RequestRevenueInfoAt(relay_chain::BlockNumber),
-	#[codec(index = 3)]
-	CreditAccount(AccountId, Balance),
 	#[codec(index = 4)]
 	AssignCore(
 		CoreIndex,
@@ -65,6 +76,31 @@ enum CoretimeProviderCalls {
 	),
 }
 
+/// Call encoding for the calls needed from the relay on-demand credits pallet.
+///
+/// The relay `coretime` pallet does not implement `credit_account` yet, so the credits are handled
+/// by a dedicated pallet on the Relay chain.
+#[derive(Encode, Decode)]
+enum OnDemandCreditsCalls {
+	#[codec(index = 0)]
+	CreditAccount(AccountId, Balance),
+}
+
+/// Call encoding for the calls needed from the relay pallet reassembling core assignments.
+#[derive(Encode, Decode)]
+enum CoretimeAssignmentsCalls {
+	#[codec(index = 0)]
+	AssignCoreChunk(
+		CoreIndex,
+		relay_chain::BlockNumber,
+		Vec<(CoreAssignment, PartsOf57600)>,
+		Option<relay_chain::BlockNumber>,
+	),
+}
+
+/// The maximum number of assignments of a core sent to the relay in a single message.
+const MAX_ASSIGNMENTS_PER_MESSAGE: usize = 28;
+
 parameter_types! {
 	/// The holding account into which burnt funds will be moved at the point of sale. This will be
 	/// burnt periodically.
@@ -121,6 +157,29 @@ fn burn_at_relay(stash: &AccountId, value: Balance) -> Result<(), XcmError> {
 	Ok(())
 }
 
+fn send_core_assignment(call: RelayRuntimePallets, call_weight: Weight) {
+	let message = Xcm(vec![
+		Instruction::UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+		Instruction::Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: call_weight,
+			call: call.encode().into(),
+		},
+	]);
+
+	match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
+		Ok(_) => log::debug!(
+			target: "runtime::coretime",
+			"Core assignment sent successfully."
+		),
+		Err(e) => log::error!(
+			target: "runtime::coretime",
+			"Core assignment failed to send: {:?}",
+			e
+		),
+	}
+}
+
 parameter_types! {
 	/// The revenue from on-demand coretime sales. This is distributed amonst those who contributed
 	/// regions to the pool.
@@ -212,62 +271,17 @@ impl CoretimeInterface for CoretimeAllocator {
 	}
 
 	fn credit_account(who: Self::AccountId, amount: Self::Balance) {
-		use crate::coretime::CoretimeProviderCalls::CreditAccount;
-		let _credit_account_call = RelayRuntimePallets::Coretime(CreditAccount(who, amount));
-
-		log::debug!(
-			target: "runtime::coretime",
-			"`credit_account` is unimplemented on the relay."
-		);
-	}
+		use crate::coretime::OnDemandCreditsCalls::CreditAccount;
+		let credit_account_call = RelayRuntimePallets::OnDemandCredits(CreditAccount(who, amount));
 
-	fn assign_core(
-		core: CoreIndex,
-		begin: RCBlockNumberOf<Self>,
-		assignment: Vec<(CoreAssignment, PartsOf57600)>,
-		end_hint: Option<RCBlockNumberOf<Self>>,
-	) {
-		use crate::coretime::CoretimeProviderCalls::AssignCore;
-
-		// Weight for `assign_core` from Polkadot runtime benchmarks:
+		// Weight for `credit_account` on the Relay chain: mints into the pot and updates the
+		// credits of the account.
 		// `ref_time`, `proof_size`, reads, writes
-		// 12_201_135 + 80 * 13_556, 3579, 1, 2
+		// ~40_000_000, 3593, 3, 3
 		// Use overestimates for reads and writes, add 30% to each component with a healthy round
 		// up.
 		let call_weight =
-			Weight::from_parts(350 * WEIGHT_REF_TIME_PER_MICROS, 5 * WEIGHT_PROOF_SIZE_PER_KB);
-
-		// The relay chain currently only allows `assign_core` to be called with a complete mask
-		// and only ever with increasing `begin`. The assignments must be truncated to avoid
-		// dropping that core's assignment completely.
-
-		// This shadowing of `assignment` is temporary and can be removed when the relay can accept
-		// multiple messages to assign a single core.
-		let assignment = if assignment.len() > 28 {
-			let mut total_parts = 0u16;
-			// Account for missing parts with a new `Idle` assignment at the start as
-			// `assign_core` on the relay assumes this is sorted. We'll add the rest of the
-			// assignments and sum the parts in one pass, so this is just initialized to 0.
-			let mut assignment_truncated = vec![(CoreAssignment::Idle, 0)];
-			// Truncate to first 27 non-idle assignments.
-			assignment_truncated.extend(
-				assignment
-					.into_iter()
-					.filter(|(a, _)| *a != CoreAssignment::Idle)
-					.take(27)
-					.inspect(|(_, parts)| total_parts += *parts)
-					.collect::<Vec<_>>(),
-			);
-
-			// Set the parts of the `Idle` assignment we injected at the start of the vec above.
-			assignment_truncated[0].1 = 57_600u16.saturating_sub(total_parts);
-			assignment_truncated
-		} else {
-			assignment
-		};
-
-		let assign_core_call =
-			RelayRuntimePallets::Coretime(AssignCore(core, begin, assignment, end_hint));
+			Weight::from_parts(500 * WEIGHT_REF_TIME_PER_MICROS, 6 * WEIGHT_PROOF_SIZE_PER_KB);
 
 		let message = Xcm(vec![
 			Instruction::UnpaidExecution {
@@ -277,24 +291,90 @@ impl CoretimeInterface for CoretimeAllocator {
 			Instruction::Transact {
 				origin_kind: OriginKind::Native,
 				require_weight_at_most: call_weight,
-				call: assign_core_call.encode().into(),
+				call: credit_account_call.encode().into(),
 			},
 		]);
 
-		match PolkadotXcm::send_xcm(Here, Location::parent(), message.clone()) {
+		match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
 			Ok(_) => log::debug!(
 				target: "runtime::coretime",
-				"Core assignment sent successfully."
+				"Account credited successfully."
 			),
 			Err(e) => log::error!(
 				target: "runtime::coretime",
-				"Core assignment failed to send: {:?}",
+				"Failed to credit the account: {:?}",
 				e
 			),
 		}
 	}
 
+	fn assign_core(
+		core: CoreIndex,
+		begin: RCBlockNumberOf<Self>,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<RCBlockNumberOf<Self>>,
+	) {
+		use crate::coretime::{
+			CoretimeAssignmentsCalls::AssignCoreChunk, CoretimeProviderCalls::AssignCore,
+		};
+
+		// The relay `coretime` pallet only accepts the complete assignment of a core in a single
+		// message, so larger assignments are chunked across several messages to the
+		// `CoretimeAssignments` pallet of the relay, which reassembles them.
+		if assignment.len() <= MAX_ASSIGNMENTS_PER_MESSAGE {
+			// Weight for `assign_core` from Polkadot runtime benchmarks:
+			// `ref_time`, `proof_size`, reads, writes
+			// 12_201_135 + 80 * 13_556, 3579, 1, 2
+			// Use overestimates for reads and writes, add 30% to each component with a healthy
+			// round up.
+			let call_weight =
+				Weight::from_parts(350 * WEIGHT_REF_TIME_PER_MICROS, 5 * WEIGHT_PROOF_SIZE_PER_KB);
+			let assign_core_call =
+				RelayRuntimePallets::Coretime(AssignCore(core, begin, assignment, end_hint));
+			send_core_assignment(assign_core_call, call_weight);
+			return;
+		}
+
+		// `assign_core_chunk` is not benchmarked on the relay yet, its weights declare an upper
+		// bound of:
+		// `ref_time`, `proof_size`, reads, writes
+		// 250_000_000, 5000, 2, 3
+		// Add headroom to each component with a healthy round up.
+		let call_weight =
+			Weight::from_parts(500 * WEIGHT_REF_TIME_PER_MICROS, 6 * WEIGHT_PROOF_SIZE_PER_KB);
+
+		let chunks = assignment.len().div_ceil(MAX_ASSIGNMENTS_PER_MESSAGE) as u32;
+		log::debug!(
+			target: "runtime::coretime",
+			"Chunking the {} assignments of core {} into {} messages.",
+			assignment.len(),
+			core,
+			chunks
+		);
+		AssignmentChunks::deposit_event(pallet_assignment_chunks::Event::AssignmentChunked {
+			core,
+			begin,
+			assignments: assignment.len() as u32,
+			chunks,
+		});
+		for chunk in assignment.chunks(MAX_ASSIGNMENTS_PER_MESSAGE) {
+			let assign_core_chunk_call = RelayRuntimePallets::CoretimeAssignments(AssignCoreChunk(
+				core,
+				begin,
+				chunk.to_vec(),
+				end_hint,
+			));
+			send_core_assignment(assign_core_chunk_call, call_weight);
+		}
+	}
+
 	fn on_new_timeslice(t: pallet_broker::Timeslice) {
+		// The broker does not account for the work done here, so it is registered separately.
+		System::<Runtime>::register_extra_weight_unchecked(
+			discount_renewals(),
+			DispatchClass::Mandatory,
+		);
+
 		// Burn roughly once per day. TIMESLICE_PERIOD tested to be != 0.
 		const BURN_PERIOD: pallet_broker::Timeslice =
 			RELAY_DAYS.saturating_div(coretime::TIMESLICE_PERIOD);
@@ -324,8 +404,20 @@ impl CoretimeInterface for CoretimeAllocator {
 
 parameter_types! {
 	pub const BrokerPalletId: PalletId = PalletId(*b"py/broke");
+	/// `GeneralAdmin` pluralistic body.
+	pub const GeneralAdminBodyId: BodyId = BodyId::Administration;
 }
 
+/// We allow Root and the `GeneralAdmin` of governance, on the Relay Chain or on Asset Hub, to
+/// administer the broker.
+pub type BrokerAdminOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	EitherOfDiverse<
+		EnsureXcm<IsVoiceOfBody<GovernanceLocation, GeneralAdminBodyId>>,
+		EnsureXcm<IsVoiceOfBody<AssetHubLocation, GeneralAdminBodyId>>,
+	>,
+>;
+
 pub struct SovereignAccountOf;
 impl MaybeConvert<TaskId, AccountId> for SovereignAccountOf {
 	fn maybe_convert(id: TaskId) -> Option<AccountId> {
@@ -335,6 +427,183 @@ impl MaybeConvert<TaskId, AccountId> for SovereignAccountOf {
 	}
 }
 
+/// Dynamic params of the broker that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Parameters of the prices of the sales, see [`PaseoPriceAdapter`].
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod broker {
+		/// The highest price of a core. `None` leaves the prices uncapped.
+		#[codec(index = 0)]
+		pub static PriceCeiling: Option<Balance> = None;
+
+		/// The price of a core. When set, the sales neither lead in nor adapt their prices to the
+		/// previous sale.
+		#[codec(index = 1)]
+		pub static FixedPrice: Option<Balance> = None;
+
+		/// The discount on the end price of the sale for the renewals of the expired leases.
+		#[codec(index = 2)]
+		pub static RenewalDiscount: Perbill = Perbill::zero();
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Broker(dynamic_params::broker::Parameters::PriceCeiling(
+			dynamic_params::broker::PriceCeiling,
+			Some(None),
+		))
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	// The parameters only configure the broker, so they are administered like it.
+	type AdminOrigin = AsEnsureOriginWithArg<BrokerAdminOrigin>;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	/// The beginning of the region of the last sale whose renewals were discounted.
+	pub storage RenewalsDiscountedAt: pallet_broker::Timeslice = 0;
+	/// The tasks that held a lease when the renewals were last discounted.
+	pub storage LeaseHolders: Vec<TaskId> = Vec::new();
+}
+
+/// The price adapter of Paseo.
+///
+/// A testnet should not price teams out of cores, so on top of [`CenterTargetPrice`] governance can
+/// cap the prices with [`dynamic_params::broker::PriceCeiling`] or fix them with
+/// [`dynamic_params::broker::FixedPrice`]. Both apply from the next sale on.
+pub struct PaseoPriceAdapter;
+impl AdaptPrice<Balance> for PaseoPriceAdapter {
+	fn leadin_factor_at(when: FixedU64) -> FixedU64 {
+		use dynamic_params::broker::{FixedPrice, PriceCeiling};
+
+		let one = FixedU64::from_u32(1);
+		if FixedPrice::get().is_some() {
+			return one;
+		}
+		let factor = CenterTargetPrice::<Balance>::leadin_factor_at(when);
+		// The leadin starts above the end price of the sale, keep it below the ceiling.
+		match (PriceCeiling::get(), SaleInfo::<Runtime>::get()) {
+			(Some(ceiling), Some(sale)) if sale.end_price > 0 =>
+				factor.min(FixedU64::from_rational(ceiling, sale.end_price).max(one)),
+			_ => factor,
+		}
+	}
+
+	fn adapt_price(performance: SalePerformance<Balance>) -> AdaptedPrices<Balance> {
+		use dynamic_params::broker::{FixedPrice, PriceCeiling};
+
+		if let Some(price) = FixedPrice::get() {
+			return AdaptedPrices { end_price: price, target_price: price };
+		}
+		let prices = CenterTargetPrice::<Balance>::adapt_price(performance);
+		match PriceCeiling::get() {
+			Some(ceiling) => AdaptedPrices {
+				end_price: prices.end_price.min(ceiling),
+				target_price: prices.target_price.min(ceiling),
+			},
+			None => prices,
+		}
+	}
+}
+
+/// Seeds [`LeaseHolders`] with the current leases.
+///
+/// Without it the leases that end in the first sale rotation after the upgrade are not found by
+/// [`discount_renewals`]. Does nothing once the lease holders were recorded.
+pub struct SeedLeaseHolders;
+impl OnRuntimeUpgrade for SeedLeaseHolders {
+	fn on_runtime_upgrade() -> Weight {
+		let db: RuntimeDbWeight = <Runtime as frame_system::Config>::DbWeight::get();
+		if frame_support::storage::unhashed::exists(&LeaseHolders::key()) {
+			return db.reads(1);
+		}
+		let leases: Vec<TaskId> =
+			Leases::<Runtime>::get().into_iter().map(|lease| lease.task).collect();
+		log::info!(target: "runtime::coretime", "Seeding {} lease holders", leases.len());
+		LeaseHolders::set(&leases);
+		db.reads_writes(2, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let holders = LeaseHolders::get();
+		frame_support::ensure!(
+			Leases::<Runtime>::get().iter().all(|lease| holders.contains(&lease.task)),
+			"Not all lease holders are recorded"
+		);
+		Ok(())
+	}
+}
+
+/// Discounts the renewals of expired leases by [`dynamic_params::broker::RenewalDiscount`].
+///
+/// When a lease expires, its holder may renew the core at the end price of the sale in which the
+/// lease ended. Once per sale, on the first timeslice after the sale rotated, the leases that ended
+/// are found by comparing [`Leases`] with [`LeaseHolders`] and the price of their renewals is
+/// capped at the discounted end price. The renewals of the cores bought in a sale are not
+/// discounted.
+///
+/// Returns the weight it consumed.
+fn discount_renewals() -> Weight {
+	let db: RuntimeDbWeight = <Runtime as frame_system::Config>::DbWeight::get();
+	let Some(sale) = SaleInfo::<Runtime>::get() else { return db.reads(1) };
+	if RenewalsDiscountedAt::get() == sale.region_begin {
+		return db.reads(2);
+	}
+
+	let lease_holders = LeaseHolders::get();
+	let leases: Vec<TaskId> =
+		Leases::<Runtime>::get().into_iter().map(|lease| lease.task).collect();
+	let ended: Vec<TaskId> =
+		lease_holders.iter().filter(|task| !leases.contains(task)).copied().collect();
+	LeaseHolders::set(&leases);
+	RenewalsDiscountedAt::set(&sale.region_begin);
+	let mut weight = db.reads_writes(5, 2);
+
+	let discount = dynamic_params::broker::RenewalDiscount::get();
+	if discount.is_zero() || ended.is_empty() {
+		return weight;
+	}
+
+	// The cores of the expired leases are not known, so look for their renewals among all
+	// renewals into the next region.
+	let price = discount.left_from_one() * sale.end_price;
+	let mut renewals = 0;
+	let discounted: Vec<_> = PotentialRenewals::<Runtime>::iter()
+		.inspect(|_| renewals += 1)
+		.filter(|(id, record)| {
+			let CompletionStatus::Complete(schedule) = &record.completion else { return false };
+			let ended_lease = matches!(
+				schedule.as_slice(),
+				[ScheduleItem { assignment: CoreAssignment::Task(task), .. }] if ended.contains(task)
+			);
+			id.when == sale.region_end && ended_lease && record.price > price
+		})
+		.collect();
+	weight.saturating_accrue(db.reads(renewals));
+	let count = discounted.len() as u64;
+	for (id, mut record) in discounted {
+		record.price = price;
+		PotentialRenewals::<Runtime>::insert(id, record);
+	}
+	log::debug!(
+		target: "runtime::coretime",
+		"Discounted {count} renewals of expired leases into {} to {price}",
+		sale.region_end
+	);
+	weight.saturating_add(db.writes(count))
+}
+
 impl pallet_broker::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -346,8 +615,12 @@ impl pallet_broker::Config for Runtime {
 	type ConvertBalance = sp_runtime::traits::Identity;
 	type WeightInfo = weights::pallet_broker::WeightInfo<Runtime>;
 	type PalletId = BrokerPalletId;
-	type AdminOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = BrokerAdminOrigin;
 	type SovereignAccountOf = SovereignAccountOf;
 	type MaxAutoRenewals = ConstU32<100>;
-	type PriceAdapter = pallet_broker::CenterTargetPrice<Balance>;
+	type PriceAdapter = PaseoPriceAdapter;
+}
+
+impl pallet_assignment_chunks::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 }
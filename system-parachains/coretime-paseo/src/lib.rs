@@ -22,6 +22,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+pub mod assignment_chunks;
 mod coretime;
 // Genesis preset configurations.
 pub mod genesis_config_presets;
@@ -30,6 +31,7 @@ mod tests;
 mod weights;
 pub mod xcm_config;
 
+use assignment_chunks::pallet_assignment_chunks;
 use codec::{Decode, Encode, MaxEncodedLen};
 use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
@@ -114,10 +116,18 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 
 /// Migrations to apply on runtime upgrade.
-pub type Migrations = (
-	// permanent
-	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
-);
+pub type Migrations = (migrations::Unreleased, migrations::Permanent);
+
+/// The runtime migrations per release.
+pub mod migrations {
+	use super::*;
+
+	/// Unreleased migrations. Add new ones here:
+	pub type Unreleased = (coretime::SeedLeaseHolders,);
+
+	/// Migrations/checks that do not need to be versioned and can run on every update.
+	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+}
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -179,17 +189,11 @@ parameter_types! {
 }
 
 /// Filter:
-/// - Credit purchase calls until the credit system is implemented. Otherwise, users may have chance
-///   of locking their funds forever on purchased credits they cannot use.
 /// - The interlace call until the relay can support this fully
 pub struct IsFilteredBrokerCall;
 impl Contains<RuntimeCall> for IsFilteredBrokerCall {
 	fn contains(c: &RuntimeCall) -> bool {
-		matches!(
-			c,
-			RuntimeCall::Broker(pallet_broker::Call::purchase_credit { .. }) |
-				RuntimeCall::Broker(pallet_broker::Call::interlace { .. })
-		)
+		matches!(c, RuntimeCall::Broker(pallet_broker::Call::interlace { .. }))
 	}
 }
 
@@ -636,6 +640,8 @@ construct_runtime!(
 
 		// The main stage.
 		Broker: pallet_broker = 50,
+		AssignmentChunks: pallet_assignment_chunks = 51,
+		Parameters: pallet_parameters = 52,
 
 		// Sudo.
 		Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>, Config<T>} = 255,
@@ -658,6 +664,7 @@ mod benches {
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
+		[pallet_parameters, Parameters]
 		[pallet_proxy, Proxy]
 		[pallet_utility, Utility]
 		// NOTE: Make sure you point to the individual modules below.
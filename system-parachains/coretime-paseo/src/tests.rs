@@ -15,23 +15,30 @@
 // limitations under the License.
 
 use crate::{
-	coretime::{BrokerPalletId, CoretimeBurnAccount},
+	coretime::{
+		dynamic_params::broker, BrokerPalletId, CoretimeBurnAccount, LeaseHolders,
+		PaseoPriceAdapter, RuntimeParameters,
+	},
 	xcm_config::LocationToAccountId,
 	*,
 };
 use coretime::CoretimeAllocator;
 use frame_support::{
-	assert_ok,
+	assert_noop, assert_ok,
 	traits::{
 		fungible::{Inspect, Mutate},
 		Get, OnInitialize,
 	},
 };
-use pallet_broker::{ConfigRecordOf, RCBlockNumberOf, SaleInfo};
+use pallet_broker::{
+	AdaptPrice, CompletionStatus, ConfigRecordOf, CoreAssignment, CoreMask, CoretimeInterface,
+	LeaseRecordItem, Leases, PotentialRenewalId, PotentialRenewalRecord, PotentialRenewals,
+	RCBlockNumberOf, SaleInfo, SalePerformance, Schedule, ScheduleItem,
+};
 use parachains_runtimes_test_utils::ExtBuilder;
 use paseo_runtime_constants::system_parachain::coretime::TIMESLICE_PERIOD;
 use sp_core::crypto::Ss58Codec;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{traits::AccountIdConversion, FixedU64};
 use xcm_runtime_apis::conversions::LocationToAccountHelper;
 
 const ALICE: [u8; 32] = [1u8; 32];
@@ -44,6 +51,13 @@ fn advance_to(b: BlockNumber) {
 	}
 }
 
+fn set_broker_parameter(parameter: broker::Parameters) {
+	assert_ok!(Parameters::set_parameter(
+		RuntimeOrigin::root(),
+		RuntimeParameters::Broker(parameter)
+	));
+}
+
 #[test]
 fn bulk_revenue_is_burnt() {
 	const ALICE: [u8; 32] = [1u8; 32];
@@ -116,6 +130,149 @@ fn timeslice_period_is_sane() {
 	assert_eq!(TIMESLICE_PERIOD, 80);
 }
 
+#[test]
+fn price_adapter_can_be_capped_and_fixed() {
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let performance = SalePerformance {
+			sellout_price: Some(1_000),
+			end_price: 100,
+			ideal_cores_sold: 1,
+			cores_offered: 1,
+			cores_sold: 1,
+		};
+
+		// Without a ceiling, the target price is the sellout price.
+		let prices = PaseoPriceAdapter::adapt_price(performance);
+		assert_eq!(prices.target_price, 1_000);
+		assert_eq!(prices.end_price, 100);
+
+		// Only the admin of the broker sets the parameters.
+		assert_noop!(
+			Parameters::set_parameter(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				RuntimeParameters::Broker(broker::Parameters::PriceCeiling(
+					broker::PriceCeiling,
+					Some(Some(500))
+				))
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		// The ceiling caps the prices.
+		set_broker_parameter(broker::Parameters::PriceCeiling(
+			broker::PriceCeiling,
+			Some(Some(500)),
+		));
+		let prices = PaseoPriceAdapter::adapt_price(performance);
+		assert_eq!(prices.target_price, 500);
+		assert_eq!(prices.end_price, 100);
+
+		// A fixed price overrides the ceiling and removes the leadin.
+		set_broker_parameter(broker::Parameters::FixedPrice(broker::FixedPrice, Some(Some(42))));
+		let prices = PaseoPriceAdapter::adapt_price(performance);
+		assert_eq!(prices.target_price, 42);
+		assert_eq!(prices.end_price, 42);
+		assert_eq!(
+			PaseoPriceAdapter::leadin_factor_at(FixedU64::from_u32(0)),
+			FixedU64::from_u32(1)
+		);
+	});
+}
+
+#[test]
+fn renewals_of_expired_leases_are_discounted_once_per_sale() {
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let config = ConfigRecordOf::<Runtime> {
+				advance_notice: 1,
+				interlude_length: 1,
+				leadin_length: 2,
+				region_length: 1,
+				ideal_bulk_proportion: Perbill::from_percent(100),
+				limit_cores_offered: None,
+				renewal_bump: Perbill::from_percent(3),
+				contribution_timeout: 1,
+			};
+			let ed = ExistentialDeposit::get();
+			assert_ok!(Broker::configure(RuntimeOrigin::root(), config));
+			assert_ok!(Broker::start_sales(RuntimeOrigin::root(), 100 * ed, 1));
+
+			// The lease of 2000 expired in the last rotation, 2001 still holds its lease and 3000
+			// bought its core in a sale.
+			let sale = SaleInfo::<Runtime>::get().unwrap();
+			LeaseHolders::set(&vec![2000, 2001]);
+			Leases::<Runtime>::put(frame_support::BoundedVec::truncate_from(vec![
+				LeaseRecordItem { until: sale.region_end + 10, task: 2001 },
+			]));
+			let renewal = |core, when, task| {
+				let id = PotentialRenewalId { core, when };
+				let completion =
+					CompletionStatus::Complete(Schedule::truncate_from(vec![ScheduleItem {
+						mask: CoreMask::complete(),
+						assignment: CoreAssignment::Task(task),
+					}]));
+				PotentialRenewals::<Runtime>::insert(
+					id,
+					PotentialRenewalRecord { price: 2 * sale.end_price, completion },
+				);
+				id
+			};
+			// The renewals are found on any core, but only into the next region.
+			let lease = renewal(7, sale.region_end, 2000);
+			let bought = renewal(1, sale.region_end, 3000);
+			let later = renewal(0, sale.region_end + 1, 2000);
+
+			set_broker_parameter(broker::Parameters::RenewalDiscount(
+				broker::RenewalDiscount,
+				Some(Perbill::from_percent(20)),
+			));
+			CoretimeAllocator::on_new_timeslice(1);
+			let discounted = Perbill::from_percent(80) * sale.end_price;
+			assert_eq!(PotentialRenewals::<Runtime>::get(lease).unwrap().price, discounted);
+			for undiscounted in [bought, later] {
+				assert_eq!(
+					PotentialRenewals::<Runtime>::get(undiscounted).unwrap().price,
+					2 * sale.end_price
+				);
+			}
+			assert_eq!(LeaseHolders::get(), vec![2001]);
+
+			// The discount is not applied again within the same sale.
+			set_broker_parameter(broker::Parameters::RenewalDiscount(
+				broker::RenewalDiscount,
+				Some(Perbill::from_percent(50)),
+			));
+			CoretimeAllocator::on_new_timeslice(2);
+			assert_eq!(PotentialRenewals::<Runtime>::get(lease).unwrap().price, discounted);
+		});
+}
+
+#[test]
+fn lease_holders_are_seeded_once() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		Leases::<Runtime>::put(frame_support::BoundedVec::truncate_from(vec![
+			LeaseRecordItem { until: 10, task: 2000 },
+			LeaseRecordItem { until: 20, task: 2001 },
+		]));
+		coretime::SeedLeaseHolders::on_runtime_upgrade();
+		assert_eq!(LeaseHolders::get(), vec![2000, 2001]);
+
+		// The recorded lease holders are kept on later upgrades.
+		LeaseHolders::set(&vec![2000]);
+		coretime::SeedLeaseHolders::on_runtime_upgrade();
+		assert_eq!(LeaseHolders::get(), vec![2000]);
+	});
+}
+
 #[test]
 fn location_conversion_works() {
 	let alice_32 =
@@ -59,6 +59,7 @@ parameter_types! {
 	pub const MaxInstructions: u32 = 100;
 	pub const MaxAssetsIntoHolding: u32 = 64;
 	pub const GovernanceLocation: Location = Location::parent();
+	pub AssetHubLocation: Location = Location::new(1, Parachain(system_parachain::ASSET_HUB_ID));
 	pub FellowshipLocation: Location = Location::new(1, Parachain(system_parachain::COLLECTIVES_ID));
 	pub StakingPot: AccountId = CollatorSelection::account_id();
 }
@@ -144,6 +145,17 @@ impl Contains<Location> for ParentOrParentsPlurality {
 	}
 }
 
+/// A location matching the pluralities (i.e. governance bodies) of Asset Hub.
+pub struct AssetHubPlurality;
+impl Contains<Location> for AssetHubPlurality {
+	fn contains(location: &Location) -> bool {
+		matches!(
+			location.unpack(),
+			(1, [Parachain(system_parachain::ASSET_HUB_ID), Plurality { .. }])
+		)
+	}
+}
+
 /// A location matching the Core Technical Fellowship.
 pub struct FellowsPlurality;
 impl Contains<Location> for FellowsPlurality {
@@ -174,9 +186,11 @@ pub type Barrier = TrailingSetTopicAsId<
 					// If the message is one that immediately attemps to pay for execution, then
 					// allow it.
 					AllowTopLevelPaidExecutionFrom<Everything>,
-					// Parent and its pluralities (i.e. governance bodies) get free execution.
+					// Parent and its pluralities (i.e. governance bodies), as well as the
+					// governance bodies of Asset Hub, get free execution.
 					AllowExplicitUnpaidExecutionFrom<(
 						ParentOrParentsPlurality,
+						AssetHubPlurality,
 						FellowsPlurality,
 						Equals<RelayTreasuryLocation>,
 					)>,